@@ -0,0 +1,113 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::{io::Cursor, net::{TcpListener, TcpStream}};
+use bytecon::{blocking::BlockingByteStream, ByteStreamReader, ByteStreamWriter};
+
+const MESSAGES_LENGTH: usize = 1000;
+
+fn get_messages() -> Vec<(u64, String)> {
+    (0..MESSAGES_LENGTH as u64)
+        .map(|value| (value, format!("message {value}")))
+        .collect()
+}
+
+// a connected socket whose peer discards everything written to it on another thread
+fn get_draining_tcp_stream() -> TcpStream {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (mut peer, _) = listener.accept().unwrap();
+    std::thread::spawn(move || {
+        let _ = std::io::copy(&mut peer, &mut std::io::sink());
+    });
+    stream
+}
+
+fn bench_blocking_write(c: &mut Criterion) {
+    let messages = get_messages();
+    let mut group = c.benchmark_group("blocking_write");
+
+    let mut stream = get_draining_tcp_stream();
+    group.bench_function("write_from_byte_converter", |b| {
+        b.iter(|| {
+            for message in messages.iter() {
+                stream.write_from_byte_converter(black_box(message)).unwrap();
+            }
+        })
+    });
+
+    let mut stream = get_draining_tcp_stream();
+    group.bench_function("write_many", |b| {
+        b.iter(|| stream.write_many(black_box(&messages)).unwrap())
+    });
+
+    let mut stream = BlockingByteStream::new(get_draining_tcp_stream());
+    group.bench_function("blocking_byte_stream_write_many", |b| {
+        b.iter(|| stream.write_many(black_box(&messages)).unwrap())
+    });
+    group.finish();
+}
+
+fn bench_blocking_read(c: &mut Criterion) {
+    let mut bytes = Vec::new();
+    bytes.write_many(&get_messages()).unwrap();
+    let mut group = c.benchmark_group("blocking_read");
+
+    group.bench_function("read_to_byte_converter", |b| {
+        b.iter(|| {
+            let mut cursor = Cursor::new(&bytes);
+            for _ in 0..MESSAGES_LENGTH {
+                black_box(cursor.read_to_byte_converter::<(u64, String)>().unwrap());
+            }
+        })
+    });
+
+    group.bench_function("blocking_byte_stream_read_many", |b| {
+        b.iter(|| {
+            let mut stream = BlockingByteStream::new(Cursor::new(&bytes));
+            let mut read_length = 0;
+            while read_length < MESSAGES_LENGTH {
+                read_length += black_box(stream.read_many::<(u64, String)>(MESSAGES_LENGTH).unwrap()).len();
+            }
+        })
+    });
+    group.finish();
+}
+
+#[cfg(feature = "tokio")]
+fn bench_tokio_write(c: &mut Criterion) {
+    use bytecon::ByteStreamWriterAsync;
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let messages = get_messages();
+    let mut stream = runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let stream = tokio::net::TcpStream::connect(listener.local_addr().unwrap()).await.unwrap();
+        let (mut peer, _) = listener.accept().await.unwrap();
+        tokio::spawn(async move {
+            let _ = tokio::io::copy(&mut peer, &mut tokio::io::sink()).await;
+        });
+        stream
+    });
+    let mut group = c.benchmark_group("tokio_write");
+
+    group.bench_function("write_from_byte_converter", |b| {
+        b.iter(|| runtime.block_on(async {
+            for message in messages.iter() {
+                stream.write_from_byte_converter(black_box(message)).await.unwrap();
+            }
+        }))
+    });
+
+    group.bench_function("write_many", |b| {
+        b.iter(|| runtime.block_on(async {
+            stream.write_many(black_box(&messages)).await.unwrap();
+        }))
+    });
+    group.finish();
+}
+
+#[cfg(not(feature = "tokio"))]
+fn bench_tokio_write(_: &mut Criterion) {
+}
+
+criterion_group!(benches, bench_blocking_write, bench_blocking_read, bench_tokio_write);
+criterion_main!(benches);
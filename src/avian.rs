@@ -0,0 +1,907 @@
+use bevy::ecs::entity::Entity;
+
+// the components, resources and events that are laid out the same in avian2d and avian3d
+// the invoking module must have the prelude, Scalar, the bevy ecs types and ByteConverter in scope
+// vector is the linear Vector type, torque is Vector in 3D and Scalar in 2D, and dir is the Dir type of the ray casters
+macro_rules! avian_byte_converters {
+    (vector: $vector:ident, torque: $torque:ident, dir: $dir:ident) => {
+        impl ByteConverter for AngleLimit {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.min.append_to_bytes(bytes)?;
+                self.max.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    min: Scalar::extract_from_bytes(bytes, index)?,
+                    max: Scalar::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for AngularDamping {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(Scalar::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for CoefficientCombine {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                match self {
+                    Self::Average => 0u8.append_to_bytes(bytes)?,
+                    Self::GeometricMean => 1u8.append_to_bytes(bytes)?,
+                    Self::Min => 2u8.append_to_bytes(bytes)?,
+                    Self::Multiply => 3u8.append_to_bytes(bytes)?,
+                    Self::Max => 4u8.append_to_bytes(bytes)?,
+                }
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+                match enum_variant_byte {
+                    0u8 => Ok(Self::Average),
+                    1u8 => Ok(Self::GeometricMean),
+                    2u8 => Ok(Self::Min),
+                    3u8 => Ok(Self::Multiply),
+                    4u8 => Ok(Self::Max),
+                    _ => Err("Unexpected enum variant byte.".into()),
+                }
+            }
+        }
+
+        impl ByteConverter for ColliderAabb {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.min.append_to_bytes(bytes)?;
+                self.max.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    min: $vector::extract_from_bytes(bytes, index)?,
+                    max: $vector::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for ColliderConstructorHierarchy {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.default_constructor.append_to_bytes(bytes)?;
+                self.default_layers.append_to_bytes(bytes)?;
+                self.default_density.append_to_bytes(bytes)?;
+                self.config.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    default_constructor: Option::<ColliderConstructor>::extract_from_bytes(bytes, index)?,
+                    default_layers: CollisionLayers::extract_from_bytes(bytes, index)?,
+                    default_density: ColliderDensity::extract_from_bytes(bytes, index)?,
+                    config: bevy::platform::collections::HashMap::<String, Option<ColliderConstructorHierarchyConfig>>::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for ColliderConstructorHierarchyConfig {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.constructor.append_to_bytes(bytes)?;
+                self.layers.append_to_bytes(bytes)?;
+                self.density.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    constructor: Option::<ColliderConstructor>::extract_from_bytes(bytes, index)?,
+                    layers: Option::<CollisionLayers>::extract_from_bytes(bytes, index)?,
+                    density: Option::<ColliderDensity>::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for ColliderDensity {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(f32::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for ColliderDisabled {
+            fn append_to_bytes(&self, _bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                // nothing to serialize
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_bytes: &'a TBytes, _index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                // nothing to deserialize
+                Ok(Self)
+            }
+        }
+
+        impl ByteConverter for ColliderMarker {
+            fn append_to_bytes(&self, _bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                // nothing to serialize
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_bytes: &'a TBytes, _index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                // nothing to deserialize
+                Ok(Self)
+            }
+        }
+
+        impl ByteConverter for ColliderMassProperties {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            }
+        }
+
+        impl ByteConverter for ColliderOf {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.body.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    body: Entity::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for ColliderTransform {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.translation.append_to_bytes(bytes)?;
+                self.rotation.append_to_bytes(bytes)?;
+                self.scale.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    translation: $vector::extract_from_bytes(bytes, index)?,
+                    rotation: Rotation::extract_from_bytes(bytes, index)?,
+                    scale: $vector::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for CollisionEnded {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                self.1.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(Entity::extract_from_bytes(bytes, index)?, Entity::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for CollisionLayers {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.memberships.append_to_bytes(bytes)?;
+                self.filters.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    memberships: LayerMask::extract_from_bytes(bytes, index)?,
+                    filters: LayerMask::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for CollisionMargin {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(Scalar::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for CollisionStarted {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                self.1.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(Entity::extract_from_bytes(bytes, index)?, Entity::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for ComputedMass {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.inverse().append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self::from_inverse(Scalar::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for ContactPair {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            }
+        }
+
+        impl ByteConverter for DefaultFriction {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(Friction::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for DefaultRestitution {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(Restitution::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for DistanceJoint {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.entity1.append_to_bytes(bytes)?;
+                self.entity2.append_to_bytes(bytes)?;
+                self.local_anchor1.append_to_bytes(bytes)?;
+                self.local_anchor2.append_to_bytes(bytes)?;
+                self.rest_length.append_to_bytes(bytes)?;
+                self.length_limits.append_to_bytes(bytes)?;
+                self.damping_linear.append_to_bytes(bytes)?;
+                self.damping_angular.append_to_bytes(bytes)?;
+                self.lagrange.append_to_bytes(bytes)?;
+                self.compliance.append_to_bytes(bytes)?;
+                self.force.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    entity1: Entity::extract_from_bytes(bytes, index)?,
+                    entity2: Entity::extract_from_bytes(bytes, index)?,
+                    local_anchor1: $vector::extract_from_bytes(bytes, index)?,
+                    local_anchor2: $vector::extract_from_bytes(bytes, index)?,
+                    rest_length: Scalar::extract_from_bytes(bytes, index)?,
+                    length_limits: Option::<DistanceLimit>::extract_from_bytes(bytes, index)?,
+                    damping_linear: Scalar::extract_from_bytes(bytes, index)?,
+                    damping_angular: Scalar::extract_from_bytes(bytes, index)?,
+                    lagrange: Scalar::extract_from_bytes(bytes, index)?,
+                    compliance: Scalar::extract_from_bytes(bytes, index)?,
+                    force: $vector::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for DistanceLimit {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.min.append_to_bytes(bytes)?;
+                self.max.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    min: Scalar::extract_from_bytes(bytes, index)?,
+                    max: Scalar::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for Dominance {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(i8::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for ExternalAngularImpulse {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            }
+        }
+
+        impl ByteConverter for ExternalForce {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            }
+        }
+
+        impl ByteConverter for ExternalImpulse {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            }
+        }
+
+        impl ByteConverter for ExternalTorque {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            }
+        }
+
+        impl ByteConverter for FixedJoint {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.entity1.append_to_bytes(bytes)?;
+                self.entity2.append_to_bytes(bytes)?;
+                self.local_anchor1.append_to_bytes(bytes)?;
+                self.local_anchor2.append_to_bytes(bytes)?;
+                self.damping_linear.append_to_bytes(bytes)?;
+                self.damping_angular.append_to_bytes(bytes)?;
+                self.position_lagrange.append_to_bytes(bytes)?;
+                self.align_lagrange.append_to_bytes(bytes)?;
+                self.compliance.append_to_bytes(bytes)?;
+                self.force.append_to_bytes(bytes)?;
+                self.align_torque.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    entity1: Entity::extract_from_bytes(bytes, index)?,
+                    entity2: Entity::extract_from_bytes(bytes, index)?,
+                    local_anchor1: $vector::extract_from_bytes(bytes, index)?,
+                    local_anchor2: $vector::extract_from_bytes(bytes, index)?,
+                    damping_linear: Scalar::extract_from_bytes(bytes, index)?,
+                    damping_angular: Scalar::extract_from_bytes(bytes, index)?,
+                    position_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+                    align_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+                    compliance: Scalar::extract_from_bytes(bytes, index)?,
+                    force: $vector::extract_from_bytes(bytes, index)?,
+                    align_torque: $torque::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for Friction {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.dynamic_coefficient.append_to_bytes(bytes)?;
+                self.static_coefficient.append_to_bytes(bytes)?;
+                self.combine_rule.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    dynamic_coefficient: Scalar::extract_from_bytes(bytes, index)?,
+                    static_coefficient: Scalar::extract_from_bytes(bytes, index)?,
+                    combine_rule: CoefficientCombine::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for Gravity {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self($vector::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for GravityScale {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(f32::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for LayerMask {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(u32::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for LinearDamping {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(Scalar::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for LinearVelocity {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self($vector::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for LockedAxes {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.to_bits().append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self::from_bits(u8::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for Mass {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(f32::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for PrismaticJoint {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.entity1.append_to_bytes(bytes)?;
+                self.entity2.append_to_bytes(bytes)?;
+                self.local_anchor1.append_to_bytes(bytes)?;
+                self.local_anchor2.append_to_bytes(bytes)?;
+                self.free_axis.append_to_bytes(bytes)?;
+                self.free_axis_limits.append_to_bytes(bytes)?;
+                self.damping_linear.append_to_bytes(bytes)?;
+                self.damping_angular.append_to_bytes(bytes)?;
+                self.position_lagrange.append_to_bytes(bytes)?;
+                self.align_lagrange.append_to_bytes(bytes)?;
+                self.compliance.append_to_bytes(bytes)?;
+                self.force.append_to_bytes(bytes)?;
+                self.align_torque.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    entity1: Entity::extract_from_bytes(bytes, index)?,
+                    entity2: Entity::extract_from_bytes(bytes, index)?,
+                    local_anchor1: $vector::extract_from_bytes(bytes, index)?,
+                    local_anchor2: $vector::extract_from_bytes(bytes, index)?,
+                    free_axis: $vector::extract_from_bytes(bytes, index)?,
+                    free_axis_limits: Option::<DistanceLimit>::extract_from_bytes(bytes, index)?,
+                    damping_linear: Scalar::extract_from_bytes(bytes, index)?,
+                    damping_angular: Scalar::extract_from_bytes(bytes, index)?,
+                    position_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+                    align_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+                    compliance: Scalar::extract_from_bytes(bytes, index)?,
+                    force: $vector::extract_from_bytes(bytes, index)?,
+                    align_torque: $torque::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        // the global origin and direction are left out since avian recomputes them from the transform every frame
+        impl ByteConverter for RayCaster {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.enabled.append_to_bytes(bytes)?;
+                self.origin.append_to_bytes(bytes)?;
+                self.direction.append_to_bytes(bytes)?;
+                self.max_hits.append_to_bytes(bytes)?;
+                self.max_distance.append_to_bytes(bytes)?;
+                self.solid.append_to_bytes(bytes)?;
+                self.ignore_self.append_to_bytes(bytes)?;
+                self.query_filter.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let enabled = bool::extract_from_bytes(bytes, index)?;
+                let origin = $vector::extract_from_bytes(bytes, index)?;
+                let direction = $dir::extract_from_bytes(bytes, index)?;
+                let mut ray_caster = Self::new(origin, direction)
+                    .with_max_hits(u32::extract_from_bytes(bytes, index)?)
+                    .with_max_distance(Scalar::extract_from_bytes(bytes, index)?)
+                    .with_solidness(bool::extract_from_bytes(bytes, index)?)
+                    .with_ignore_self(bool::extract_from_bytes(bytes, index)?)
+                    .with_query_filter(SpatialQueryFilter::extract_from_bytes(bytes, index)?);
+                ray_caster.enabled = enabled;
+                Ok(ray_caster)
+            }
+        }
+
+        impl ByteConverter for RayHitData {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.entity.append_to_bytes(bytes)?;
+                self.distance.append_to_bytes(bytes)?;
+                self.normal.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    entity: Entity::extract_from_bytes(bytes, index)?,
+                    distance: Scalar::extract_from_bytes(bytes, index)?,
+                    normal: $vector::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        // the hits are only reachable through serde, so bincode is used like for the force components
+        impl ByteConverter for RayHits {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            }
+        }
+
+        impl ByteConverter for Restitution {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.coefficient.append_to_bytes(bytes)?;
+                self.combine_rule.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    coefficient: Scalar::extract_from_bytes(bytes, index)?,
+                    combine_rule: CoefficientCombine::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for Sensor {
+            fn append_to_bytes(&self, _bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                // nothing to serialize
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_bytes: &'a TBytes, _index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                // nothing to deserialize
+                Ok(Self)
+            }
+        }
+
+        impl ByteConverter for ShapeHitData {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.entity.append_to_bytes(bytes)?;
+                self.distance.append_to_bytes(bytes)?;
+                self.point1.append_to_bytes(bytes)?;
+                self.point2.append_to_bytes(bytes)?;
+                self.normal1.append_to_bytes(bytes)?;
+                self.normal2.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    entity: Entity::extract_from_bytes(bytes, index)?,
+                    distance: Scalar::extract_from_bytes(bytes, index)?,
+                    point1: $vector::extract_from_bytes(bytes, index)?,
+                    point2: $vector::extract_from_bytes(bytes, index)?,
+                    normal1: $vector::extract_from_bytes(bytes, index)?,
+                    normal2: $vector::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        impl ByteConverter for ShapeHits {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            }
+        }
+
+        impl ByteConverter for Sleeping {
+            fn append_to_bytes(&self, _bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                // nothing to serialize
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_bytes: &'a TBytes, _index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                // nothing to deserialize
+                Ok(Self)
+            }
+        }
+
+        impl ByteConverter for SpatialQueryFilter {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.mask.append_to_bytes(bytes)?;
+                self.excluded_entities.len().append_to_bytes(bytes)?;
+                for excluded_entity in self.excluded_entities.iter() {
+                    excluded_entity.append_to_bytes(bytes)?;
+                }
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    mask: LayerMask::extract_from_bytes(bytes, index)?,
+                    excluded_entities: Vec::<Entity>::extract_from_bytes(bytes, index)?
+                        .into_iter()
+                        .collect::<EntityHashSet>(),
+                })
+            }
+        }
+
+        impl ByteConverter for SpeculativeMargin {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(f32::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for Position {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self($vector::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for PreviousRotation {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(Rotation::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        impl ByteConverter for RigidBody {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                match self {
+                    Self::Dynamic => {
+                        0u8.append_to_bytes(bytes)?;
+                    },
+                    Self::Static => {
+                        1u8.append_to_bytes(bytes)?;
+                    },
+                    Self::Kinematic => {
+                        2u8.append_to_bytes(bytes)?;
+                    },
+                }
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+                match enum_variant_byte {
+                    0u8 => Ok(Self::Dynamic),
+                    1u8 => Ok(Self::Static),
+                    2u8 => Ok(Self::Kinematic),
+                    _ => Err("Unexpected enum variant byte.".into()),
+                }
+            }
+        }
+
+        impl ByteConverter for TimeSleeping {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.0.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(Scalar::extract_from_bytes(bytes, index)?))
+            }
+        }
+
+        // the rigid body state of a single entity at the tick that the PhysicsSnapshot was captured
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct RigidBodySnapshot {
+            pub entity: Entity,
+            pub position: Position,
+            pub rotation: Rotation,
+            pub previous_rotation: PreviousRotation,
+            pub linear_velocity: LinearVelocity,
+            pub angular_velocity: AngularVelocity,
+            pub external_force: ExternalForce,
+            pub external_torque: ExternalTorque,
+            pub external_impulse: ExternalImpulse,
+            pub external_angular_impulse: ExternalAngularImpulse,
+            pub time_sleeping: Option<TimeSleeping>,
+            pub is_sleeping: bool,
+        }
+
+        impl ByteConverter for RigidBodySnapshot {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.entity.append_to_bytes(bytes)?;
+                self.position.append_to_bytes(bytes)?;
+                self.rotation.append_to_bytes(bytes)?;
+                self.previous_rotation.append_to_bytes(bytes)?;
+                self.linear_velocity.append_to_bytes(bytes)?;
+                self.angular_velocity.append_to_bytes(bytes)?;
+                self.external_force.append_to_bytes(bytes)?;
+                self.external_torque.append_to_bytes(bytes)?;
+                self.external_impulse.append_to_bytes(bytes)?;
+                self.external_angular_impulse.append_to_bytes(bytes)?;
+                self.time_sleeping.append_to_bytes(bytes)?;
+                self.is_sleeping.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    entity: Entity::extract_from_bytes(bytes, index)?,
+                    position: Position::extract_from_bytes(bytes, index)?,
+                    rotation: Rotation::extract_from_bytes(bytes, index)?,
+                    previous_rotation: PreviousRotation::extract_from_bytes(bytes, index)?,
+                    linear_velocity: LinearVelocity::extract_from_bytes(bytes, index)?,
+                    angular_velocity: AngularVelocity::extract_from_bytes(bytes, index)?,
+                    external_force: ExternalForce::extract_from_bytes(bytes, index)?,
+                    external_torque: ExternalTorque::extract_from_bytes(bytes, index)?,
+                    external_impulse: ExternalImpulse::extract_from_bytes(bytes, index)?,
+                    external_angular_impulse: ExternalAngularImpulse::extract_from_bytes(bytes, index)?,
+                    time_sleeping: Option::<TimeSleeping>::extract_from_bytes(bytes, index)?,
+                    is_sleeping: bool::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        // the whole physics state of a World at a single tick, restored atomically for rollback
+        // the contact pairs are kept in their original order so that the solver warm starts and iterates exactly as it did before
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct PhysicsSnapshot {
+            pub rigid_bodies: Vec<RigidBodySnapshot>,
+            pub contact_pairs: Vec<ContactPair>,
+        }
+
+        impl PhysicsSnapshot {
+            // rigid bodies that have not been through a physics step yet do not have a Position and are left out
+            pub fn capture(world: &mut World) -> Self {
+                let mut query = world.query_filtered::<(
+                    Entity,
+                    &Position,
+                    &Rotation,
+                    &PreviousRotation,
+                    &LinearVelocity,
+                    &AngularVelocity,
+                    &ExternalForce,
+                    &ExternalTorque,
+                    &ExternalImpulse,
+                    &ExternalAngularImpulse,
+                    Option<&TimeSleeping>,
+                    Has<Sleeping>,
+                ), With<RigidBody>>();
+                let mut rigid_bodies = query.iter(world)
+                    .map(|(entity, position, rotation, previous_rotation, linear_velocity, angular_velocity, external_force, external_torque, external_impulse, external_angular_impulse, time_sleeping, is_sleeping)| {
+                        RigidBodySnapshot {
+                            entity,
+                            position: *position,
+                            rotation: *rotation,
+                            previous_rotation: *previous_rotation,
+                            linear_velocity: *linear_velocity,
+                            angular_velocity: *angular_velocity,
+                            external_force: *external_force,
+                            external_torque: *external_torque,
+                            external_impulse: *external_impulse,
+                            external_angular_impulse: *external_angular_impulse,
+                            time_sleeping: time_sleeping.copied(),
+                            is_sleeping,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                rigid_bodies.sort_by_key(|rigid_body| rigid_body.entity);
+                let contact_pairs = world.get_resource::<ContactGraph>()
+                    .map(|contact_graph| contact_graph.internal.all_edge_weights().cloned().collect())
+                    .unwrap_or_default();
+                Self {
+                    rigid_bodies,
+                    contact_pairs,
+                }
+            }
+            // every rigid body must still exist, otherwise nothing is restored
+            pub fn restore(&self, world: &mut World) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                if let Some(rigid_body) = self.rigid_bodies.iter().find(|rigid_body| world.get_entity(rigid_body.entity).is_err()) {
+                    return Err($crate::avian::AvianByteConError::RigidBodyNotFound {
+                        entity: rigid_body.entity,
+                    }.into());
+                }
+                for rigid_body in self.rigid_bodies.iter() {
+                    let mut entity_mut = world.entity_mut(rigid_body.entity);
+                    entity_mut.insert((
+                        rigid_body.position,
+                        rigid_body.rotation,
+                        rigid_body.previous_rotation,
+                        rigid_body.linear_velocity,
+                        rigid_body.angular_velocity,
+                        rigid_body.external_force,
+                        rigid_body.external_torque,
+                        rigid_body.external_impulse,
+                        rigid_body.external_angular_impulse,
+                    ));
+                    match rigid_body.time_sleeping {
+                        Some(time_sleeping) => {
+                            entity_mut.insert(time_sleeping);
+                        },
+                        None => {
+                            entity_mut.remove::<TimeSleeping>();
+                        },
+                    }
+                    if rigid_body.is_sleeping {
+                        entity_mut.insert(Sleeping);
+                    }
+                    else {
+                        entity_mut.remove::<Sleeping>();
+                    }
+                }
+                if let Some(mut contact_graph) = world.get_resource_mut::<ContactGraph>() {
+                    let mut restored_contact_graph = ContactGraph::default();
+                    for contact_pair in self.contact_pairs.iter() {
+                        restored_contact_graph.add_pair(contact_pair.clone());
+                    }
+                    *contact_graph = restored_contact_graph;
+                }
+                Ok(())
+            }
+        }
+
+        impl ByteConverter for PhysicsSnapshot {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                self.rigid_bodies.append_to_bytes(bytes)?;
+                self.contact_pairs.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    rigid_bodies: Vec::<RigidBodySnapshot>::extract_from_bytes(bytes, index)?,
+                    contact_pairs: Vec::<ContactPair>::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use avian_byte_converters;
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum AvianByteConError {
+    #[error("Rigid body {entity} from the snapshot no longer exists.")]
+    RigidBodyNotFound {
+        entity: Entity,
+    },
+}
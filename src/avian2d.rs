@@ -0,0 +1,472 @@
+use avian2d::{collision::collider::ColliderConstructorHierarchyConfig, math::Scalar, parry::{math::{Isometry, Point, Translation}, na::{Complex, UnitComplex}, shape::{SharedShape, TriMeshFlags, TypedShape}}, position::PreviousRotation, prelude::*};
+use bevy::{ecs::{entity::{Entity, EntityHashSet}, query::{Has, With}, world::World}, math::Dir2};
+use glam::Vec2;
+use crate::ByteConverter;
+
+crate::avian::avian_byte_converters!(vector: Vec2, torque: Scalar, dir: Dir2);
+
+impl ByteConverter for AngularVelocity {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.0.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self(Scalar::extract_from_bytes(bytes, index)?))
+    }
+}
+
+#[inline(always)]
+fn point_to_vec2(point: &Point<Scalar>) -> Vec2 {
+    Vec2::new(point.x, point.y)
+}
+
+#[inline(always)]
+fn vec2_to_point(vector: Vec2) -> Point<Scalar> {
+    Point::new(vector.x, vector.y)
+}
+
+// shapes without a native encoding fall back to the bincode representation of the parry shape
+fn append_shared_shape_to_bytes(shape: &SharedShape, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    match shape.as_typed_shape() {
+        TypedShape::Ball(ball) => {
+            0u8.append_to_bytes(bytes)?;
+            ball.radius.append_to_bytes(bytes)?;
+        },
+        TypedShape::Cuboid(cuboid) => {
+            1u8.append_to_bytes(bytes)?;
+            cuboid.half_extents.x.append_to_bytes(bytes)?;
+            cuboid.half_extents.y.append_to_bytes(bytes)?;
+        },
+        TypedShape::Capsule(capsule) => {
+            2u8.append_to_bytes(bytes)?;
+            point_to_vec2(&capsule.segment.a).append_to_bytes(bytes)?;
+            point_to_vec2(&capsule.segment.b).append_to_bytes(bytes)?;
+            capsule.radius.append_to_bytes(bytes)?;
+        },
+        TypedShape::Segment(segment) => {
+            3u8.append_to_bytes(bytes)?;
+            point_to_vec2(&segment.a).append_to_bytes(bytes)?;
+            point_to_vec2(&segment.b).append_to_bytes(bytes)?;
+        },
+        TypedShape::Triangle(triangle) => {
+            4u8.append_to_bytes(bytes)?;
+            point_to_vec2(&triangle.a).append_to_bytes(bytes)?;
+            point_to_vec2(&triangle.b).append_to_bytes(bytes)?;
+            point_to_vec2(&triangle.c).append_to_bytes(bytes)?;
+        },
+        TypedShape::ConvexPolygon(convex_polygon) => {
+            5u8.append_to_bytes(bytes)?;
+            convex_polygon.points().len().append_to_bytes(bytes)?;
+            for point in convex_polygon.points() {
+                point_to_vec2(point).append_to_bytes(bytes)?;
+            }
+        },
+        TypedShape::TriMesh(trimesh) => {
+            6u8.append_to_bytes(bytes)?;
+            trimesh.vertices().len().append_to_bytes(bytes)?;
+            for vertex in trimesh.vertices() {
+                point_to_vec2(vertex).append_to_bytes(bytes)?;
+            }
+            trimesh.indices().len().append_to_bytes(bytes)?;
+            for triangle_indices in trimesh.indices() {
+                triangle_indices.append_to_bytes(bytes)?;
+            }
+            trimesh.flags().bits().append_to_bytes(bytes)?;
+        },
+        TypedShape::Compound(compound) => {
+            7u8.append_to_bytes(bytes)?;
+            compound.shapes().len().append_to_bytes(bytes)?;
+            for (isometry, shape) in compound.shapes() {
+                Vec2::new(isometry.translation.x, isometry.translation.y).append_to_bytes(bytes)?;
+                // the unit complex number is kept as is since converting it to an angle and back is lossy
+                isometry.rotation.re.append_to_bytes(bytes)?;
+                isometry.rotation.im.append_to_bytes(bytes)?;
+                append_shared_shape_to_bytes(shape, bytes)?;
+            }
+        },
+        _ => {
+            255u8.append_to_bytes(bytes)?;
+            let serialized_bytes = bincode::serialize(shape)?;
+            serialized_bytes.append_to_bytes(bytes)?;
+        },
+    }
+    Ok(())
+}
+
+fn extract_shared_shape_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<SharedShape, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+    match enum_variant_byte {
+        0u8 => Ok(SharedShape::ball(Scalar::extract_from_bytes(bytes, index)?)),
+        1u8 => Ok(SharedShape::cuboid(
+            Scalar::extract_from_bytes(bytes, index)?,
+            Scalar::extract_from_bytes(bytes, index)?,
+        )),
+        2u8 => Ok(SharedShape::capsule(
+            vec2_to_point(Vec2::extract_from_bytes(bytes, index)?),
+            vec2_to_point(Vec2::extract_from_bytes(bytes, index)?),
+            Scalar::extract_from_bytes(bytes, index)?,
+        )),
+        3u8 => Ok(SharedShape::segment(
+            vec2_to_point(Vec2::extract_from_bytes(bytes, index)?),
+            vec2_to_point(Vec2::extract_from_bytes(bytes, index)?),
+        )),
+        4u8 => Ok(SharedShape::triangle(
+            vec2_to_point(Vec2::extract_from_bytes(bytes, index)?),
+            vec2_to_point(Vec2::extract_from_bytes(bytes, index)?),
+            vec2_to_point(Vec2::extract_from_bytes(bytes, index)?),
+        )),
+        5u8 => {
+            // the points are already the ordered hull, so the polygon is rebuilt as is instead of recomputing its hull
+            let points = Vec::<Vec2>::extract_from_bytes(bytes, index)?
+                .into_iter()
+                .map(vec2_to_point)
+                .collect::<Vec<_>>();
+            SharedShape::convex_polyline(points)
+                .ok_or_else(|| "Failed to build convex polygon from points.".into())
+        },
+        6u8 => {
+            let vertices = Vec::<Vec2>::extract_from_bytes(bytes, index)?
+                .into_iter()
+                .map(vec2_to_point)
+                .collect::<Vec<_>>();
+            let indices = Vec::<[u32; 3]>::extract_from_bytes(bytes, index)?;
+            if indices.is_empty() {
+                return Err("Trimesh has no triangles.".into());
+            }
+            if indices.iter().flatten().any(|vertex_index| *vertex_index as usize >= vertices.len()) {
+                return Err("Trimesh index out of bounds.".into());
+            }
+            let flags = TriMeshFlags::from_bits(u16::extract_from_bytes(bytes, index)?)
+                .ok_or("Unexpected trimesh flag bits.")?;
+            Ok(SharedShape::trimesh_with_flags(vertices, indices, flags))
+        },
+        7u8 => {
+            let shapes_length = usize::extract_from_bytes(bytes, index)?;
+            if shapes_length == 0 {
+                return Err("Compound shape has no shapes.".into());
+            }
+            let mut shapes = Vec::with_capacity(crate::base::get_capacity_hint::<(Isometry<Scalar>, SharedShape), _>(shapes_length, bytes, index));
+            for _ in 0..shapes_length {
+                let translation = Vec2::extract_from_bytes(bytes, index)?;
+                let rotation_re = Scalar::extract_from_bytes(bytes, index)?;
+                let rotation_im = Scalar::extract_from_bytes(bytes, index)?;
+                let isometry = Isometry::from_parts(
+                    Translation::new(translation.x, translation.y),
+                    UnitComplex::new_unchecked(Complex::new(rotation_re, rotation_im)),
+                );
+                let shape = extract_shared_shape_from_bytes(bytes, index)?;
+                if shape.as_composite_shape().is_some() {
+                    return Err("Nested composite shapes are not allowed in a compound shape.".into());
+                }
+                shapes.push((isometry, shape));
+            }
+            Ok(SharedShape::compound(shapes))
+        },
+        255u8 => {
+            let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+            Ok(bincode::deserialize::<SharedShape>(&serialized_bytes)?)
+        },
+        _ => Err("Unexpected enum variant byte.".into()),
+    }
+}
+
+impl ByteConverter for Collider {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        append_shared_shape_to_bytes(self.shape(), bytes)?;
+        self.scale().append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let shape = extract_shared_shape_from_bytes(bytes, index)?;
+        let scale = Vec2::extract_from_bytes(bytes, index)?;
+        let mut collider = Self::from(shape);
+        // the same subdivision count that avian uses when the shape of a scaled collider is replaced
+        collider.set_scale(scale, 10);
+        Ok(collider)
+    }
+}
+
+// constructors that are not listed here, like the ones with vhacd parameters, fall back to bincode
+impl ByteConverter for ColliderConstructor {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Circle { radius } => {
+                0u8.append_to_bytes(bytes)?;
+                radius.append_to_bytes(bytes)?;
+            },
+            Self::Ellipse { half_width, half_height } => {
+                1u8.append_to_bytes(bytes)?;
+                half_width.append_to_bytes(bytes)?;
+                half_height.append_to_bytes(bytes)?;
+            },
+            Self::Rectangle { x_length, y_length } => {
+                2u8.append_to_bytes(bytes)?;
+                x_length.append_to_bytes(bytes)?;
+                y_length.append_to_bytes(bytes)?;
+            },
+            Self::RoundRectangle { x_length, y_length, border_radius } => {
+                3u8.append_to_bytes(bytes)?;
+                x_length.append_to_bytes(bytes)?;
+                y_length.append_to_bytes(bytes)?;
+                border_radius.append_to_bytes(bytes)?;
+            },
+            Self::Capsule { radius, height } => {
+                4u8.append_to_bytes(bytes)?;
+                radius.append_to_bytes(bytes)?;
+                height.append_to_bytes(bytes)?;
+            },
+            Self::CapsuleEndpoints { radius, a, b } => {
+                5u8.append_to_bytes(bytes)?;
+                radius.append_to_bytes(bytes)?;
+                a.append_to_bytes(bytes)?;
+                b.append_to_bytes(bytes)?;
+            },
+            Self::HalfSpace { outward_normal } => {
+                6u8.append_to_bytes(bytes)?;
+                outward_normal.append_to_bytes(bytes)?;
+            },
+            Self::Segment { a, b } => {
+                7u8.append_to_bytes(bytes)?;
+                a.append_to_bytes(bytes)?;
+                b.append_to_bytes(bytes)?;
+            },
+            Self::Triangle { a, b, c } => {
+                8u8.append_to_bytes(bytes)?;
+                a.append_to_bytes(bytes)?;
+                b.append_to_bytes(bytes)?;
+                c.append_to_bytes(bytes)?;
+            },
+            Self::RegularPolygon { circumradius, sides } => {
+                9u8.append_to_bytes(bytes)?;
+                circumradius.append_to_bytes(bytes)?;
+                sides.append_to_bytes(bytes)?;
+            },
+            Self::Polyline { vertices, indices } => {
+                10u8.append_to_bytes(bytes)?;
+                vertices.append_to_bytes(bytes)?;
+                indices.append_to_bytes(bytes)?;
+            },
+            Self::Trimesh { vertices, indices } => {
+                11u8.append_to_bytes(bytes)?;
+                vertices.append_to_bytes(bytes)?;
+                indices.append_to_bytes(bytes)?;
+            },
+            Self::TrimeshWithConfig { vertices, indices, flags } => {
+                12u8.append_to_bytes(bytes)?;
+                vertices.append_to_bytes(bytes)?;
+                indices.append_to_bytes(bytes)?;
+                flags.bits().append_to_bytes(bytes)?;
+            },
+            Self::ConvexDecomposition { vertices, indices } => {
+                13u8.append_to_bytes(bytes)?;
+                vertices.append_to_bytes(bytes)?;
+                indices.append_to_bytes(bytes)?;
+            },
+            Self::ConvexHull { points } => {
+                14u8.append_to_bytes(bytes)?;
+                points.append_to_bytes(bytes)?;
+            },
+            Self::Heightfield { heights, scale } => {
+                15u8.append_to_bytes(bytes)?;
+                heights.append_to_bytes(bytes)?;
+                scale.append_to_bytes(bytes)?;
+            },
+            _ => {
+                255u8.append_to_bytes(bytes)?;
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+            },
+        }
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Circle {
+                radius: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            1u8 => Ok(Self::Ellipse {
+                half_width: Scalar::extract_from_bytes(bytes, index)?,
+                half_height: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            2u8 => Ok(Self::Rectangle {
+                x_length: Scalar::extract_from_bytes(bytes, index)?,
+                y_length: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            3u8 => Ok(Self::RoundRectangle {
+                x_length: Scalar::extract_from_bytes(bytes, index)?,
+                y_length: Scalar::extract_from_bytes(bytes, index)?,
+                border_radius: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            4u8 => Ok(Self::Capsule {
+                radius: Scalar::extract_from_bytes(bytes, index)?,
+                height: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            5u8 => Ok(Self::CapsuleEndpoints {
+                radius: Scalar::extract_from_bytes(bytes, index)?,
+                a: Vec2::extract_from_bytes(bytes, index)?,
+                b: Vec2::extract_from_bytes(bytes, index)?,
+            }),
+            6u8 => Ok(Self::HalfSpace {
+                outward_normal: Vec2::extract_from_bytes(bytes, index)?,
+            }),
+            7u8 => Ok(Self::Segment {
+                a: Vec2::extract_from_bytes(bytes, index)?,
+                b: Vec2::extract_from_bytes(bytes, index)?,
+            }),
+            8u8 => Ok(Self::Triangle {
+                a: Vec2::extract_from_bytes(bytes, index)?,
+                b: Vec2::extract_from_bytes(bytes, index)?,
+                c: Vec2::extract_from_bytes(bytes, index)?,
+            }),
+            9u8 => Ok(Self::RegularPolygon {
+                circumradius: f32::extract_from_bytes(bytes, index)?,
+                sides: u32::extract_from_bytes(bytes, index)?,
+            }),
+            10u8 => Ok(Self::Polyline {
+                vertices: Vec::<Vec2>::extract_from_bytes(bytes, index)?,
+                indices: Option::<Vec<[u32; 2]>>::extract_from_bytes(bytes, index)?,
+            }),
+            11u8 => Ok(Self::Trimesh {
+                vertices: Vec::<Vec2>::extract_from_bytes(bytes, index)?,
+                indices: Vec::<[u32; 3]>::extract_from_bytes(bytes, index)?,
+            }),
+            12u8 => Ok(Self::TrimeshWithConfig {
+                vertices: Vec::<Vec2>::extract_from_bytes(bytes, index)?,
+                indices: Vec::<[u32; 3]>::extract_from_bytes(bytes, index)?,
+                flags: TrimeshFlags::from_bits(u8::extract_from_bytes(bytes, index)?)
+                    .ok_or("Unexpected trimesh flag bits.")?,
+            }),
+            13u8 => Ok(Self::ConvexDecomposition {
+                vertices: Vec::<Vec2>::extract_from_bytes(bytes, index)?,
+                indices: Vec::<[u32; 2]>::extract_from_bytes(bytes, index)?,
+            }),
+            14u8 => Ok(Self::ConvexHull {
+                points: Vec::<Vec2>::extract_from_bytes(bytes, index)?,
+            }),
+            15u8 => Ok(Self::Heightfield {
+                heights: Vec::<Scalar>::extract_from_bytes(bytes, index)?,
+                scale: Vec2::extract_from_bytes(bytes, index)?,
+            }),
+            255u8 => {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            },
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
+    }
+}
+
+impl ByteConverter for ComputedAngularInertia {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.inverse().append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self::from_inverse(Scalar::extract_from_bytes(bytes, index)?))
+    }
+}
+
+impl ByteConverter for MassProperties2d {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.mass.append_to_bytes(bytes)?;
+        self.angular_inertia.append_to_bytes(bytes)?;
+        self.center_of_mass.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            mass: f32::extract_from_bytes(bytes, index)?,
+            angular_inertia: f32::extract_from_bytes(bytes, index)?,
+            center_of_mass: Vec2::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+// the aligned axis is always the z axis in 2D and is not public, so it is left to the joint constructor
+impl ByteConverter for RevoluteJoint {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.entity1.append_to_bytes(bytes)?;
+        self.entity2.append_to_bytes(bytes)?;
+        self.local_anchor1.append_to_bytes(bytes)?;
+        self.local_anchor2.append_to_bytes(bytes)?;
+        self.angle_limit.append_to_bytes(bytes)?;
+        self.damping_linear.append_to_bytes(bytes)?;
+        self.damping_angular.append_to_bytes(bytes)?;
+        self.position_lagrange.append_to_bytes(bytes)?;
+        self.align_lagrange.append_to_bytes(bytes)?;
+        self.angle_limit_lagrange.append_to_bytes(bytes)?;
+        self.compliance.append_to_bytes(bytes)?;
+        self.force.append_to_bytes(bytes)?;
+        self.align_torque.append_to_bytes(bytes)?;
+        self.angle_limit_torque.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let entity1 = Entity::extract_from_bytes(bytes, index)?;
+        let entity2 = Entity::extract_from_bytes(bytes, index)?;
+        let mut revolute_joint = <Self as Joint>::new(entity1, entity2);
+        revolute_joint.local_anchor1 = Vec2::extract_from_bytes(bytes, index)?;
+        revolute_joint.local_anchor2 = Vec2::extract_from_bytes(bytes, index)?;
+        revolute_joint.angle_limit = Option::<AngleLimit>::extract_from_bytes(bytes, index)?;
+        revolute_joint.damping_linear = Scalar::extract_from_bytes(bytes, index)?;
+        revolute_joint.damping_angular = Scalar::extract_from_bytes(bytes, index)?;
+        revolute_joint.position_lagrange = Scalar::extract_from_bytes(bytes, index)?;
+        revolute_joint.align_lagrange = Scalar::extract_from_bytes(bytes, index)?;
+        revolute_joint.angle_limit_lagrange = Scalar::extract_from_bytes(bytes, index)?;
+        revolute_joint.compliance = Scalar::extract_from_bytes(bytes, index)?;
+        revolute_joint.force = Vec2::extract_from_bytes(bytes, index)?;
+        revolute_joint.align_torque = Scalar::extract_from_bytes(bytes, index)?;
+        revolute_joint.angle_limit_torque = Scalar::extract_from_bytes(bytes, index)?;
+        Ok(revolute_joint)
+    }
+}
+
+impl ByteConverter for ShapeCaster {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.enabled.append_to_bytes(bytes)?;
+        self.shape.append_to_bytes(bytes)?;
+        self.origin.append_to_bytes(bytes)?;
+        self.shape_rotation.append_to_bytes(bytes)?;
+        self.direction.append_to_bytes(bytes)?;
+        self.max_hits.append_to_bytes(bytes)?;
+        self.max_distance.append_to_bytes(bytes)?;
+        self.target_distance.append_to_bytes(bytes)?;
+        self.compute_contact_on_penetration.append_to_bytes(bytes)?;
+        self.ignore_origin_penetration.append_to_bytes(bytes)?;
+        self.ignore_self.append_to_bytes(bytes)?;
+        self.query_filter.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enabled = bool::extract_from_bytes(bytes, index)?;
+        let shape = Collider::extract_from_bytes(bytes, index)?;
+        let origin = Vec2::extract_from_bytes(bytes, index)?;
+        let shape_rotation = Scalar::extract_from_bytes(bytes, index)?;
+        let direction = Dir2::extract_from_bytes(bytes, index)?;
+        let mut shape_caster = Self::new(shape, origin, shape_rotation, direction)
+            .with_max_hits(u32::extract_from_bytes(bytes, index)?)
+            .with_max_distance(Scalar::extract_from_bytes(bytes, index)?)
+            .with_target_distance(Scalar::extract_from_bytes(bytes, index)?)
+            .with_compute_contact_on_penetration(bool::extract_from_bytes(bytes, index)?)
+            .with_ignore_origin_penetration(bool::extract_from_bytes(bytes, index)?)
+            .with_ignore_self(bool::extract_from_bytes(bytes, index)?)
+            .with_query_filter(SpatialQueryFilter::extract_from_bytes(bytes, index)?);
+        shape_caster.enabled = enabled;
+        Ok(shape_caster)
+    }
+}
+
+impl ByteConverter for Rotation {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.cos.append_to_bytes(bytes)?;
+        self.sin.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            cos: Scalar::extract_from_bytes(bytes, index)?,
+            sin: Scalar::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
@@ -1,8 +1,10 @@
-use avian3d::{collision::collider::ColliderConstructorHierarchyConfig, math::Scalar, prelude::*};
-use bevy::ecs::entity::Entity;
-use glam::{Quat, Vec3};
+use avian3d::{collision::collider::ColliderConstructorHierarchyConfig, math::{Scalar, Vector3}, parry::{math::{Isometry, Point, Translation}, na::{Quaternion, UnitQuaternion}, shape::{SharedShape, TriMeshFlags, TypedShape}}, position::PreviousRotation, prelude::*};
+use bevy::{ecs::{entity::{Entity, EntityHashSet}, query::{Has, With}, world::World}, math::Dir3};
+use glam::{Mat3, Quat, Vec3};
 use crate::ByteConverter;
 
+crate::avian::avian_byte_converters!(vector: Vec3, torque: Vec3, dir: Dir3);
+
 impl ByteConverter for AngularVelocity {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
         self.0.append_to_bytes(bytes)?;
@@ -13,339 +15,368 @@ impl ByteConverter for AngularVelocity {
     }
 }
 
-impl ByteConverter for CoefficientCombine {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        match self {
-            Self::Average => 0u8.append_to_bytes(bytes)?,
-            Self::GeometricMean => 1u8.append_to_bytes(bytes)?,
-            Self::Min => 2u8.append_to_bytes(bytes)?,
-            Self::Multiply => 3u8.append_to_bytes(bytes)?,
-            Self::Max => 4u8.append_to_bytes(bytes)?,
-        }
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
-        match enum_variant_byte {
-            0u8 => Ok(Self::Average),
-            1u8 => Ok(Self::GeometricMean),
-            2u8 => Ok(Self::Min),
-            3u8 => Ok(Self::Multiply),
-            4u8 => Ok(Self::Max),
-            _ => Err("Unexpected enum variant byte.".into()),
-        }
+#[inline(always)]
+fn point_to_vec3(point: &Point<Scalar>) -> Vec3 {
+    Vec3::new(point.x, point.y, point.z)
+}
+
+#[inline(always)]
+fn vec3_to_point(vector: Vec3) -> Point<Scalar> {
+    Point::new(vector.x, vector.y, vector.z)
+}
+
+// shapes without a native encoding fall back to the bincode representation of the parry shape
+fn append_shared_shape_to_bytes(shape: &SharedShape, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    match shape.as_typed_shape() {
+        TypedShape::Ball(ball) => {
+            0u8.append_to_bytes(bytes)?;
+            ball.radius.append_to_bytes(bytes)?;
+        },
+        TypedShape::Cuboid(cuboid) => {
+            1u8.append_to_bytes(bytes)?;
+            cuboid.half_extents.x.append_to_bytes(bytes)?;
+            cuboid.half_extents.y.append_to_bytes(bytes)?;
+            cuboid.half_extents.z.append_to_bytes(bytes)?;
+        },
+        TypedShape::Capsule(capsule) => {
+            2u8.append_to_bytes(bytes)?;
+            point_to_vec3(&capsule.segment.a).append_to_bytes(bytes)?;
+            point_to_vec3(&capsule.segment.b).append_to_bytes(bytes)?;
+            capsule.radius.append_to_bytes(bytes)?;
+        },
+        TypedShape::Cylinder(cylinder) => {
+            3u8.append_to_bytes(bytes)?;
+            cylinder.half_height.append_to_bytes(bytes)?;
+            cylinder.radius.append_to_bytes(bytes)?;
+        },
+        TypedShape::Cone(cone) => {
+            4u8.append_to_bytes(bytes)?;
+            cone.half_height.append_to_bytes(bytes)?;
+            cone.radius.append_to_bytes(bytes)?;
+        },
+        TypedShape::ConvexPolyhedron(convex_polyhedron) => {
+            // the faces are stored as triangles over the points so that the polyhedron is rebuilt as is instead of recomputing its hull
+            5u8.append_to_bytes(bytes)?;
+            let (points, indices) = convex_polyhedron.to_trimesh();
+            points.len().append_to_bytes(bytes)?;
+            for point in points.iter() {
+                point_to_vec3(point).append_to_bytes(bytes)?;
+            }
+            indices.append_to_bytes(bytes)?;
+        },
+        TypedShape::TriMesh(trimesh) => {
+            6u8.append_to_bytes(bytes)?;
+            trimesh.vertices().len().append_to_bytes(bytes)?;
+            for vertex in trimesh.vertices() {
+                point_to_vec3(vertex).append_to_bytes(bytes)?;
+            }
+            trimesh.indices().len().append_to_bytes(bytes)?;
+            for triangle_indices in trimesh.indices() {
+                triangle_indices.append_to_bytes(bytes)?;
+            }
+            trimesh.flags().bits().append_to_bytes(bytes)?;
+        },
+        TypedShape::Compound(compound) => {
+            7u8.append_to_bytes(bytes)?;
+            compound.shapes().len().append_to_bytes(bytes)?;
+            for (isometry, shape) in compound.shapes() {
+                Vec3::new(isometry.translation.x, isometry.translation.y, isometry.translation.z).append_to_bytes(bytes)?;
+                Quat::from_xyzw(isometry.rotation.i, isometry.rotation.j, isometry.rotation.k, isometry.rotation.w).append_to_bytes(bytes)?;
+                append_shared_shape_to_bytes(shape, bytes)?;
+            }
+        },
+        _ => {
+            255u8.append_to_bytes(bytes)?;
+            let serialized_bytes = bincode::serialize(shape)?;
+            serialized_bytes.append_to_bytes(bytes)?;
+        },
+    }
+    Ok(())
+}
+
+fn extract_shared_shape_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<SharedShape, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+    match enum_variant_byte {
+        0u8 => Ok(SharedShape::ball(Scalar::extract_from_bytes(bytes, index)?)),
+        1u8 => Ok(SharedShape::cuboid(
+            Scalar::extract_from_bytes(bytes, index)?,
+            Scalar::extract_from_bytes(bytes, index)?,
+            Scalar::extract_from_bytes(bytes, index)?,
+        )),
+        2u8 => Ok(SharedShape::capsule(
+            vec3_to_point(Vec3::extract_from_bytes(bytes, index)?),
+            vec3_to_point(Vec3::extract_from_bytes(bytes, index)?),
+            Scalar::extract_from_bytes(bytes, index)?,
+        )),
+        3u8 => Ok(SharedShape::cylinder(
+            Scalar::extract_from_bytes(bytes, index)?,
+            Scalar::extract_from_bytes(bytes, index)?,
+        )),
+        4u8 => Ok(SharedShape::cone(
+            Scalar::extract_from_bytes(bytes, index)?,
+            Scalar::extract_from_bytes(bytes, index)?,
+        )),
+        5u8 => {
+            let points = Vec::<Vec3>::extract_from_bytes(bytes, index)?
+                .into_iter()
+                .map(vec3_to_point)
+                .collect::<Vec<_>>();
+            let indices = Vec::<[u32; 3]>::extract_from_bytes(bytes, index)?;
+            if indices.iter().flatten().any(|vertex_index| *vertex_index as usize >= points.len()) {
+                return Err("Convex mesh index out of bounds.".into());
+            }
+            SharedShape::convex_mesh(points, &indices)
+                .ok_or_else(|| "Failed to build convex polyhedron from mesh.".into())
+        },
+        6u8 => {
+            let vertices = Vec::<Vec3>::extract_from_bytes(bytes, index)?
+                .into_iter()
+                .map(vec3_to_point)
+                .collect::<Vec<_>>();
+            let indices = Vec::<[u32; 3]>::extract_from_bytes(bytes, index)?;
+            if indices.is_empty() {
+                return Err("Trimesh has no triangles.".into());
+            }
+            if indices.iter().flatten().any(|vertex_index| *vertex_index as usize >= vertices.len()) {
+                return Err("Trimesh index out of bounds.".into());
+            }
+            let flags = TriMeshFlags::from_bits(u16::extract_from_bytes(bytes, index)?)
+                .ok_or("Unexpected trimesh flag bits.")?;
+            Ok(SharedShape::trimesh_with_flags(vertices, indices, flags))
+        },
+        7u8 => {
+            let shapes_length = usize::extract_from_bytes(bytes, index)?;
+            if shapes_length == 0 {
+                return Err("Compound shape has no shapes.".into());
+            }
+            let mut shapes = Vec::with_capacity(crate::base::get_capacity_hint::<(Isometry<Scalar>, SharedShape), _>(shapes_length, bytes, index));
+            for _ in 0..shapes_length {
+                let translation = Vec3::extract_from_bytes(bytes, index)?;
+                let rotation = Quat::extract_from_bytes(bytes, index)?;
+                let isometry = Isometry::from_parts(
+                    Translation::new(translation.x, translation.y, translation.z),
+                    UnitQuaternion::new_unchecked(Quaternion::new(rotation.w, rotation.x, rotation.y, rotation.z)),
+                );
+                let shape = extract_shared_shape_from_bytes(bytes, index)?;
+                if shape.as_composite_shape().is_some() {
+                    return Err("Nested composite shapes are not allowed in a compound shape.".into());
+                }
+                shapes.push((isometry, shape));
+            }
+            Ok(SharedShape::compound(shapes))
+        },
+        255u8 => {
+            let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+            Ok(bincode::deserialize::<SharedShape>(&serialized_bytes)?)
+        },
+        _ => Err("Unexpected enum variant byte.".into()),
     }
 }
 
 impl ByteConverter for Collider {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        let serialized_bytes = bincode::serialize(self)?;
-        serialized_bytes.append_to_bytes(bytes)?;
+        append_shared_shape_to_bytes(self.shape(), bytes)?;
+        self.scale().append_to_bytes(bytes)?;
         Ok(())
     }
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
-        Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
-    }
-}
-
-impl ByteConverter for ColliderAabb {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.min.append_to_bytes(bytes)?;
-        self.max.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self {
-            min: Vec3::extract_from_bytes(bytes, index)?,
-            max: Vec3::extract_from_bytes(bytes, index)?,
-        })
+        let shape = extract_shared_shape_from_bytes(bytes, index)?;
+        let scale = Vec3::extract_from_bytes(bytes, index)?;
+        let mut collider = Self::from(shape);
+        // the same subdivision count that avian uses when the shape of a scaled collider is replaced
+        collider.set_scale(scale, 10);
+        Ok(collider)
     }
 }
 
+// constructors that are not listed here, like the ones with vhacd parameters, fall back to bincode
 impl ByteConverter for ColliderConstructor {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        let serialized_bytes = bincode::serialize(self)?;
-        serialized_bytes.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
-        Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
-    }
-}
-
-impl ByteConverter for ColliderConstructorHierarchy {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.default_constructor.append_to_bytes(bytes)?;
-        self.default_layers.append_to_bytes(bytes)?;
-        self.default_density.append_to_bytes(bytes)?;
-        self.config.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self {
-            default_constructor: Option::<ColliderConstructor>::extract_from_bytes(bytes, index)?,
-            default_layers: CollisionLayers::extract_from_bytes(bytes, index)?,
-            default_density: ColliderDensity::extract_from_bytes(bytes, index)?,
-            config: bevy::platform::collections::HashMap::<String, Option<ColliderConstructorHierarchyConfig>>::extract_from_bytes(bytes, index)?,
-        })
-    }
-}
-
-impl ByteConverter for ColliderConstructorHierarchyConfig {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.constructor.append_to_bytes(bytes)?;
-        self.layers.append_to_bytes(bytes)?;
-        self.density.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self {
-            constructor: Option::<ColliderConstructor>::extract_from_bytes(bytes, index)?,
-            layers: Option::<CollisionLayers>::extract_from_bytes(bytes, index)?,
-            density: Option::<ColliderDensity>::extract_from_bytes(bytes, index)?,
-        })
-    }
-}
-
-impl ByteConverter for ColliderDensity {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(f32::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for ColliderDisabled {
-    fn append_to_bytes(&self, _bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        // nothing to serialize
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_bytes: &'a TBytes, _index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        // nothing to deserialize
-        Ok(Self)
-    }
-}
-
-impl ByteConverter for ColliderMarker {
-    fn append_to_bytes(&self, _bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        // nothing to serialize
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_bytes: &'a TBytes, _index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        // nothing to deserialize
-        Ok(Self)
-    }
-}
-
-impl ByteConverter for ColliderMassProperties {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        let serialized_bytes = bincode::serialize(self)?;
-        serialized_bytes.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
-        Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
-    }
-}
-
-impl ByteConverter for ColliderOf {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.body.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self {
-            body: Entity::extract_from_bytes(bytes, index)?,
-        })
-    }
-}
-
-impl ByteConverter for ColliderTransform {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.translation.append_to_bytes(bytes)?;
-        self.rotation.append_to_bytes(bytes)?;
-        self.scale.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self {
-            translation: Vec3::extract_from_bytes(bytes, index)?,
-            rotation: Rotation::extract_from_bytes(bytes, index)?,
-            scale: Vec3::extract_from_bytes(bytes, index)?,
-        })
-    }
-}
-
-impl ByteConverter for CollisionEnded {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        self.1.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(Entity::extract_from_bytes(bytes, index)?, Entity::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for CollisionLayers {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.memberships.append_to_bytes(bytes)?;
-        self.filters.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self {
-            memberships: LayerMask::extract_from_bytes(bytes, index)?,
-            filters: LayerMask::extract_from_bytes(bytes, index)?,
-        })
-    }
-}
-
-impl ByteConverter for CollisionMargin {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(Scalar::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for CollisionStarted {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        self.1.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(Entity::extract_from_bytes(bytes, index)?, Entity::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for DefaultFriction {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(Friction::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for DefaultRestitution {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(Restitution::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for ExternalForce {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        let serialized_bytes = bincode::serialize(self)?;
-        serialized_bytes.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
-        Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
-    }
-}
-
-impl ByteConverter for ExternalImpulse {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        let serialized_bytes = bincode::serialize(self)?;
-        serialized_bytes.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
-        Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
-    }
-}
-
-impl ByteConverter for ExternalTorque {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        let serialized_bytes = bincode::serialize(self)?;
-        serialized_bytes.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
-        Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
-    }
-}
-
-impl ByteConverter for Friction {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.dynamic_coefficient.append_to_bytes(bytes)?;
-        self.static_coefficient.append_to_bytes(bytes)?;
-        self.combine_rule.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self {
-            dynamic_coefficient: Scalar::extract_from_bytes(bytes, index)?,
-            static_coefficient: Scalar::extract_from_bytes(bytes, index)?,
-            combine_rule: CoefficientCombine::extract_from_bytes(bytes, index)?,
-        })
-    }
-}
-
-impl ByteConverter for Gravity {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(Vec3::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for GravityScale {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(f32::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for LayerMask {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(u32::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for LinearVelocity {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
+        match self {
+            Self::Sphere { radius } => {
+                0u8.append_to_bytes(bytes)?;
+                radius.append_to_bytes(bytes)?;
+            },
+            Self::Cuboid { x_length, y_length, z_length } => {
+                1u8.append_to_bytes(bytes)?;
+                x_length.append_to_bytes(bytes)?;
+                y_length.append_to_bytes(bytes)?;
+                z_length.append_to_bytes(bytes)?;
+            },
+            Self::RoundCuboid { x_length, y_length, z_length, border_radius } => {
+                2u8.append_to_bytes(bytes)?;
+                x_length.append_to_bytes(bytes)?;
+                y_length.append_to_bytes(bytes)?;
+                z_length.append_to_bytes(bytes)?;
+                border_radius.append_to_bytes(bytes)?;
+            },
+            Self::Cylinder { radius, height } => {
+                3u8.append_to_bytes(bytes)?;
+                radius.append_to_bytes(bytes)?;
+                height.append_to_bytes(bytes)?;
+            },
+            Self::Cone { radius, height } => {
+                4u8.append_to_bytes(bytes)?;
+                radius.append_to_bytes(bytes)?;
+                height.append_to_bytes(bytes)?;
+            },
+            Self::Capsule { radius, height } => {
+                5u8.append_to_bytes(bytes)?;
+                radius.append_to_bytes(bytes)?;
+                height.append_to_bytes(bytes)?;
+            },
+            Self::CapsuleEndpoints { radius, a, b } => {
+                6u8.append_to_bytes(bytes)?;
+                radius.append_to_bytes(bytes)?;
+                a.append_to_bytes(bytes)?;
+                b.append_to_bytes(bytes)?;
+            },
+            Self::HalfSpace { outward_normal } => {
+                7u8.append_to_bytes(bytes)?;
+                outward_normal.append_to_bytes(bytes)?;
+            },
+            Self::Segment { a, b } => {
+                8u8.append_to_bytes(bytes)?;
+                a.append_to_bytes(bytes)?;
+                b.append_to_bytes(bytes)?;
+            },
+            Self::Triangle { a, b, c } => {
+                9u8.append_to_bytes(bytes)?;
+                a.append_to_bytes(bytes)?;
+                b.append_to_bytes(bytes)?;
+                c.append_to_bytes(bytes)?;
+            },
+            Self::Polyline { vertices, indices } => {
+                10u8.append_to_bytes(bytes)?;
+                vertices.append_to_bytes(bytes)?;
+                indices.append_to_bytes(bytes)?;
+            },
+            Self::Trimesh { vertices, indices } => {
+                11u8.append_to_bytes(bytes)?;
+                vertices.append_to_bytes(bytes)?;
+                indices.append_to_bytes(bytes)?;
+            },
+            Self::TrimeshWithConfig { vertices, indices, flags } => {
+                12u8.append_to_bytes(bytes)?;
+                vertices.append_to_bytes(bytes)?;
+                indices.append_to_bytes(bytes)?;
+                flags.bits().append_to_bytes(bytes)?;
+            },
+            Self::ConvexDecomposition { vertices, indices } => {
+                13u8.append_to_bytes(bytes)?;
+                vertices.append_to_bytes(bytes)?;
+                indices.append_to_bytes(bytes)?;
+            },
+            Self::ConvexHull { points } => {
+                14u8.append_to_bytes(bytes)?;
+                points.append_to_bytes(bytes)?;
+            },
+            Self::Heightfield { heights, scale } => {
+                15u8.append_to_bytes(bytes)?;
+                heights.append_to_bytes(bytes)?;
+                scale.append_to_bytes(bytes)?;
+            },
+            Self::TrimeshFromMesh => 16u8.append_to_bytes(bytes)?,
+            Self::ConvexDecompositionFromMesh => 17u8.append_to_bytes(bytes)?,
+            Self::ConvexHullFromMesh => 18u8.append_to_bytes(bytes)?,
+            _ => {
+                255u8.append_to_bytes(bytes)?;
+                let serialized_bytes = bincode::serialize(self)?;
+                serialized_bytes.append_to_bytes(bytes)?;
+            },
+        }
         Ok(())
     }
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(Vec3::extract_from_bytes(bytes, index)?))
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Sphere {
+                radius: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            1u8 => Ok(Self::Cuboid {
+                x_length: Scalar::extract_from_bytes(bytes, index)?,
+                y_length: Scalar::extract_from_bytes(bytes, index)?,
+                z_length: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            2u8 => Ok(Self::RoundCuboid {
+                x_length: Scalar::extract_from_bytes(bytes, index)?,
+                y_length: Scalar::extract_from_bytes(bytes, index)?,
+                z_length: Scalar::extract_from_bytes(bytes, index)?,
+                border_radius: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            3u8 => Ok(Self::Cylinder {
+                radius: Scalar::extract_from_bytes(bytes, index)?,
+                height: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            4u8 => Ok(Self::Cone {
+                radius: Scalar::extract_from_bytes(bytes, index)?,
+                height: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            5u8 => Ok(Self::Capsule {
+                radius: Scalar::extract_from_bytes(bytes, index)?,
+                height: Scalar::extract_from_bytes(bytes, index)?,
+            }),
+            6u8 => Ok(Self::CapsuleEndpoints {
+                radius: Scalar::extract_from_bytes(bytes, index)?,
+                a: Vec3::extract_from_bytes(bytes, index)?,
+                b: Vec3::extract_from_bytes(bytes, index)?,
+            }),
+            7u8 => Ok(Self::HalfSpace {
+                outward_normal: Vec3::extract_from_bytes(bytes, index)?,
+            }),
+            8u8 => Ok(Self::Segment {
+                a: Vec3::extract_from_bytes(bytes, index)?,
+                b: Vec3::extract_from_bytes(bytes, index)?,
+            }),
+            9u8 => Ok(Self::Triangle {
+                a: Vec3::extract_from_bytes(bytes, index)?,
+                b: Vec3::extract_from_bytes(bytes, index)?,
+                c: Vec3::extract_from_bytes(bytes, index)?,
+            }),
+            10u8 => Ok(Self::Polyline {
+                vertices: Vec::<Vec3>::extract_from_bytes(bytes, index)?,
+                indices: Option::<Vec<[u32; 2]>>::extract_from_bytes(bytes, index)?,
+            }),
+            11u8 => Ok(Self::Trimesh {
+                vertices: Vec::<Vec3>::extract_from_bytes(bytes, index)?,
+                indices: Vec::<[u32; 3]>::extract_from_bytes(bytes, index)?,
+            }),
+            12u8 => Ok(Self::TrimeshWithConfig {
+                vertices: Vec::<Vec3>::extract_from_bytes(bytes, index)?,
+                indices: Vec::<[u32; 3]>::extract_from_bytes(bytes, index)?,
+                flags: TrimeshFlags::from_bits(u8::extract_from_bytes(bytes, index)?)
+                    .ok_or("Unexpected trimesh flag bits.")?,
+            }),
+            13u8 => Ok(Self::ConvexDecomposition {
+                vertices: Vec::<Vec3>::extract_from_bytes(bytes, index)?,
+                indices: Vec::<[u32; 3]>::extract_from_bytes(bytes, index)?,
+            }),
+            14u8 => Ok(Self::ConvexHull {
+                points: Vec::<Vec3>::extract_from_bytes(bytes, index)?,
+            }),
+            15u8 => Ok(Self::Heightfield {
+                heights: Vec::<Vec<Scalar>>::extract_from_bytes(bytes, index)?,
+                scale: Vec3::extract_from_bytes(bytes, index)?,
+            }),
+            16u8 => Ok(Self::TrimeshFromMesh),
+            17u8 => Ok(Self::ConvexDecompositionFromMesh),
+            18u8 => Ok(Self::ConvexHullFromMesh),
+            255u8 => {
+                let serialized_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+                Ok(bincode::deserialize::<Self>(&serialized_bytes)?)
+            },
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
     }
 }
 
-impl ByteConverter for Mass {
+impl ByteConverter for ComputedAngularInertia {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
+        self.inverse_tensor().append_to_bytes(bytes)?;
         Ok(())
     }
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(f32::extract_from_bytes(bytes, index)?))
+        Ok(Self::from_inverse_tensor(Mat3::extract_from_bytes(bytes, index)?))
     }
 }
 
@@ -367,85 +398,124 @@ impl ByteConverter for MassProperties3d {
     }
 }
 
-impl ByteConverter for Restitution {
+impl ByteConverter for RevoluteJoint {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.coefficient.append_to_bytes(bytes)?;
-        self.combine_rule.append_to_bytes(bytes)?;
+        self.entity1.append_to_bytes(bytes)?;
+        self.entity2.append_to_bytes(bytes)?;
+        self.local_anchor1.append_to_bytes(bytes)?;
+        self.local_anchor2.append_to_bytes(bytes)?;
+        self.aligned_axis.append_to_bytes(bytes)?;
+        self.angle_limit.append_to_bytes(bytes)?;
+        self.damping_linear.append_to_bytes(bytes)?;
+        self.damping_angular.append_to_bytes(bytes)?;
+        self.position_lagrange.append_to_bytes(bytes)?;
+        self.align_lagrange.append_to_bytes(bytes)?;
+        self.angle_limit_lagrange.append_to_bytes(bytes)?;
+        self.compliance.append_to_bytes(bytes)?;
+        self.force.append_to_bytes(bytes)?;
+        self.align_torque.append_to_bytes(bytes)?;
+        self.angle_limit_torque.append_to_bytes(bytes)?;
         Ok(())
     }
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
         Ok(Self {
-            coefficient: Scalar::extract_from_bytes(bytes, index)?,
-            combine_rule: CoefficientCombine::extract_from_bytes(bytes, index)?,
+            entity1: Entity::extract_from_bytes(bytes, index)?,
+            entity2: Entity::extract_from_bytes(bytes, index)?,
+            local_anchor1: Vec3::extract_from_bytes(bytes, index)?,
+            local_anchor2: Vec3::extract_from_bytes(bytes, index)?,
+            aligned_axis: Vec3::extract_from_bytes(bytes, index)?,
+            angle_limit: Option::<AngleLimit>::extract_from_bytes(bytes, index)?,
+            damping_linear: Scalar::extract_from_bytes(bytes, index)?,
+            damping_angular: Scalar::extract_from_bytes(bytes, index)?,
+            position_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+            align_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+            angle_limit_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+            compliance: Scalar::extract_from_bytes(bytes, index)?,
+            force: Vec3::extract_from_bytes(bytes, index)?,
+            align_torque: Vec3::extract_from_bytes(bytes, index)?,
+            angle_limit_torque: Vec3::extract_from_bytes(bytes, index)?,
         })
     }
 }
 
-impl ByteConverter for Sensor {
-    fn append_to_bytes(&self, _bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        // nothing to serialize
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_bytes: &'a TBytes, _index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        // nothing to deserialize
-        Ok(Self)
-    }
-}
-
-impl ByteConverter for Sleeping {
-    fn append_to_bytes(&self, _bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        // nothing to serialize
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_bytes: &'a TBytes, _index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        // nothing to deserialize
-        Ok(Self)
-    }
-}
-
-impl ByteConverter for SpeculativeMargin {
-    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
-        Ok(())
-    }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(f32::extract_from_bytes(bytes, index)?))
-    }
-}
-
-impl ByteConverter for Position {
+// only avian3d has spherical joints, so this lives outside of the shared avian impls
+impl ByteConverter for SphericalJoint {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        self.0.append_to_bytes(bytes)?;
+        self.entity1.append_to_bytes(bytes)?;
+        self.entity2.append_to_bytes(bytes)?;
+        self.local_anchor1.append_to_bytes(bytes)?;
+        self.local_anchor2.append_to_bytes(bytes)?;
+        self.swing_axis.append_to_bytes(bytes)?;
+        self.twist_axis.append_to_bytes(bytes)?;
+        self.swing_limit.append_to_bytes(bytes)?;
+        self.twist_limit.append_to_bytes(bytes)?;
+        self.damping_linear.append_to_bytes(bytes)?;
+        self.damping_angular.append_to_bytes(bytes)?;
+        self.position_lagrange.append_to_bytes(bytes)?;
+        self.swing_lagrange.append_to_bytes(bytes)?;
+        self.twist_lagrange.append_to_bytes(bytes)?;
+        self.compliance.append_to_bytes(bytes)?;
+        self.force.append_to_bytes(bytes)?;
+        self.swing_torque.append_to_bytes(bytes)?;
+        self.twist_torque.append_to_bytes(bytes)?;
         Ok(())
     }
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        Ok(Self(Vec3::extract_from_bytes(bytes, index)?))
+        Ok(Self {
+            entity1: Entity::extract_from_bytes(bytes, index)?,
+            entity2: Entity::extract_from_bytes(bytes, index)?,
+            local_anchor1: Vec3::extract_from_bytes(bytes, index)?,
+            local_anchor2: Vec3::extract_from_bytes(bytes, index)?,
+            swing_axis: Vector3::extract_from_bytes(bytes, index)?,
+            twist_axis: Vector3::extract_from_bytes(bytes, index)?,
+            swing_limit: Option::<AngleLimit>::extract_from_bytes(bytes, index)?,
+            twist_limit: Option::<AngleLimit>::extract_from_bytes(bytes, index)?,
+            damping_linear: Scalar::extract_from_bytes(bytes, index)?,
+            damping_angular: Scalar::extract_from_bytes(bytes, index)?,
+            position_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+            swing_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+            twist_lagrange: Scalar::extract_from_bytes(bytes, index)?,
+            compliance: Scalar::extract_from_bytes(bytes, index)?,
+            force: Vec3::extract_from_bytes(bytes, index)?,
+            swing_torque: Vec3::extract_from_bytes(bytes, index)?,
+            twist_torque: Vec3::extract_from_bytes(bytes, index)?,
+        })
     }
 }
 
-impl ByteConverter for RigidBody {
+// the global origin, rotation and direction are left out since avian recomputes them from the transform every frame
+impl ByteConverter for ShapeCaster {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        match self {
-            Self::Dynamic => {
-                0u8.append_to_bytes(bytes)?;
-            },
-            Self::Static => {
-                1u8.append_to_bytes(bytes)?;
-            },
-            Self::Kinematic => {
-                2u8.append_to_bytes(bytes)?;
-            },
-        }
+        self.enabled.append_to_bytes(bytes)?;
+        self.shape.append_to_bytes(bytes)?;
+        self.origin.append_to_bytes(bytes)?;
+        self.shape_rotation.append_to_bytes(bytes)?;
+        self.direction.append_to_bytes(bytes)?;
+        self.max_hits.append_to_bytes(bytes)?;
+        self.max_distance.append_to_bytes(bytes)?;
+        self.target_distance.append_to_bytes(bytes)?;
+        self.compute_contact_on_penetration.append_to_bytes(bytes)?;
+        self.ignore_origin_penetration.append_to_bytes(bytes)?;
+        self.ignore_self.append_to_bytes(bytes)?;
+        self.query_filter.append_to_bytes(bytes)?;
         Ok(())
     }
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
-        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
-        match enum_variant_byte {
-            0u8 => Ok(Self::Dynamic),
-            1u8 => Ok(Self::Static),
-            2u8 => Ok(Self::Kinematic),
-            _ => Err("Unexpected enum variant byte.".into()),
-        }
+        let enabled = bool::extract_from_bytes(bytes, index)?;
+        let shape = Collider::extract_from_bytes(bytes, index)?;
+        let origin = Vec3::extract_from_bytes(bytes, index)?;
+        let shape_rotation = Quat::extract_from_bytes(bytes, index)?;
+        let direction = Dir3::extract_from_bytes(bytes, index)?;
+        let mut shape_caster = Self::new(shape, origin, shape_rotation, direction)
+            .with_max_hits(u32::extract_from_bytes(bytes, index)?)
+            .with_max_distance(Scalar::extract_from_bytes(bytes, index)?)
+            .with_target_distance(Scalar::extract_from_bytes(bytes, index)?)
+            .with_compute_contact_on_penetration(bool::extract_from_bytes(bytes, index)?)
+            .with_ignore_origin_penetration(bool::extract_from_bytes(bytes, index)?)
+            .with_ignore_self(bool::extract_from_bytes(bytes, index)?)
+            .with_query_filter(SpatialQueryFilter::extract_from_bytes(bytes, index)?);
+        shape_caster.enabled = enabled;
+        Ok(shape_caster)
     }
 }
 
@@ -457,4 +527,4 @@ impl ByteConverter for Rotation {
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
         Ok(Self(Quat::extract_from_bytes(bytes, index)?))
     }
-}
\ No newline at end of file
+}
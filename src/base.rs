@@ -1,6 +1,18 @@
-use crate::{get_multiple_bytes, get_single_byte, ByteConverter, ByteConverterError, ByteStreamReader, ByteStreamWriter};
+use crate::{get_multiple_bytes, get_single_byte, BitConverter, BitReader, BitWriter, ByteConverter, ByteConverterError, ByteStreamReader, ByteStreamWriter, ContextualByteConverter, DeltaByteConverter};
 use std::{cell::RefCell, collections::{HashMap, VecDeque}, error::Error, ffi::CString, path::PathBuf, rc::Rc, sync::{Arc, Mutex, RwLock}, time::Duration};
 
+// the most memory that a collection reserves up front, after which it grows as its elements are actually extracted
+const MAX_PREALLOCATED_BYTES_LENGTH: usize = 1024 * 1024;
+
+// a corrupted or malicious length should not be able to reserve more elements than there are bytes left to extract them from
+// since the bytes left only bound the element count, large elements are also capped by the memory that they would reserve
+#[inline(always)]
+pub(crate) fn get_capacity_hint<TElement, TBytes: AsRef<[u8]>>(length: usize, bytes: &TBytes, index: &usize) -> usize {
+    length
+        .min(bytes.as_ref().len().saturating_sub(*index))
+        .min(MAX_PREALLOCATED_BYTES_LENGTH / std::mem::size_of::<TElement>().max(1))
+}
+
 impl ByteConverter for () {
     #[inline(always)]
     fn append_to_bytes(&self, _: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
@@ -373,7 +385,7 @@ where
     #[inline(always)]
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
         let length = usize::extract_from_bytes(bytes, index)?;
-        let mut output = HashMap::with_capacity(length);
+        let mut output = HashMap::with_capacity(get_capacity_hint::<(TKey, TValue), _>(length, bytes, index));
         for _ in 0..length {
             output.insert(
                 TKey::extract_from_bytes(bytes, index)?,
@@ -404,7 +416,7 @@ impl<T: ByteConverter> ByteConverter for Vec<T> {
         // usize
         let list_length = usize::extract_from_bytes(bytes, index)?;
 
-        let mut list = Vec::with_capacity(list_length);
+        let mut list = Vec::with_capacity(get_capacity_hint::<T, _>(list_length, bytes, index));
         for _ in 0..list_length {
             // T
             let list_element = T::extract_from_bytes(bytes, index)?;
@@ -415,6 +427,26 @@ impl<T: ByteConverter> ByteConverter for Vec<T> {
     }
 }
 
+impl<TContext: ?Sized, T: ContextualByteConverter<TContext>> ContextualByteConverter<TContext> for Vec<T> {
+    #[inline(always)]
+    fn append_to_bytes_with(&self, context: &TContext, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.len().append_to_bytes(bytes)?;
+        for element in self {
+            element.append_to_bytes_with(context, bytes)?;
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut TContext, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let list_length = usize::extract_from_bytes(bytes, index)?;
+        let mut list = Vec::with_capacity(get_capacity_hint::<T, _>(list_length, bytes, index));
+        for _ in 0..list_length {
+            list.push(T::extract_from_bytes_with(context, bytes, index)?);
+        }
+        Ok(list)
+    }
+}
+
 impl<T: ByteConverter> ByteConverter for VecDeque<T> {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
@@ -431,7 +463,7 @@ impl<T: ByteConverter> ByteConverter for VecDeque<T> {
     #[inline(always)]
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
         let vec_deque_length = usize::extract_from_bytes(bytes, index)?;
-        let mut vec_deque = VecDeque::with_capacity(vec_deque_length);
+        let mut vec_deque = VecDeque::with_capacity(get_capacity_hint::<T, _>(vec_deque_length, bytes, index));
         for _ in 0..vec_deque_length {
             let vec_deque_element = T::extract_from_bytes(bytes, index)?;
             vec_deque.push_back(vec_deque_element);
@@ -495,6 +527,39 @@ impl<T: ByteConverter> ByteConverter for Option<T> {
     }
 }
 
+impl<TContext: ?Sized, T: ContextualByteConverter<TContext>> ContextualByteConverter<TContext> for Option<T> {
+    #[inline(always)]
+    fn append_to_bytes_with(&self, context: &TContext, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            Some(inner) => {
+                0u8.append_to_bytes(bytes)?;
+                inner.append_to_bytes_with(context, bytes)?;
+            },
+            None => {
+                1u8.append_to_bytes(bytes)?;
+            }
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut TContext, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => {
+                Ok(Self::Some(T::extract_from_bytes_with(context, bytes, index)?))
+            },
+            1u8 => {
+                Ok(Self::None)
+            },
+            _ => {
+                Err(ByteConverterError::UnexpectedByteValueForOption {
+                    byte_value: enum_variant_byte,
+                }.into())
+            }
+        }
+    }
+}
+
 //impl<T, E> ByteConverter for Result<T, E>
 //where
 //    T: ByteConverter,
@@ -765,48 +830,23 @@ tuple_byte_converter!(0 T1, 1 T2, 2 T3, 3 T4, 4 T5, 5 T6, 6 T7, 7 T8, 8 T9, 9 T1
 //    }
 //}
 
+// the payload is read in bounded chunks so that a corrupted length can only allocate as much as the stream actually provides
+pub(crate) const STREAM_READ_CHUNK_SIZE: usize = 64 * 1024;
+
 impl<TRead: std::io::Read> ByteStreamReader for TRead {
     fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
-        let mut bytes = Vec::new();
-        const CHUNK_SIZE: usize = 1024;
-        let mut chunk = [0u8; CHUNK_SIZE];
-
         // read the number of bytes we are expecting to read as part of the deserialization to T
         let mut initial_packet = [0u8; 8];
-        let read_result = self.read_exact(&mut initial_packet);
-        if let Err(error) = read_result {
-            let result: Result<T, Box<dyn Error + Send + Sync + 'static>> = Err(Box::new(error));
-            return result;
-        }
-        let expected_bytes_length: u64 = u64::from_le_bytes(initial_packet);
-
-        // loop over self and read out bytes until we have reach the expected number of bytes
-        while (bytes.len() as u64) < expected_bytes_length {
-            let expected_remaining_bytes_length = expected_bytes_length - bytes.len() as u64;
-            if expected_remaining_bytes_length >= CHUNK_SIZE as u64 {
-                let read_bytes_length_result = self.read(&mut chunk);
-                if let Err(error) = read_bytes_length_result {
-                    let result: Result<T, Box<dyn Error + Send + Sync + 'static>> = Err(Box::new(error));
-                    return result;
-                };
-                let read_bytes_length = read_bytes_length_result.unwrap();
-                if read_bytes_length != 0 {
-                    bytes.extend_from_slice(&chunk[..read_bytes_length]);
-                }
-            }
-            else {
-                // the remaining bytes is less than the chunk size, so only grab the remaining bytes
-                let mut smaller_chunk = vec![0u8; expected_remaining_bytes_length as usize];
-                let read_bytes_length_result = self.read(&mut smaller_chunk);
-                if let Err(error) = read_bytes_length_result {
-                    let result: Result<T, Box<dyn Error + Send + Sync + 'static>> = Err(Box::new(error));
-                    return result;
-                };
-                let read_bytes_length = read_bytes_length_result.unwrap();
-                if read_bytes_length != 0 {
-                    bytes.extend_from_slice(&smaller_chunk[..read_bytes_length]);
-                }
-            }
+        self.read_exact(&mut initial_packet)?;
+        let expected_bytes_length = usize::try_from(u64::from_le_bytes(initial_packet))?;
+
+        // read_exact fails with UnexpectedEof instead of waiting forever once the stream ends early
+        let mut bytes = Vec::with_capacity(expected_bytes_length.min(STREAM_READ_CHUNK_SIZE));
+        while bytes.len() < expected_bytes_length {
+            let start_index = bytes.len();
+            let chunk_length = (expected_bytes_length - start_index).min(STREAM_READ_CHUNK_SIZE);
+            bytes.resize(start_index + chunk_length, 0);
+            self.read_exact(&mut bytes[start_index..])?;
         }
 
         // we are explicitly pulling out the exact number of bytes for T to deserialize
@@ -814,12 +854,31 @@ impl<TRead: std::io::Read> ByteStreamReader for TRead {
     }
 }
 
+// the length is written in front of the bytes in place so that any number of frames can go out in a single write_all
+#[inline(always)]
+pub(crate) fn append_stream_frame(bytes: &mut Vec<u8>, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let length_index = bytes.len();
+    bytes.extend_from_slice(&[0u8; 8]);
+    byte_converter.append_to_bytes(bytes)?;
+    let stream_bytes_length = (bytes.len() - length_index - 8) as u64;
+    bytes[length_index..length_index + 8].copy_from_slice(&stream_bytes_length.to_le_bytes());
+    Ok(())
+}
+
+// flushing is left to the caller so that a writer such as BufWriter can keep batching frames
 impl<TWrite: std::io::Write> ByteStreamWriter for TWrite {
     fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
-        let stream_bytes = byte_converter.to_vec_bytes()?;
-        let stream_bytes_length: u64 = stream_bytes.len() as u64;
-        self.write(&stream_bytes_length.to_le_bytes())?;
-        self.write(&stream_bytes)?;
+        let mut stream_bytes = Vec::new();
+        append_stream_frame(&mut stream_bytes, byte_converter)?;
+        self.write_all(&stream_bytes)?;
+        Ok(())
+    }
+    fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let mut stream_bytes = Vec::new();
+        for byte_converter in byte_converters {
+            append_stream_frame(&mut stream_bytes, byte_converter)?;
+        }
+        self.write_all(&stream_bytes)?;
         Ok(())
     }
 }
@@ -860,4 +919,362 @@ impl ByteConverter for Duration {
             u32::extract_from_bytes(bytes, index)?,
         ))
     }
-}
\ No newline at end of file
+}
+
+// unchanged values are a single byte while changed values are that byte followed by the whole value
+// a container that already knows the value changed writes just the whole value
+macro_rules! whole_value_delta_byte_converter {
+    ($t:ty, |$value:ident, $baseline:ident| $has_changed:expr) => {
+        impl DeltaByteConverter for $t {
+            #[inline(always)]
+            fn has_changed(&self, baseline: &Self) -> bool {
+                let ($value, $baseline) = (self, baseline);
+                $has_changed
+            }
+            #[inline(always)]
+            fn append_delta(&self, baseline: &Self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+                if self.has_changed(baseline) {
+                    true.append_to_bytes(bytes)?;
+                    self.append_to_bytes(bytes)?;
+                }
+                else {
+                    false.append_to_bytes(bytes)?;
+                }
+                Ok(())
+            }
+            #[inline(always)]
+            fn apply_delta<'a, TBytes: AsRef<[u8]>>(baseline: &Self, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+                if bool::extract_from_bytes(bytes, index)? {
+                    Self::extract_from_bytes(bytes, index)
+                }
+                else {
+                    Ok(baseline.clone())
+                }
+            }
+            #[inline(always)]
+            fn append_changed_delta(&self, _baseline: &Self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+                self.append_to_bytes(bytes)
+            }
+            #[inline(always)]
+            fn apply_changed_delta<'a, TBytes: AsRef<[u8]>>(_baseline: &Self, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+                Self::extract_from_bytes(bytes, index)
+            }
+        }
+    };
+    ($($t:ty),+) => {
+        $(
+            whole_value_delta_byte_converter!($t, |value, baseline| value != baseline);
+        )+
+    };
+}
+
+whole_value_delta_byte_converter!(bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, String, Duration);
+// floats are compared by their bits so that -0.0 and NaN payloads are kept as they are
+whole_value_delta_byte_converter!(f32, |value, baseline| value.to_bits() != baseline.to_bits());
+whole_value_delta_byte_converter!(f64, |value, baseline| value.to_bits() != baseline.to_bits());
+
+impl<T: DeltaByteConverter + Clone> DeltaByteConverter for Option<T> {
+    #[inline(always)]
+    fn has_changed(&self, baseline: &Self) -> bool {
+        match (self, baseline) {
+            (None, None) => false,
+            (Some(value), Some(baseline_value)) => value.has_changed(baseline_value),
+            _ => true,
+        }
+    }
+    #[inline(always)]
+    fn append_delta(&self, baseline: &Self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        match (self, baseline) {
+            (None, _) => 0u8.append_to_bytes(bytes)?,
+            (Some(value), Some(baseline_value)) => {
+                1u8.append_to_bytes(bytes)?;
+                value.append_delta(baseline_value, bytes)?;
+            },
+            (Some(value), None) => {
+                2u8.append_to_bytes(bytes)?;
+                value.append_to_bytes(bytes)?;
+            },
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn apply_delta<'a, TBytes: AsRef<[u8]>>(baseline: &Self, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(None),
+            1u8 => {
+                let baseline_value = baseline.as_ref()
+                    .ok_or("The delta expects a baseline value that is Some.")?;
+                Ok(Some(T::apply_delta(baseline_value, bytes, index)?))
+            },
+            2u8 => Ok(Some(T::extract_from_bytes(bytes, index)?)),
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
+    }
+}
+
+// the new length is followed by the changed elements within the length that both share and then the appended elements
+impl<T: DeltaByteConverter + Clone> DeltaByteConverter for Vec<T> {
+    #[inline(always)]
+    fn has_changed(&self, baseline: &Self) -> bool {
+        self.len() != baseline.len() || self.iter().zip(baseline.iter()).any(|(element, baseline_element)| element.has_changed(baseline_element))
+    }
+    #[inline(always)]
+    fn append_delta(&self, baseline: &Self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.len().append_to_bytes(bytes)?;
+        let changed_indices = self.iter()
+            .zip(baseline.iter())
+            .enumerate()
+            .filter(|(_, (element, baseline_element))| element.has_changed(baseline_element))
+            .map(|(element_index, _)| element_index)
+            .collect::<Vec<_>>();
+        changed_indices.len().append_to_bytes(bytes)?;
+        for element_index in changed_indices {
+            element_index.append_to_bytes(bytes)?;
+            self[element_index].append_changed_delta(&baseline[element_index], bytes)?;
+        }
+        for element in self.iter().skip(baseline.len()) {
+            element.append_to_bytes(bytes)?;
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn apply_delta<'a, TBytes: AsRef<[u8]>>(baseline: &Self, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let list_length = usize::extract_from_bytes(bytes, index)?;
+        let mut list = Vec::with_capacity(get_capacity_hint::<T, _>(list_length, bytes, index));
+        list.extend_from_slice(&baseline[..list_length.min(baseline.len())]);
+        let changed_elements_length = usize::extract_from_bytes(bytes, index)?;
+        for _ in 0..changed_elements_length {
+            let element_index = usize::extract_from_bytes(bytes, index)?;
+            if element_index >= list.len() {
+                return Err(ByteConverterError::IndexOutOfRange {
+                    index: element_index,
+                    length: list.len(),
+                }.into());
+            }
+            list[element_index] = T::apply_changed_delta(&baseline[element_index], bytes, index)?;
+        }
+        while list.len() < list_length {
+            list.push(T::extract_from_bytes(bytes, index)?);
+        }
+        Ok(list)
+    }
+}
+
+// the removed keys are followed by the changed entries and then the added entries
+impl<TKey, TValue> DeltaByteConverter for HashMap<TKey, TValue>
+where
+    TKey: ByteConverter + Eq + std::hash::Hash + Clone,
+    TValue: DeltaByteConverter + Clone,
+{
+    #[inline(always)]
+    fn has_changed(&self, baseline: &Self) -> bool {
+        self.len() != baseline.len() || self.iter().any(|(key, value)| {
+            baseline.get(key)
+                .map(|baseline_value| value.has_changed(baseline_value))
+                .unwrap_or(true)
+        })
+    }
+    #[inline(always)]
+    fn append_delta(&self, baseline: &Self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let removed_keys = baseline.keys()
+            .filter(|key| !self.contains_key(key))
+            .collect::<Vec<_>>();
+        removed_keys.len().append_to_bytes(bytes)?;
+        for key in removed_keys {
+            key.append_to_bytes(bytes)?;
+        }
+        let mut changed_entries = Vec::new();
+        let mut added_entries = Vec::new();
+        for (key, value) in self {
+            match baseline.get(key) {
+                Some(baseline_value) => {
+                    if value.has_changed(baseline_value) {
+                        changed_entries.push((key, value, baseline_value));
+                    }
+                },
+                None => added_entries.push((key, value)),
+            }
+        }
+        changed_entries.len().append_to_bytes(bytes)?;
+        for (key, value, baseline_value) in changed_entries {
+            key.append_to_bytes(bytes)?;
+            value.append_changed_delta(baseline_value, bytes)?;
+        }
+        added_entries.len().append_to_bytes(bytes)?;
+        for (key, value) in added_entries {
+            key.append_to_bytes(bytes)?;
+            value.append_to_bytes(bytes)?;
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn apply_delta<'a, TBytes: AsRef<[u8]>>(baseline: &Self, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let mut output = baseline.clone();
+        let removed_keys_length = usize::extract_from_bytes(bytes, index)?;
+        for _ in 0..removed_keys_length {
+            let key = TKey::extract_from_bytes(bytes, index)?;
+            output.remove(&key);
+        }
+        let changed_entries_length = usize::extract_from_bytes(bytes, index)?;
+        for _ in 0..changed_entries_length {
+            let key = TKey::extract_from_bytes(bytes, index)?;
+            let baseline_value = baseline.get(&key)
+                .ok_or("The delta changes a key that does not exist in the baseline.")?;
+            let value = TValue::apply_changed_delta(baseline_value, bytes, index)?;
+            output.insert(key, value);
+        }
+        let added_entries_length = usize::extract_from_bytes(bytes, index)?;
+        for _ in 0..added_entries_length {
+            output.insert(
+                TKey::extract_from_bytes(bytes, index)?,
+                TValue::extract_from_bytes(bytes, index)?,
+            );
+        }
+        Ok(output)
+    }
+}
+
+macro_rules! integer_bit_converter {
+    ($($t:ty => $unsigned:ty),+ $(,)?) => {
+        $(
+            impl BitConverter for $t {
+                const BIT_LENGTH: u32 = <$t>::BITS;
+                #[inline(always)]
+                fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+                    bit_writer.write_bits(*self as $unsigned as u64, Self::BIT_LENGTH);
+                    Ok(())
+                }
+                #[inline(always)]
+                fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+                    Ok(bit_reader.read_bits(Self::BIT_LENGTH)? as $unsigned as $t)
+                }
+            }
+        )+
+    };
+}
+
+integer_bit_converter!(
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+);
+
+impl BitConverter for bool {
+    const BIT_LENGTH: u32 = 1;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        bit_writer.write_bool(*self);
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        bit_reader.read_bool()
+    }
+}
+
+// every unicode scalar value fits within 21 bits
+impl BitConverter for char {
+    const BIT_LENGTH: u32 = 21;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        bit_writer.write_bits(*self as u64, Self::BIT_LENGTH);
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let code_point = bit_reader.read_bits(Self::BIT_LENGTH)? as u32;
+        char::from_u32(code_point)
+            .ok_or_else(|| format!("Invalid char code point {code_point}.").into())
+    }
+}
+
+impl BitConverter for f32 {
+    const BIT_LENGTH: u32 = 32;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        bit_writer.write_bits(self.to_bits() as u64, Self::BIT_LENGTH);
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(f32::from_bits(bit_reader.read_bits(Self::BIT_LENGTH)? as u32))
+    }
+}
+
+impl BitConverter for f64 {
+    const BIT_LENGTH: u32 = 64;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        bit_writer.write_bits(self.to_bits(), Self::BIT_LENGTH);
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(f64::from_bits(bit_reader.read_bits(Self::BIT_LENGTH)?))
+    }
+}
+
+// None is padded with zero bits so that the length stays fixed
+impl<T: BitConverter> BitConverter for Option<T> {
+    const BIT_LENGTH: u32 = 1 + T::BIT_LENGTH;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            Some(value) => {
+                bit_writer.write_bool(true);
+                value.append_to_bits(bit_writer)?;
+            },
+            None => {
+                bit_writer.write_bool(false);
+                let mut remaining_bit_length = T::BIT_LENGTH;
+                while remaining_bit_length != 0 {
+                    let bit_count = remaining_bit_length.min(64);
+                    bit_writer.write_bits(0, bit_count);
+                    remaining_bit_length -= bit_count;
+                }
+            },
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        if bit_reader.read_bool()? {
+            Ok(Some(T::extract_from_bits(bit_reader)?))
+        }
+        else {
+            let mut remaining_bit_length = T::BIT_LENGTH;
+            while remaining_bit_length != 0 {
+                let bit_count = remaining_bit_length.min(64);
+                bit_reader.read_bits(bit_count)?;
+                remaining_bit_length -= bit_count;
+            }
+            Ok(None)
+        }
+    }
+}
+
+impl<T: BitConverter, const C: usize> BitConverter for [T; C] {
+    const BIT_LENGTH: u32 = C as u32 * T::BIT_LENGTH;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        for element in self {
+            element.append_to_bits(bit_writer)?;
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let mut elements = Vec::with_capacity(C);
+        for _ in 0..C {
+            elements.push(T::extract_from_bits(bit_reader)?);
+        }
+        elements.try_into()
+            .map_err(|_| "Failed to convert the extracted elements into an array.".into())
+    }
+}
@@ -1,6 +1,6 @@
-use bevy::{asset::uuid::Uuid, input::{keyboard::NativeKeyCode, mouse::MouseScrollUnit}, math::{Affine3, FloatOrd}, pbr::wireframe::{ExtractedWireframeColor, Mesh3dWireframe, NoWireframe, Wireframe, WireframeColor, WireframeConfig, WireframeMaterial}, picking::{backend::HitData, pointer::{Location, PointerId, PointerLocation}}, prelude::*, render::camera::{ImageRenderTarget, ManualTextureViewHandle, NormalizedRenderTarget}, text::{FontSmoothing, LineHeight}, ui::{FocusPolicy, RelativeCursorPosition}, window::NormalizedWindowRef};
-use crate::ByteConverter;
-use std::{convert::Infallible, error::Error, time::Duration};
+use bevy::{asset::uuid::Uuid, ecs::entity::EntityMapper, input::{gamepad::{GamepadAxisChangedEvent, GamepadButtonChangedEvent, GamepadButtonStateChangedEvent, GamepadConnection, GamepadConnectionEvent, GamepadEvent}, keyboard::{Key, KeyboardInput, NativeKey, NativeKeyCode}, mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel}, touch::{ForceTouch, TouchPhase}, ButtonState}, math::{Affine3, FloatOrd}, pbr::wireframe::{ExtractedWireframeColor, Mesh3dWireframe, NoWireframe, Wireframe, WireframeColor, WireframeConfig, WireframeMaterial}, picking::{backend::HitData, pointer::{Location, PointerId, PointerLocation}}, prelude::*, render::camera::{ImageRenderTarget, ManualTextureViewHandle, NormalizedRenderTarget}, text::{FontSmoothing, LineHeight}, time::{TimeSystem, TimeUpdateStrategy}, ui::{FocusPolicy, RelativeCursorPosition}, window::NormalizedWindowRef};
+use crate::{BitConverter, BitReader, BitWriter, ByteConverter, ContextualByteConverter};
+use std::{any::TypeId, convert::Infallible, error::Error, marker::PhantomData, path::PathBuf, sync::Mutex, time::Duration};
 
 impl ByteConverter for KeyCode {
     #[inline(always)]
@@ -492,6 +492,39 @@ impl ByteConverter for MouseButton {
     }
 }
 
+// the button id of Other is always written so that the length stays fixed
+impl BitConverter for MouseButton {
+    const BIT_LENGTH: u32 = 3 + u16::BIT_LENGTH;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let (enum_variant_bits, mouse_button_id) = match self {
+            Self::Back => (0, 0),
+            Self::Forward => (1, 0),
+            Self::Left => (2, 0),
+            Self::Middle => (3, 0),
+            Self::Other(mouse_button_id) => (4, *mouse_button_id),
+            Self::Right => (5, 0),
+        };
+        bit_writer.write_bits(enum_variant_bits, 3);
+        mouse_button_id.append_to_bits(bit_writer)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_bits = bit_reader.read_bits(3)?;
+        let mouse_button_id = u16::extract_from_bits(bit_reader)?;
+        match enum_variant_bits {
+            0 => Ok(Self::Back),
+            1 => Ok(Self::Forward),
+            2 => Ok(Self::Left),
+            3 => Ok(Self::Middle),
+            4 => Ok(Self::Other(mouse_button_id)),
+            5 => Ok(Self::Right),
+            _ => Err("Unexpected enum variant bits.".into()),
+        }
+    }
+}
+
 impl ByteConverter for MouseScrollUnit {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
@@ -512,6 +545,1147 @@ impl ByteConverter for MouseScrollUnit {
     }
 }
 
+impl ByteConverter for NativeKey {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Unidentified => 0u8.append_to_bytes(bytes)?,
+            Self::Android(scan_code) => {
+                1u8.append_to_bytes(bytes)?;
+                scan_code.append_to_bytes(bytes)?;
+            },
+            Self::MacOS(scan_code) => {
+                2u8.append_to_bytes(bytes)?;
+                scan_code.append_to_bytes(bytes)?;
+            },
+            Self::Windows(scan_code) => {
+                3u8.append_to_bytes(bytes)?;
+                scan_code.append_to_bytes(bytes)?;
+            },
+            Self::Xkb(key_code) => {
+                4u8.append_to_bytes(bytes)?;
+                key_code.append_to_bytes(bytes)?;
+            },
+            Self::Web(code) => {
+                5u8.append_to_bytes(bytes)?;
+                String::from(code.as_str()).append_to_bytes(bytes)?;
+            },
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Unidentified),
+            1u8 => Ok(Self::Android(u32::extract_from_bytes(bytes, index)?)),
+            2u8 => Ok(Self::MacOS(u16::extract_from_bytes(bytes, index)?)),
+            3u8 => Ok(Self::Windows(u16::extract_from_bytes(bytes, index)?)),
+            4u8 => Ok(Self::Xkb(u32::extract_from_bytes(bytes, index)?)),
+            5u8 => Ok(Self::Web(String::extract_from_bytes(bytes, index)?.into())),
+            _ => Err("Unexpected enum variant byte".into()),
+        }
+    }
+}
+
+impl ByteConverter for Key {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Character(character) => {
+                0u16.append_to_bytes(bytes)?;
+                String::from(character.as_str()).append_to_bytes(bytes)?;
+            },
+            Self::Unidentified(native_key) => {
+                1u16.append_to_bytes(bytes)?;
+                native_key.append_to_bytes(bytes)?;
+            },
+            Self::Dead(character) => {
+                2u16.append_to_bytes(bytes)?;
+                character.append_to_bytes(bytes)?;
+            },
+            Self::Alt => 3u16.append_to_bytes(bytes)?,
+            Self::AltGraph => 4u16.append_to_bytes(bytes)?,
+            Self::CapsLock => 5u16.append_to_bytes(bytes)?,
+            Self::Control => 6u16.append_to_bytes(bytes)?,
+            Self::Fn => 7u16.append_to_bytes(bytes)?,
+            Self::FnLock => 8u16.append_to_bytes(bytes)?,
+            Self::NumLock => 9u16.append_to_bytes(bytes)?,
+            Self::ScrollLock => 10u16.append_to_bytes(bytes)?,
+            Self::Shift => 11u16.append_to_bytes(bytes)?,
+            Self::Symbol => 12u16.append_to_bytes(bytes)?,
+            Self::SymbolLock => 13u16.append_to_bytes(bytes)?,
+            Self::Meta => 14u16.append_to_bytes(bytes)?,
+            Self::Hyper => 15u16.append_to_bytes(bytes)?,
+            Self::Super => 16u16.append_to_bytes(bytes)?,
+            Self::Enter => 17u16.append_to_bytes(bytes)?,
+            Self::Tab => 18u16.append_to_bytes(bytes)?,
+            Self::Space => 19u16.append_to_bytes(bytes)?,
+            Self::ArrowDown => 20u16.append_to_bytes(bytes)?,
+            Self::ArrowLeft => 21u16.append_to_bytes(bytes)?,
+            Self::ArrowRight => 22u16.append_to_bytes(bytes)?,
+            Self::ArrowUp => 23u16.append_to_bytes(bytes)?,
+            Self::End => 24u16.append_to_bytes(bytes)?,
+            Self::Home => 25u16.append_to_bytes(bytes)?,
+            Self::PageDown => 26u16.append_to_bytes(bytes)?,
+            Self::PageUp => 27u16.append_to_bytes(bytes)?,
+            Self::Backspace => 28u16.append_to_bytes(bytes)?,
+            Self::Clear => 29u16.append_to_bytes(bytes)?,
+            Self::Copy => 30u16.append_to_bytes(bytes)?,
+            Self::CrSel => 31u16.append_to_bytes(bytes)?,
+            Self::Cut => 32u16.append_to_bytes(bytes)?,
+            Self::Delete => 33u16.append_to_bytes(bytes)?,
+            Self::EraseEof => 34u16.append_to_bytes(bytes)?,
+            Self::ExSel => 35u16.append_to_bytes(bytes)?,
+            Self::Insert => 36u16.append_to_bytes(bytes)?,
+            Self::Paste => 37u16.append_to_bytes(bytes)?,
+            Self::Redo => 38u16.append_to_bytes(bytes)?,
+            Self::Undo => 39u16.append_to_bytes(bytes)?,
+            Self::Accept => 40u16.append_to_bytes(bytes)?,
+            Self::Again => 41u16.append_to_bytes(bytes)?,
+            Self::Attn => 42u16.append_to_bytes(bytes)?,
+            Self::Cancel => 43u16.append_to_bytes(bytes)?,
+            Self::ContextMenu => 44u16.append_to_bytes(bytes)?,
+            Self::Escape => 45u16.append_to_bytes(bytes)?,
+            Self::Execute => 46u16.append_to_bytes(bytes)?,
+            Self::Find => 47u16.append_to_bytes(bytes)?,
+            Self::Help => 48u16.append_to_bytes(bytes)?,
+            Self::Pause => 49u16.append_to_bytes(bytes)?,
+            Self::Play => 50u16.append_to_bytes(bytes)?,
+            Self::Props => 51u16.append_to_bytes(bytes)?,
+            Self::Select => 52u16.append_to_bytes(bytes)?,
+            Self::ZoomIn => 53u16.append_to_bytes(bytes)?,
+            Self::ZoomOut => 54u16.append_to_bytes(bytes)?,
+            Self::BrightnessDown => 55u16.append_to_bytes(bytes)?,
+            Self::BrightnessUp => 56u16.append_to_bytes(bytes)?,
+            Self::Eject => 57u16.append_to_bytes(bytes)?,
+            Self::LogOff => 58u16.append_to_bytes(bytes)?,
+            Self::Power => 59u16.append_to_bytes(bytes)?,
+            Self::PowerOff => 60u16.append_to_bytes(bytes)?,
+            Self::PrintScreen => 61u16.append_to_bytes(bytes)?,
+            Self::Hibernate => 62u16.append_to_bytes(bytes)?,
+            Self::Standby => 63u16.append_to_bytes(bytes)?,
+            Self::WakeUp => 64u16.append_to_bytes(bytes)?,
+            Self::AllCandidates => 65u16.append_to_bytes(bytes)?,
+            Self::Alphanumeric => 66u16.append_to_bytes(bytes)?,
+            Self::CodeInput => 67u16.append_to_bytes(bytes)?,
+            Self::Compose => 68u16.append_to_bytes(bytes)?,
+            Self::Convert => 69u16.append_to_bytes(bytes)?,
+            Self::FinalMode => 70u16.append_to_bytes(bytes)?,
+            Self::GroupFirst => 71u16.append_to_bytes(bytes)?,
+            Self::GroupLast => 72u16.append_to_bytes(bytes)?,
+            Self::GroupNext => 73u16.append_to_bytes(bytes)?,
+            Self::GroupPrevious => 74u16.append_to_bytes(bytes)?,
+            Self::ModeChange => 75u16.append_to_bytes(bytes)?,
+            Self::NextCandidate => 76u16.append_to_bytes(bytes)?,
+            Self::NonConvert => 77u16.append_to_bytes(bytes)?,
+            Self::PreviousCandidate => 78u16.append_to_bytes(bytes)?,
+            Self::Process => 79u16.append_to_bytes(bytes)?,
+            Self::SingleCandidate => 80u16.append_to_bytes(bytes)?,
+            Self::HangulMode => 81u16.append_to_bytes(bytes)?,
+            Self::HanjaMode => 82u16.append_to_bytes(bytes)?,
+            Self::JunjaMode => 83u16.append_to_bytes(bytes)?,
+            Self::Eisu => 84u16.append_to_bytes(bytes)?,
+            Self::Hankaku => 85u16.append_to_bytes(bytes)?,
+            Self::Hiragana => 86u16.append_to_bytes(bytes)?,
+            Self::HiraganaKatakana => 87u16.append_to_bytes(bytes)?,
+            Self::KanaMode => 88u16.append_to_bytes(bytes)?,
+            Self::KanjiMode => 89u16.append_to_bytes(bytes)?,
+            Self::Katakana => 90u16.append_to_bytes(bytes)?,
+            Self::Romaji => 91u16.append_to_bytes(bytes)?,
+            Self::Zenkaku => 92u16.append_to_bytes(bytes)?,
+            Self::ZenkakuHankaku => 93u16.append_to_bytes(bytes)?,
+            Self::Soft1 => 94u16.append_to_bytes(bytes)?,
+            Self::Soft2 => 95u16.append_to_bytes(bytes)?,
+            Self::Soft3 => 96u16.append_to_bytes(bytes)?,
+            Self::Soft4 => 97u16.append_to_bytes(bytes)?,
+            Self::ChannelDown => 98u16.append_to_bytes(bytes)?,
+            Self::ChannelUp => 99u16.append_to_bytes(bytes)?,
+            Self::Close => 100u16.append_to_bytes(bytes)?,
+            Self::MailForward => 101u16.append_to_bytes(bytes)?,
+            Self::MailReply => 102u16.append_to_bytes(bytes)?,
+            Self::MailSend => 103u16.append_to_bytes(bytes)?,
+            Self::MediaClose => 104u16.append_to_bytes(bytes)?,
+            Self::MediaFastForward => 105u16.append_to_bytes(bytes)?,
+            Self::MediaPause => 106u16.append_to_bytes(bytes)?,
+            Self::MediaPlay => 107u16.append_to_bytes(bytes)?,
+            Self::MediaPlayPause => 108u16.append_to_bytes(bytes)?,
+            Self::MediaRecord => 109u16.append_to_bytes(bytes)?,
+            Self::MediaRewind => 110u16.append_to_bytes(bytes)?,
+            Self::MediaStop => 111u16.append_to_bytes(bytes)?,
+            Self::MediaTrackNext => 112u16.append_to_bytes(bytes)?,
+            Self::MediaTrackPrevious => 113u16.append_to_bytes(bytes)?,
+            Self::New => 114u16.append_to_bytes(bytes)?,
+            Self::Open => 115u16.append_to_bytes(bytes)?,
+            Self::Print => 116u16.append_to_bytes(bytes)?,
+            Self::Save => 117u16.append_to_bytes(bytes)?,
+            Self::SpellCheck => 118u16.append_to_bytes(bytes)?,
+            Self::Key11 => 119u16.append_to_bytes(bytes)?,
+            Self::Key12 => 120u16.append_to_bytes(bytes)?,
+            Self::AudioBalanceLeft => 121u16.append_to_bytes(bytes)?,
+            Self::AudioBalanceRight => 122u16.append_to_bytes(bytes)?,
+            Self::AudioBassBoostDown => 123u16.append_to_bytes(bytes)?,
+            Self::AudioBassBoostToggle => 124u16.append_to_bytes(bytes)?,
+            Self::AudioBassBoostUp => 125u16.append_to_bytes(bytes)?,
+            Self::AudioFaderFront => 126u16.append_to_bytes(bytes)?,
+            Self::AudioFaderRear => 127u16.append_to_bytes(bytes)?,
+            Self::AudioSurroundModeNext => 128u16.append_to_bytes(bytes)?,
+            Self::AudioTrebleDown => 129u16.append_to_bytes(bytes)?,
+            Self::AudioTrebleUp => 130u16.append_to_bytes(bytes)?,
+            Self::AudioVolumeDown => 131u16.append_to_bytes(bytes)?,
+            Self::AudioVolumeUp => 132u16.append_to_bytes(bytes)?,
+            Self::AudioVolumeMute => 133u16.append_to_bytes(bytes)?,
+            Self::MicrophoneToggle => 134u16.append_to_bytes(bytes)?,
+            Self::MicrophoneVolumeDown => 135u16.append_to_bytes(bytes)?,
+            Self::MicrophoneVolumeUp => 136u16.append_to_bytes(bytes)?,
+            Self::MicrophoneVolumeMute => 137u16.append_to_bytes(bytes)?,
+            Self::SpeechCorrectionList => 138u16.append_to_bytes(bytes)?,
+            Self::SpeechInputToggle => 139u16.append_to_bytes(bytes)?,
+            Self::LaunchApplication1 => 140u16.append_to_bytes(bytes)?,
+            Self::LaunchApplication2 => 141u16.append_to_bytes(bytes)?,
+            Self::LaunchCalendar => 142u16.append_to_bytes(bytes)?,
+            Self::LaunchContacts => 143u16.append_to_bytes(bytes)?,
+            Self::LaunchMail => 144u16.append_to_bytes(bytes)?,
+            Self::LaunchMediaPlayer => 145u16.append_to_bytes(bytes)?,
+            Self::LaunchMusicPlayer => 146u16.append_to_bytes(bytes)?,
+            Self::LaunchPhone => 147u16.append_to_bytes(bytes)?,
+            Self::LaunchScreenSaver => 148u16.append_to_bytes(bytes)?,
+            Self::LaunchSpreadsheet => 149u16.append_to_bytes(bytes)?,
+            Self::LaunchWebBrowser => 150u16.append_to_bytes(bytes)?,
+            Self::LaunchWebCam => 151u16.append_to_bytes(bytes)?,
+            Self::LaunchWordProcessor => 152u16.append_to_bytes(bytes)?,
+            Self::BrowserBack => 153u16.append_to_bytes(bytes)?,
+            Self::BrowserFavorites => 154u16.append_to_bytes(bytes)?,
+            Self::BrowserForward => 155u16.append_to_bytes(bytes)?,
+            Self::BrowserHome => 156u16.append_to_bytes(bytes)?,
+            Self::BrowserRefresh => 157u16.append_to_bytes(bytes)?,
+            Self::BrowserSearch => 158u16.append_to_bytes(bytes)?,
+            Self::BrowserStop => 159u16.append_to_bytes(bytes)?,
+            Self::AppSwitch => 160u16.append_to_bytes(bytes)?,
+            Self::Call => 161u16.append_to_bytes(bytes)?,
+            Self::Camera => 162u16.append_to_bytes(bytes)?,
+            Self::CameraFocus => 163u16.append_to_bytes(bytes)?,
+            Self::EndCall => 164u16.append_to_bytes(bytes)?,
+            Self::GoBack => 165u16.append_to_bytes(bytes)?,
+            Self::GoHome => 166u16.append_to_bytes(bytes)?,
+            Self::HeadsetHook => 167u16.append_to_bytes(bytes)?,
+            Self::LastNumberRedial => 168u16.append_to_bytes(bytes)?,
+            Self::Notification => 169u16.append_to_bytes(bytes)?,
+            Self::MannerMode => 170u16.append_to_bytes(bytes)?,
+            Self::VoiceDial => 171u16.append_to_bytes(bytes)?,
+            Self::TV => 172u16.append_to_bytes(bytes)?,
+            Self::TV3DMode => 173u16.append_to_bytes(bytes)?,
+            Self::TVAntennaCable => 174u16.append_to_bytes(bytes)?,
+            Self::TVAudioDescription => 175u16.append_to_bytes(bytes)?,
+            Self::TVAudioDescriptionMixDown => 176u16.append_to_bytes(bytes)?,
+            Self::TVAudioDescriptionMixUp => 177u16.append_to_bytes(bytes)?,
+            Self::TVContentsMenu => 178u16.append_to_bytes(bytes)?,
+            Self::TVDataService => 179u16.append_to_bytes(bytes)?,
+            Self::TVInput => 180u16.append_to_bytes(bytes)?,
+            Self::TVInputComponent1 => 181u16.append_to_bytes(bytes)?,
+            Self::TVInputComponent2 => 182u16.append_to_bytes(bytes)?,
+            Self::TVInputComposite1 => 183u16.append_to_bytes(bytes)?,
+            Self::TVInputComposite2 => 184u16.append_to_bytes(bytes)?,
+            Self::TVInputHDMI1 => 185u16.append_to_bytes(bytes)?,
+            Self::TVInputHDMI2 => 186u16.append_to_bytes(bytes)?,
+            Self::TVInputHDMI3 => 187u16.append_to_bytes(bytes)?,
+            Self::TVInputHDMI4 => 188u16.append_to_bytes(bytes)?,
+            Self::TVInputVGA1 => 189u16.append_to_bytes(bytes)?,
+            Self::TVMediaContext => 190u16.append_to_bytes(bytes)?,
+            Self::TVNetwork => 191u16.append_to_bytes(bytes)?,
+            Self::TVNumberEntry => 192u16.append_to_bytes(bytes)?,
+            Self::TVPower => 193u16.append_to_bytes(bytes)?,
+            Self::TVRadioService => 194u16.append_to_bytes(bytes)?,
+            Self::TVSatellite => 195u16.append_to_bytes(bytes)?,
+            Self::TVSatelliteBS => 196u16.append_to_bytes(bytes)?,
+            Self::TVSatelliteCS => 197u16.append_to_bytes(bytes)?,
+            Self::TVSatelliteToggle => 198u16.append_to_bytes(bytes)?,
+            Self::TVTerrestrialAnalog => 199u16.append_to_bytes(bytes)?,
+            Self::TVTerrestrialDigital => 200u16.append_to_bytes(bytes)?,
+            Self::TVTimer => 201u16.append_to_bytes(bytes)?,
+            Self::AVRInput => 202u16.append_to_bytes(bytes)?,
+            Self::AVRPower => 203u16.append_to_bytes(bytes)?,
+            Self::ColorF0Red => 204u16.append_to_bytes(bytes)?,
+            Self::ColorF1Green => 205u16.append_to_bytes(bytes)?,
+            Self::ColorF2Yellow => 206u16.append_to_bytes(bytes)?,
+            Self::ColorF3Blue => 207u16.append_to_bytes(bytes)?,
+            Self::ColorF4Grey => 208u16.append_to_bytes(bytes)?,
+            Self::ColorF5Brown => 209u16.append_to_bytes(bytes)?,
+            Self::ClosedCaptionToggle => 210u16.append_to_bytes(bytes)?,
+            Self::Dimmer => 211u16.append_to_bytes(bytes)?,
+            Self::DisplaySwap => 212u16.append_to_bytes(bytes)?,
+            Self::DVR => 213u16.append_to_bytes(bytes)?,
+            Self::Exit => 214u16.append_to_bytes(bytes)?,
+            Self::FavoriteClear0 => 215u16.append_to_bytes(bytes)?,
+            Self::FavoriteClear1 => 216u16.append_to_bytes(bytes)?,
+            Self::FavoriteClear2 => 217u16.append_to_bytes(bytes)?,
+            Self::FavoriteClear3 => 218u16.append_to_bytes(bytes)?,
+            Self::FavoriteRecall0 => 219u16.append_to_bytes(bytes)?,
+            Self::FavoriteRecall1 => 220u16.append_to_bytes(bytes)?,
+            Self::FavoriteRecall2 => 221u16.append_to_bytes(bytes)?,
+            Self::FavoriteRecall3 => 222u16.append_to_bytes(bytes)?,
+            Self::FavoriteStore0 => 223u16.append_to_bytes(bytes)?,
+            Self::FavoriteStore1 => 224u16.append_to_bytes(bytes)?,
+            Self::FavoriteStore2 => 225u16.append_to_bytes(bytes)?,
+            Self::FavoriteStore3 => 226u16.append_to_bytes(bytes)?,
+            Self::Guide => 227u16.append_to_bytes(bytes)?,
+            Self::GuideNextDay => 228u16.append_to_bytes(bytes)?,
+            Self::GuidePreviousDay => 229u16.append_to_bytes(bytes)?,
+            Self::Info => 230u16.append_to_bytes(bytes)?,
+            Self::InstantReplay => 231u16.append_to_bytes(bytes)?,
+            Self::Link => 232u16.append_to_bytes(bytes)?,
+            Self::ListProgram => 233u16.append_to_bytes(bytes)?,
+            Self::LiveContent => 234u16.append_to_bytes(bytes)?,
+            Self::Lock => 235u16.append_to_bytes(bytes)?,
+            Self::MediaApps => 236u16.append_to_bytes(bytes)?,
+            Self::MediaAudioTrack => 237u16.append_to_bytes(bytes)?,
+            Self::MediaLast => 238u16.append_to_bytes(bytes)?,
+            Self::MediaSkipBackward => 239u16.append_to_bytes(bytes)?,
+            Self::MediaSkipForward => 240u16.append_to_bytes(bytes)?,
+            Self::MediaStepBackward => 241u16.append_to_bytes(bytes)?,
+            Self::MediaStepForward => 242u16.append_to_bytes(bytes)?,
+            Self::MediaTopMenu => 243u16.append_to_bytes(bytes)?,
+            Self::NavigateIn => 244u16.append_to_bytes(bytes)?,
+            Self::NavigateNext => 245u16.append_to_bytes(bytes)?,
+            Self::NavigateOut => 246u16.append_to_bytes(bytes)?,
+            Self::NavigatePrevious => 247u16.append_to_bytes(bytes)?,
+            Self::NextFavoriteChannel => 248u16.append_to_bytes(bytes)?,
+            Self::NextUserProfile => 249u16.append_to_bytes(bytes)?,
+            Self::OnDemand => 250u16.append_to_bytes(bytes)?,
+            Self::Pairing => 251u16.append_to_bytes(bytes)?,
+            Self::PinPDown => 252u16.append_to_bytes(bytes)?,
+            Self::PinPMove => 253u16.append_to_bytes(bytes)?,
+            Self::PinPToggle => 254u16.append_to_bytes(bytes)?,
+            Self::PinPUp => 255u16.append_to_bytes(bytes)?,
+            Self::PlaySpeedDown => 256u16.append_to_bytes(bytes)?,
+            Self::PlaySpeedReset => 257u16.append_to_bytes(bytes)?,
+            Self::PlaySpeedUp => 258u16.append_to_bytes(bytes)?,
+            Self::RandomToggle => 259u16.append_to_bytes(bytes)?,
+            Self::RcLowBattery => 260u16.append_to_bytes(bytes)?,
+            Self::RecordSpeedNext => 261u16.append_to_bytes(bytes)?,
+            Self::RfBypass => 262u16.append_to_bytes(bytes)?,
+            Self::ScanChannelsToggle => 263u16.append_to_bytes(bytes)?,
+            Self::ScreenModeNext => 264u16.append_to_bytes(bytes)?,
+            Self::Settings => 265u16.append_to_bytes(bytes)?,
+            Self::SplitScreenToggle => 266u16.append_to_bytes(bytes)?,
+            Self::STBInput => 267u16.append_to_bytes(bytes)?,
+            Self::STBPower => 268u16.append_to_bytes(bytes)?,
+            Self::Subtitle => 269u16.append_to_bytes(bytes)?,
+            Self::Teletext => 270u16.append_to_bytes(bytes)?,
+            Self::VideoModeNext => 271u16.append_to_bytes(bytes)?,
+            Self::Wink => 272u16.append_to_bytes(bytes)?,
+            Self::ZoomToggle => 273u16.append_to_bytes(bytes)?,
+            Self::F1 => 274u16.append_to_bytes(bytes)?,
+            Self::F2 => 275u16.append_to_bytes(bytes)?,
+            Self::F3 => 276u16.append_to_bytes(bytes)?,
+            Self::F4 => 277u16.append_to_bytes(bytes)?,
+            Self::F5 => 278u16.append_to_bytes(bytes)?,
+            Self::F6 => 279u16.append_to_bytes(bytes)?,
+            Self::F7 => 280u16.append_to_bytes(bytes)?,
+            Self::F8 => 281u16.append_to_bytes(bytes)?,
+            Self::F9 => 282u16.append_to_bytes(bytes)?,
+            Self::F10 => 283u16.append_to_bytes(bytes)?,
+            Self::F11 => 284u16.append_to_bytes(bytes)?,
+            Self::F12 => 285u16.append_to_bytes(bytes)?,
+            Self::F13 => 286u16.append_to_bytes(bytes)?,
+            Self::F14 => 287u16.append_to_bytes(bytes)?,
+            Self::F15 => 288u16.append_to_bytes(bytes)?,
+            Self::F16 => 289u16.append_to_bytes(bytes)?,
+            Self::F17 => 290u16.append_to_bytes(bytes)?,
+            Self::F18 => 291u16.append_to_bytes(bytes)?,
+            Self::F19 => 292u16.append_to_bytes(bytes)?,
+            Self::F20 => 293u16.append_to_bytes(bytes)?,
+            Self::F21 => 294u16.append_to_bytes(bytes)?,
+            Self::F22 => 295u16.append_to_bytes(bytes)?,
+            Self::F23 => 296u16.append_to_bytes(bytes)?,
+            Self::F24 => 297u16.append_to_bytes(bytes)?,
+            Self::F25 => 298u16.append_to_bytes(bytes)?,
+            Self::F26 => 299u16.append_to_bytes(bytes)?,
+            Self::F27 => 300u16.append_to_bytes(bytes)?,
+            Self::F28 => 301u16.append_to_bytes(bytes)?,
+            Self::F29 => 302u16.append_to_bytes(bytes)?,
+            Self::F30 => 303u16.append_to_bytes(bytes)?,
+            Self::F31 => 304u16.append_to_bytes(bytes)?,
+            Self::F32 => 305u16.append_to_bytes(bytes)?,
+            Self::F33 => 306u16.append_to_bytes(bytes)?,
+            Self::F34 => 307u16.append_to_bytes(bytes)?,
+            Self::F35 => 308u16.append_to_bytes(bytes)?,
+            _ => return Err("Unexpected Key variant".into()),
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_bytes = u16::extract_from_bytes(bytes, index)?;
+        match enum_variant_bytes {
+            0u16 => Ok(Self::Character(String::extract_from_bytes(bytes, index)?.into())),
+            1u16 => Ok(Self::Unidentified(NativeKey::extract_from_bytes(bytes, index)?)),
+            2u16 => Ok(Self::Dead(Option::<char>::extract_from_bytes(bytes, index)?)),
+            3u16 => Ok(Self::Alt),
+            4u16 => Ok(Self::AltGraph),
+            5u16 => Ok(Self::CapsLock),
+            6u16 => Ok(Self::Control),
+            7u16 => Ok(Self::Fn),
+            8u16 => Ok(Self::FnLock),
+            9u16 => Ok(Self::NumLock),
+            10u16 => Ok(Self::ScrollLock),
+            11u16 => Ok(Self::Shift),
+            12u16 => Ok(Self::Symbol),
+            13u16 => Ok(Self::SymbolLock),
+            14u16 => Ok(Self::Meta),
+            15u16 => Ok(Self::Hyper),
+            16u16 => Ok(Self::Super),
+            17u16 => Ok(Self::Enter),
+            18u16 => Ok(Self::Tab),
+            19u16 => Ok(Self::Space),
+            20u16 => Ok(Self::ArrowDown),
+            21u16 => Ok(Self::ArrowLeft),
+            22u16 => Ok(Self::ArrowRight),
+            23u16 => Ok(Self::ArrowUp),
+            24u16 => Ok(Self::End),
+            25u16 => Ok(Self::Home),
+            26u16 => Ok(Self::PageDown),
+            27u16 => Ok(Self::PageUp),
+            28u16 => Ok(Self::Backspace),
+            29u16 => Ok(Self::Clear),
+            30u16 => Ok(Self::Copy),
+            31u16 => Ok(Self::CrSel),
+            32u16 => Ok(Self::Cut),
+            33u16 => Ok(Self::Delete),
+            34u16 => Ok(Self::EraseEof),
+            35u16 => Ok(Self::ExSel),
+            36u16 => Ok(Self::Insert),
+            37u16 => Ok(Self::Paste),
+            38u16 => Ok(Self::Redo),
+            39u16 => Ok(Self::Undo),
+            40u16 => Ok(Self::Accept),
+            41u16 => Ok(Self::Again),
+            42u16 => Ok(Self::Attn),
+            43u16 => Ok(Self::Cancel),
+            44u16 => Ok(Self::ContextMenu),
+            45u16 => Ok(Self::Escape),
+            46u16 => Ok(Self::Execute),
+            47u16 => Ok(Self::Find),
+            48u16 => Ok(Self::Help),
+            49u16 => Ok(Self::Pause),
+            50u16 => Ok(Self::Play),
+            51u16 => Ok(Self::Props),
+            52u16 => Ok(Self::Select),
+            53u16 => Ok(Self::ZoomIn),
+            54u16 => Ok(Self::ZoomOut),
+            55u16 => Ok(Self::BrightnessDown),
+            56u16 => Ok(Self::BrightnessUp),
+            57u16 => Ok(Self::Eject),
+            58u16 => Ok(Self::LogOff),
+            59u16 => Ok(Self::Power),
+            60u16 => Ok(Self::PowerOff),
+            61u16 => Ok(Self::PrintScreen),
+            62u16 => Ok(Self::Hibernate),
+            63u16 => Ok(Self::Standby),
+            64u16 => Ok(Self::WakeUp),
+            65u16 => Ok(Self::AllCandidates),
+            66u16 => Ok(Self::Alphanumeric),
+            67u16 => Ok(Self::CodeInput),
+            68u16 => Ok(Self::Compose),
+            69u16 => Ok(Self::Convert),
+            70u16 => Ok(Self::FinalMode),
+            71u16 => Ok(Self::GroupFirst),
+            72u16 => Ok(Self::GroupLast),
+            73u16 => Ok(Self::GroupNext),
+            74u16 => Ok(Self::GroupPrevious),
+            75u16 => Ok(Self::ModeChange),
+            76u16 => Ok(Self::NextCandidate),
+            77u16 => Ok(Self::NonConvert),
+            78u16 => Ok(Self::PreviousCandidate),
+            79u16 => Ok(Self::Process),
+            80u16 => Ok(Self::SingleCandidate),
+            81u16 => Ok(Self::HangulMode),
+            82u16 => Ok(Self::HanjaMode),
+            83u16 => Ok(Self::JunjaMode),
+            84u16 => Ok(Self::Eisu),
+            85u16 => Ok(Self::Hankaku),
+            86u16 => Ok(Self::Hiragana),
+            87u16 => Ok(Self::HiraganaKatakana),
+            88u16 => Ok(Self::KanaMode),
+            89u16 => Ok(Self::KanjiMode),
+            90u16 => Ok(Self::Katakana),
+            91u16 => Ok(Self::Romaji),
+            92u16 => Ok(Self::Zenkaku),
+            93u16 => Ok(Self::ZenkakuHankaku),
+            94u16 => Ok(Self::Soft1),
+            95u16 => Ok(Self::Soft2),
+            96u16 => Ok(Self::Soft3),
+            97u16 => Ok(Self::Soft4),
+            98u16 => Ok(Self::ChannelDown),
+            99u16 => Ok(Self::ChannelUp),
+            100u16 => Ok(Self::Close),
+            101u16 => Ok(Self::MailForward),
+            102u16 => Ok(Self::MailReply),
+            103u16 => Ok(Self::MailSend),
+            104u16 => Ok(Self::MediaClose),
+            105u16 => Ok(Self::MediaFastForward),
+            106u16 => Ok(Self::MediaPause),
+            107u16 => Ok(Self::MediaPlay),
+            108u16 => Ok(Self::MediaPlayPause),
+            109u16 => Ok(Self::MediaRecord),
+            110u16 => Ok(Self::MediaRewind),
+            111u16 => Ok(Self::MediaStop),
+            112u16 => Ok(Self::MediaTrackNext),
+            113u16 => Ok(Self::MediaTrackPrevious),
+            114u16 => Ok(Self::New),
+            115u16 => Ok(Self::Open),
+            116u16 => Ok(Self::Print),
+            117u16 => Ok(Self::Save),
+            118u16 => Ok(Self::SpellCheck),
+            119u16 => Ok(Self::Key11),
+            120u16 => Ok(Self::Key12),
+            121u16 => Ok(Self::AudioBalanceLeft),
+            122u16 => Ok(Self::AudioBalanceRight),
+            123u16 => Ok(Self::AudioBassBoostDown),
+            124u16 => Ok(Self::AudioBassBoostToggle),
+            125u16 => Ok(Self::AudioBassBoostUp),
+            126u16 => Ok(Self::AudioFaderFront),
+            127u16 => Ok(Self::AudioFaderRear),
+            128u16 => Ok(Self::AudioSurroundModeNext),
+            129u16 => Ok(Self::AudioTrebleDown),
+            130u16 => Ok(Self::AudioTrebleUp),
+            131u16 => Ok(Self::AudioVolumeDown),
+            132u16 => Ok(Self::AudioVolumeUp),
+            133u16 => Ok(Self::AudioVolumeMute),
+            134u16 => Ok(Self::MicrophoneToggle),
+            135u16 => Ok(Self::MicrophoneVolumeDown),
+            136u16 => Ok(Self::MicrophoneVolumeUp),
+            137u16 => Ok(Self::MicrophoneVolumeMute),
+            138u16 => Ok(Self::SpeechCorrectionList),
+            139u16 => Ok(Self::SpeechInputToggle),
+            140u16 => Ok(Self::LaunchApplication1),
+            141u16 => Ok(Self::LaunchApplication2),
+            142u16 => Ok(Self::LaunchCalendar),
+            143u16 => Ok(Self::LaunchContacts),
+            144u16 => Ok(Self::LaunchMail),
+            145u16 => Ok(Self::LaunchMediaPlayer),
+            146u16 => Ok(Self::LaunchMusicPlayer),
+            147u16 => Ok(Self::LaunchPhone),
+            148u16 => Ok(Self::LaunchScreenSaver),
+            149u16 => Ok(Self::LaunchSpreadsheet),
+            150u16 => Ok(Self::LaunchWebBrowser),
+            151u16 => Ok(Self::LaunchWebCam),
+            152u16 => Ok(Self::LaunchWordProcessor),
+            153u16 => Ok(Self::BrowserBack),
+            154u16 => Ok(Self::BrowserFavorites),
+            155u16 => Ok(Self::BrowserForward),
+            156u16 => Ok(Self::BrowserHome),
+            157u16 => Ok(Self::BrowserRefresh),
+            158u16 => Ok(Self::BrowserSearch),
+            159u16 => Ok(Self::BrowserStop),
+            160u16 => Ok(Self::AppSwitch),
+            161u16 => Ok(Self::Call),
+            162u16 => Ok(Self::Camera),
+            163u16 => Ok(Self::CameraFocus),
+            164u16 => Ok(Self::EndCall),
+            165u16 => Ok(Self::GoBack),
+            166u16 => Ok(Self::GoHome),
+            167u16 => Ok(Self::HeadsetHook),
+            168u16 => Ok(Self::LastNumberRedial),
+            169u16 => Ok(Self::Notification),
+            170u16 => Ok(Self::MannerMode),
+            171u16 => Ok(Self::VoiceDial),
+            172u16 => Ok(Self::TV),
+            173u16 => Ok(Self::TV3DMode),
+            174u16 => Ok(Self::TVAntennaCable),
+            175u16 => Ok(Self::TVAudioDescription),
+            176u16 => Ok(Self::TVAudioDescriptionMixDown),
+            177u16 => Ok(Self::TVAudioDescriptionMixUp),
+            178u16 => Ok(Self::TVContentsMenu),
+            179u16 => Ok(Self::TVDataService),
+            180u16 => Ok(Self::TVInput),
+            181u16 => Ok(Self::TVInputComponent1),
+            182u16 => Ok(Self::TVInputComponent2),
+            183u16 => Ok(Self::TVInputComposite1),
+            184u16 => Ok(Self::TVInputComposite2),
+            185u16 => Ok(Self::TVInputHDMI1),
+            186u16 => Ok(Self::TVInputHDMI2),
+            187u16 => Ok(Self::TVInputHDMI3),
+            188u16 => Ok(Self::TVInputHDMI4),
+            189u16 => Ok(Self::TVInputVGA1),
+            190u16 => Ok(Self::TVMediaContext),
+            191u16 => Ok(Self::TVNetwork),
+            192u16 => Ok(Self::TVNumberEntry),
+            193u16 => Ok(Self::TVPower),
+            194u16 => Ok(Self::TVRadioService),
+            195u16 => Ok(Self::TVSatellite),
+            196u16 => Ok(Self::TVSatelliteBS),
+            197u16 => Ok(Self::TVSatelliteCS),
+            198u16 => Ok(Self::TVSatelliteToggle),
+            199u16 => Ok(Self::TVTerrestrialAnalog),
+            200u16 => Ok(Self::TVTerrestrialDigital),
+            201u16 => Ok(Self::TVTimer),
+            202u16 => Ok(Self::AVRInput),
+            203u16 => Ok(Self::AVRPower),
+            204u16 => Ok(Self::ColorF0Red),
+            205u16 => Ok(Self::ColorF1Green),
+            206u16 => Ok(Self::ColorF2Yellow),
+            207u16 => Ok(Self::ColorF3Blue),
+            208u16 => Ok(Self::ColorF4Grey),
+            209u16 => Ok(Self::ColorF5Brown),
+            210u16 => Ok(Self::ClosedCaptionToggle),
+            211u16 => Ok(Self::Dimmer),
+            212u16 => Ok(Self::DisplaySwap),
+            213u16 => Ok(Self::DVR),
+            214u16 => Ok(Self::Exit),
+            215u16 => Ok(Self::FavoriteClear0),
+            216u16 => Ok(Self::FavoriteClear1),
+            217u16 => Ok(Self::FavoriteClear2),
+            218u16 => Ok(Self::FavoriteClear3),
+            219u16 => Ok(Self::FavoriteRecall0),
+            220u16 => Ok(Self::FavoriteRecall1),
+            221u16 => Ok(Self::FavoriteRecall2),
+            222u16 => Ok(Self::FavoriteRecall3),
+            223u16 => Ok(Self::FavoriteStore0),
+            224u16 => Ok(Self::FavoriteStore1),
+            225u16 => Ok(Self::FavoriteStore2),
+            226u16 => Ok(Self::FavoriteStore3),
+            227u16 => Ok(Self::Guide),
+            228u16 => Ok(Self::GuideNextDay),
+            229u16 => Ok(Self::GuidePreviousDay),
+            230u16 => Ok(Self::Info),
+            231u16 => Ok(Self::InstantReplay),
+            232u16 => Ok(Self::Link),
+            233u16 => Ok(Self::ListProgram),
+            234u16 => Ok(Self::LiveContent),
+            235u16 => Ok(Self::Lock),
+            236u16 => Ok(Self::MediaApps),
+            237u16 => Ok(Self::MediaAudioTrack),
+            238u16 => Ok(Self::MediaLast),
+            239u16 => Ok(Self::MediaSkipBackward),
+            240u16 => Ok(Self::MediaSkipForward),
+            241u16 => Ok(Self::MediaStepBackward),
+            242u16 => Ok(Self::MediaStepForward),
+            243u16 => Ok(Self::MediaTopMenu),
+            244u16 => Ok(Self::NavigateIn),
+            245u16 => Ok(Self::NavigateNext),
+            246u16 => Ok(Self::NavigateOut),
+            247u16 => Ok(Self::NavigatePrevious),
+            248u16 => Ok(Self::NextFavoriteChannel),
+            249u16 => Ok(Self::NextUserProfile),
+            250u16 => Ok(Self::OnDemand),
+            251u16 => Ok(Self::Pairing),
+            252u16 => Ok(Self::PinPDown),
+            253u16 => Ok(Self::PinPMove),
+            254u16 => Ok(Self::PinPToggle),
+            255u16 => Ok(Self::PinPUp),
+            256u16 => Ok(Self::PlaySpeedDown),
+            257u16 => Ok(Self::PlaySpeedReset),
+            258u16 => Ok(Self::PlaySpeedUp),
+            259u16 => Ok(Self::RandomToggle),
+            260u16 => Ok(Self::RcLowBattery),
+            261u16 => Ok(Self::RecordSpeedNext),
+            262u16 => Ok(Self::RfBypass),
+            263u16 => Ok(Self::ScanChannelsToggle),
+            264u16 => Ok(Self::ScreenModeNext),
+            265u16 => Ok(Self::Settings),
+            266u16 => Ok(Self::SplitScreenToggle),
+            267u16 => Ok(Self::STBInput),
+            268u16 => Ok(Self::STBPower),
+            269u16 => Ok(Self::Subtitle),
+            270u16 => Ok(Self::Teletext),
+            271u16 => Ok(Self::VideoModeNext),
+            272u16 => Ok(Self::Wink),
+            273u16 => Ok(Self::ZoomToggle),
+            274u16 => Ok(Self::F1),
+            275u16 => Ok(Self::F2),
+            276u16 => Ok(Self::F3),
+            277u16 => Ok(Self::F4),
+            278u16 => Ok(Self::F5),
+            279u16 => Ok(Self::F6),
+            280u16 => Ok(Self::F7),
+            281u16 => Ok(Self::F8),
+            282u16 => Ok(Self::F9),
+            283u16 => Ok(Self::F10),
+            284u16 => Ok(Self::F11),
+            285u16 => Ok(Self::F12),
+            286u16 => Ok(Self::F13),
+            287u16 => Ok(Self::F14),
+            288u16 => Ok(Self::F15),
+            289u16 => Ok(Self::F16),
+            290u16 => Ok(Self::F17),
+            291u16 => Ok(Self::F18),
+            292u16 => Ok(Self::F19),
+            293u16 => Ok(Self::F20),
+            294u16 => Ok(Self::F21),
+            295u16 => Ok(Self::F22),
+            296u16 => Ok(Self::F23),
+            297u16 => Ok(Self::F24),
+            298u16 => Ok(Self::F25),
+            299u16 => Ok(Self::F26),
+            300u16 => Ok(Self::F27),
+            301u16 => Ok(Self::F28),
+            302u16 => Ok(Self::F29),
+            303u16 => Ok(Self::F30),
+            304u16 => Ok(Self::F31),
+            305u16 => Ok(Self::F32),
+            306u16 => Ok(Self::F33),
+            307u16 => Ok(Self::F34),
+            308u16 => Ok(Self::F35),
+            _ => Err("Unexpected enum variant bytes".into()),
+        }
+    }
+}
+
+impl ByteConverter for ButtonState {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Pressed => 0u8.append_to_bytes(bytes)?,
+            Self::Released => 1u8.append_to_bytes(bytes)?,
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Pressed),
+            1u8 => Ok(Self::Released),
+            _ => Err("Unexpected enum variant byte".into()),
+        }
+    }
+}
+
+impl BitConverter for ButtonState {
+    const BIT_LENGTH: u32 = 1;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Pressed => bit_writer.write_bits(0, Self::BIT_LENGTH),
+            Self::Released => bit_writer.write_bits(1, Self::BIT_LENGTH),
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        match bit_reader.read_bits(Self::BIT_LENGTH)? {
+            0 => Ok(Self::Pressed),
+            1 => Ok(Self::Released),
+            _ => Err("Unexpected enum variant bits.".into()),
+        }
+    }
+}
+
+impl ByteConverter for KeyboardInput {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.key_code.append_to_bytes(bytes)?;
+        self.logical_key.append_to_bytes(bytes)?;
+        self.state.append_to_bytes(bytes)?;
+        self.text.as_ref().map(|text| String::from(text.as_str())).append_to_bytes(bytes)?;
+        self.repeat.append_to_bytes(bytes)?;
+        self.window.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            key_code: KeyCode::extract_from_bytes(bytes, index)?,
+            logical_key: Key::extract_from_bytes(bytes, index)?,
+            state: ButtonState::extract_from_bytes(bytes, index)?,
+            text: Option::<String>::extract_from_bytes(bytes, index)?.map(|text| text.into()),
+            repeat: bool::extract_from_bytes(bytes, index)?,
+            window: Entity::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+impl ByteConverter for MouseButtonInput {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.button.append_to_bytes(bytes)?;
+        self.state.append_to_bytes(bytes)?;
+        self.window.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            button: MouseButton::extract_from_bytes(bytes, index)?,
+            state: ButtonState::extract_from_bytes(bytes, index)?,
+            window: Entity::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+impl ByteConverter for MouseMotion {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.delta.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            delta: Vec2::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+impl ByteConverter for MouseWheel {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.unit.append_to_bytes(bytes)?;
+        self.x.append_to_bytes(bytes)?;
+        self.y.append_to_bytes(bytes)?;
+        self.window.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            unit: MouseScrollUnit::extract_from_bytes(bytes, index)?,
+            x: f32::extract_from_bytes(bytes, index)?,
+            y: f32::extract_from_bytes(bytes, index)?,
+            window: Entity::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+impl ByteConverter for GamepadButton {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::South => 0u8.append_to_bytes(bytes)?,
+            Self::East => 1u8.append_to_bytes(bytes)?,
+            Self::North => 2u8.append_to_bytes(bytes)?,
+            Self::West => 3u8.append_to_bytes(bytes)?,
+            Self::C => 4u8.append_to_bytes(bytes)?,
+            Self::Z => 5u8.append_to_bytes(bytes)?,
+            Self::LeftTrigger => 6u8.append_to_bytes(bytes)?,
+            Self::LeftTrigger2 => 7u8.append_to_bytes(bytes)?,
+            Self::RightTrigger => 8u8.append_to_bytes(bytes)?,
+            Self::RightTrigger2 => 9u8.append_to_bytes(bytes)?,
+            Self::Select => 10u8.append_to_bytes(bytes)?,
+            Self::Start => 11u8.append_to_bytes(bytes)?,
+            Self::Mode => 12u8.append_to_bytes(bytes)?,
+            Self::LeftThumb => 13u8.append_to_bytes(bytes)?,
+            Self::RightThumb => 14u8.append_to_bytes(bytes)?,
+            Self::DPadUp => 15u8.append_to_bytes(bytes)?,
+            Self::DPadDown => 16u8.append_to_bytes(bytes)?,
+            Self::DPadLeft => 17u8.append_to_bytes(bytes)?,
+            Self::DPadRight => 18u8.append_to_bytes(bytes)?,
+            Self::Other(button_id) => {
+                19u8.append_to_bytes(bytes)?;
+                button_id.append_to_bytes(bytes)?;
+            },
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::South),
+            1u8 => Ok(Self::East),
+            2u8 => Ok(Self::North),
+            3u8 => Ok(Self::West),
+            4u8 => Ok(Self::C),
+            5u8 => Ok(Self::Z),
+            6u8 => Ok(Self::LeftTrigger),
+            7u8 => Ok(Self::LeftTrigger2),
+            8u8 => Ok(Self::RightTrigger),
+            9u8 => Ok(Self::RightTrigger2),
+            10u8 => Ok(Self::Select),
+            11u8 => Ok(Self::Start),
+            12u8 => Ok(Self::Mode),
+            13u8 => Ok(Self::LeftThumb),
+            14u8 => Ok(Self::RightThumb),
+            15u8 => Ok(Self::DPadUp),
+            16u8 => Ok(Self::DPadDown),
+            17u8 => Ok(Self::DPadLeft),
+            18u8 => Ok(Self::DPadRight),
+            19u8 => Ok(Self::Other(u8::extract_from_bytes(bytes, index)?)),
+            _ => Err("Unexpected enum variant byte".into()),
+        }
+    }
+}
+
+impl ByteConverter for GamepadAxis {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::LeftStickX => 0u8.append_to_bytes(bytes)?,
+            Self::LeftStickY => 1u8.append_to_bytes(bytes)?,
+            Self::LeftZ => 2u8.append_to_bytes(bytes)?,
+            Self::RightStickX => 3u8.append_to_bytes(bytes)?,
+            Self::RightStickY => 4u8.append_to_bytes(bytes)?,
+            Self::RightZ => 5u8.append_to_bytes(bytes)?,
+            Self::Other(axis_id) => {
+                6u8.append_to_bytes(bytes)?;
+                axis_id.append_to_bytes(bytes)?;
+            },
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::LeftStickX),
+            1u8 => Ok(Self::LeftStickY),
+            2u8 => Ok(Self::LeftZ),
+            3u8 => Ok(Self::RightStickX),
+            4u8 => Ok(Self::RightStickY),
+            5u8 => Ok(Self::RightZ),
+            6u8 => Ok(Self::Other(u8::extract_from_bytes(bytes, index)?)),
+            _ => Err("Unexpected enum variant byte".into()),
+        }
+    }
+}
+
+impl ByteConverter for GamepadConnection {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Connected { name, vendor_id, product_id } => {
+                0u8.append_to_bytes(bytes)?;
+                name.append_to_bytes(bytes)?;
+                vendor_id.append_to_bytes(bytes)?;
+                product_id.append_to_bytes(bytes)?;
+            },
+            Self::Disconnected => 1u8.append_to_bytes(bytes)?,
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Connected {
+                name: String::extract_from_bytes(bytes, index)?,
+                vendor_id: Option::<u16>::extract_from_bytes(bytes, index)?,
+                product_id: Option::<u16>::extract_from_bytes(bytes, index)?,
+            }),
+            1u8 => Ok(Self::Disconnected),
+            _ => Err("Unexpected enum variant byte".into()),
+        }
+    }
+}
+
+impl ByteConverter for GamepadConnectionEvent {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.gamepad.append_to_bytes(bytes)?;
+        self.connection.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            gamepad: Entity::extract_from_bytes(bytes, index)?,
+            connection: GamepadConnection::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+impl ByteConverter for GamepadButtonChangedEvent {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.entity.append_to_bytes(bytes)?;
+        self.button.append_to_bytes(bytes)?;
+        self.state.append_to_bytes(bytes)?;
+        self.value.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            entity: Entity::extract_from_bytes(bytes, index)?,
+            button: GamepadButton::extract_from_bytes(bytes, index)?,
+            state: ButtonState::extract_from_bytes(bytes, index)?,
+            value: f32::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+impl ByteConverter for GamepadButtonStateChangedEvent {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.entity.append_to_bytes(bytes)?;
+        self.button.append_to_bytes(bytes)?;
+        self.state.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            entity: Entity::extract_from_bytes(bytes, index)?,
+            button: GamepadButton::extract_from_bytes(bytes, index)?,
+            state: ButtonState::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+impl ByteConverter for GamepadAxisChangedEvent {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.entity.append_to_bytes(bytes)?;
+        self.axis.append_to_bytes(bytes)?;
+        self.value.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            entity: Entity::extract_from_bytes(bytes, index)?,
+            axis: GamepadAxis::extract_from_bytes(bytes, index)?,
+            value: f32::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+impl ByteConverter for GamepadEvent {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Connection(connection_event) => {
+                0u8.append_to_bytes(bytes)?;
+                connection_event.append_to_bytes(bytes)?;
+            },
+            Self::Button(button_event) => {
+                1u8.append_to_bytes(bytes)?;
+                button_event.append_to_bytes(bytes)?;
+            },
+            Self::Axis(axis_event) => {
+                2u8.append_to_bytes(bytes)?;
+                axis_event.append_to_bytes(bytes)?;
+            },
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Connection(GamepadConnectionEvent::extract_from_bytes(bytes, index)?)),
+            1u8 => Ok(Self::Button(GamepadButtonChangedEvent::extract_from_bytes(bytes, index)?)),
+            2u8 => Ok(Self::Axis(GamepadAxisChangedEvent::extract_from_bytes(bytes, index)?)),
+            _ => Err("Unexpected enum variant byte".into()),
+        }
+    }
+}
+
+impl ByteConverter for TouchPhase {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Started => 0u8.append_to_bytes(bytes)?,
+            Self::Moved => 1u8.append_to_bytes(bytes)?,
+            Self::Ended => 2u8.append_to_bytes(bytes)?,
+            Self::Canceled => 3u8.append_to_bytes(bytes)?,
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Started),
+            1u8 => Ok(Self::Moved),
+            2u8 => Ok(Self::Ended),
+            3u8 => Ok(Self::Canceled),
+            _ => Err("Unexpected enum variant byte".into()),
+        }
+    }
+}
+
+impl ByteConverter for ForceTouch {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Calibrated { force, max_possible_force, altitude_angle } => {
+                0u8.append_to_bytes(bytes)?;
+                force.append_to_bytes(bytes)?;
+                max_possible_force.append_to_bytes(bytes)?;
+                altitude_angle.append_to_bytes(bytes)?;
+            },
+            Self::Normalized(force) => {
+                1u8.append_to_bytes(bytes)?;
+                force.append_to_bytes(bytes)?;
+            },
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Calibrated {
+                force: f64::extract_from_bytes(bytes, index)?,
+                max_possible_force: f64::extract_from_bytes(bytes, index)?,
+                altitude_angle: Option::<f64>::extract_from_bytes(bytes, index)?,
+            }),
+            1u8 => Ok(Self::Normalized(f64::extract_from_bytes(bytes, index)?)),
+            _ => Err("Unexpected enum variant byte".into()),
+        }
+    }
+}
+
+impl ByteConverter for TouchInput {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.phase.append_to_bytes(bytes)?;
+        self.position.append_to_bytes(bytes)?;
+        self.window.append_to_bytes(bytes)?;
+        self.force.append_to_bytes(bytes)?;
+        self.id.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            phase: TouchPhase::extract_from_bytes(bytes, index)?,
+            position: Vec2::extract_from_bytes(bytes, index)?,
+            window: Entity::extract_from_bytes(bytes, index)?,
+            force: Option::<ForceTouch>::extract_from_bytes(bytes, index)?,
+            id: u64::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+// the pressed, just pressed and just released sets are each kept so that the state is identical for the current frame
+impl<T> ByteConverter for ButtonInput<T>
+where
+    T: Copy + Eq + std::hash::Hash + Send + Sync + 'static + ByteConverter,
+{
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.get_pressed().copied().collect::<Vec<T>>().append_to_bytes(bytes)?;
+        self.get_just_pressed().copied().collect::<Vec<T>>().append_to_bytes(bytes)?;
+        self.get_just_released().copied().collect::<Vec<T>>().append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let pressed = Vec::<T>::extract_from_bytes(bytes, index)?;
+        let just_pressed = Vec::<T>::extract_from_bytes(bytes, index)?;
+        let just_released = Vec::<T>::extract_from_bytes(bytes, index)?;
+
+        // every input starts out just pressed so that one pressed and released within the same frame keeps both flags
+        let mut button_input = Self::default();
+        for input in just_pressed.iter().chain(pressed.iter()).chain(just_released.iter()) {
+            button_input.press(*input);
+        }
+        for input in just_released.iter() {
+            button_input.release(*input);
+        }
+        for input in pressed.iter() {
+            button_input.press(*input);
+        }
+        for input in pressed.iter().chain(just_released.iter()) {
+            if !just_pressed.contains(input) {
+                button_input.clear_just_pressed(*input);
+            }
+        }
+        Ok(button_input)
+    }
+}
+
 impl ByteConverter for Entity {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
@@ -524,6 +1698,23 @@ impl ByteConverter for Entity {
     }
 }
 
+// the entity is sent as it exists on the sending side and mapped to the local entity on the receiving side
+impl<TEntityMapper> ContextualByteConverter<TEntityMapper> for Entity
+where
+    TEntityMapper: EntityMapper,
+{
+    #[inline(always)]
+    fn append_to_bytes_with(&self, _context: &TEntityMapper, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut TEntityMapper, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let entity = Self::extract_from_bytes(bytes, index)?;
+        Ok(context.get_mapped(entity))
+    }
+}
+
 impl ByteConverter for Transform {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
@@ -688,6 +1879,19 @@ impl ByteConverter for ChildOf {
     }
 }
 
+impl<TEntityMapper> ContextualByteConverter<TEntityMapper> for ChildOf
+where
+    TEntityMapper: EntityMapper,
+{
+    fn append_to_bytes_with(&self, context: &TEntityMapper, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.0.append_to_bytes_with(context, bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut TEntityMapper, bytes: &'a TBytes, index: &mut usize) -> std::result::Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self(Entity::extract_from_bytes_with(context, bytes, index)?))
+    }
+}
+
 impl ByteConverter for UiTargetCamera {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
         self.0.append_to_bytes(bytes)?;
@@ -2028,6 +3232,39 @@ impl ByteConverter for TextColor {
     }
 }
 
+// the components are kept as they are instead of being normalized again so that the direction is bit-identical
+impl ByteConverter for Dir2 {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.as_vec2().append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let vector = Vec2::extract_from_bytes(bytes, index)?;
+        if !vector.is_normalized() {
+            return Err("Direction is not normalized.".into());
+        }
+        Ok(Self::new_unchecked(vector))
+    }
+}
+
+impl ByteConverter for Dir3 {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.as_vec3().append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let vector = Vec3::extract_from_bytes(bytes, index)?;
+        if !vector.is_normalized() {
+            return Err("Direction is not normalized.".into());
+        }
+        Ok(Self::new_unchecked(vector))
+    }
+}
+
 impl ByteConverter for Affine3 {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
@@ -2096,6 +3333,33 @@ where
     }
 }
 
+impl<TContext> ContextualByteConverter<TContext> for Mesh3d
+where
+    Handle<Mesh>: ContextualByteConverter<TContext>,
+{
+    fn append_to_bytes_with(&self, context: &TContext, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.0.append_to_bytes_with(context, bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut TContext, bytes: &'a TBytes, index: &mut usize) -> std::result::Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self(Handle::<Mesh>::extract_from_bytes_with(context, bytes, index)?))
+    }
+}
+
+impl<T, TContext> ContextualByteConverter<TContext> for MeshMaterial3d<T>
+where
+    T: Material,
+    Handle<T>: ContextualByteConverter<TContext>,
+{
+    fn append_to_bytes_with(&self, context: &TContext, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.0.append_to_bytes_with(context, bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut TContext, bytes: &'a TBytes, index: &mut usize) -> std::result::Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self(Handle::<T>::extract_from_bytes_with(context, bytes, index)?))
+    }
+}
+
 impl<TKey, TValue> ByteConverter for bevy::platform::collections::HashMap<TKey, TValue>
 where
     TKey: ByteConverter + Eq + std::hash::Hash,
@@ -2185,6 +3449,19 @@ impl ByteConverter for Mesh3dWireframe {
     }
 }
 
+impl<TContext> ContextualByteConverter<TContext> for Mesh3dWireframe
+where
+    Handle<WireframeMaterial>: ContextualByteConverter<TContext>,
+{
+    fn append_to_bytes_with(&self, context: &TContext, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.0.append_to_bytes_with(context, bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut TContext, bytes: &'a TBytes, index: &mut usize) -> std::result::Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self(Handle::<WireframeMaterial>::extract_from_bytes_with(context, bytes, index)?))
+    }
+}
+
 impl ByteConverter for WireframeConfig {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
         self.global.append_to_bytes(bytes)?;
@@ -2214,13 +3491,35 @@ impl ByteConverter for Visibility {
         }
         Ok(())
     }
-    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> std::result::Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
-        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
-        match enum_variant_byte {
-            0u8 => Ok(Self::Inherited),
-            1u8 => Ok(Self::Hidden),
-            2u8 => Ok(Self::Visible),
-            _ => Err("Unexpected enum variant byte.".into()),
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> std::result::Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Inherited),
+            1u8 => Ok(Self::Hidden),
+            2u8 => Ok(Self::Visible),
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
+    }
+}
+
+impl BitConverter for Visibility {
+    const BIT_LENGTH: u32 = 2;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Inherited => bit_writer.write_bits(0, Self::BIT_LENGTH),
+            Self::Hidden => bit_writer.write_bits(1, Self::BIT_LENGTH),
+            Self::Visible => bit_writer.write_bits(2, Self::BIT_LENGTH),
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        match bit_reader.read_bits(Self::BIT_LENGTH)? {
+            0 => Ok(Self::Inherited),
+            1 => Ok(Self::Hidden),
+            2 => Ok(Self::Visible),
+            _ => Err("Unexpected enum variant bits.".into()),
         }
     }
 }
@@ -2247,6 +3546,26 @@ impl ByteConverter for FocusPolicy {
     }
 }
 
+impl BitConverter for FocusPolicy {
+    const BIT_LENGTH: u32 = 1;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Block => bit_writer.write_bits(0, Self::BIT_LENGTH),
+            Self::Pass => bit_writer.write_bits(1, Self::BIT_LENGTH),
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        match bit_reader.read_bits(Self::BIT_LENGTH)? {
+            0 => Ok(Self::Block),
+            1 => Ok(Self::Pass),
+            _ => Err("Unexpected enum variant bits.".into()),
+        }
+    }
+}
+
 impl ByteConverter for ScrollPosition {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
         self.offset_x.append_to_bytes(bytes)?;
@@ -2287,6 +3606,28 @@ impl ByteConverter for Interaction {
     }
 }
 
+impl BitConverter for Interaction {
+    const BIT_LENGTH: u32 = 2;
+    #[inline(always)]
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::Pressed => bit_writer.write_bits(0, Self::BIT_LENGTH),
+            Self::Hovered => bit_writer.write_bits(1, Self::BIT_LENGTH),
+            Self::None => bit_writer.write_bits(2, Self::BIT_LENGTH),
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        match bit_reader.read_bits(Self::BIT_LENGTH)? {
+            0 => Ok(Self::Pressed),
+            1 => Ok(Self::Hovered),
+            2 => Ok(Self::None),
+            _ => Err("Unexpected enum variant bits.".into()),
+        }
+    }
+}
+
 impl ByteConverter for RelativeCursorPosition {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
         self.normalized_visible_node_rect.append_to_bytes(bytes)?;
@@ -2744,6 +4085,17 @@ pub trait BevyAssetIdentifier {
     fn from_identifier(world: &mut World, identifier: Self::TIdentifier) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized;
 }
 
+#[inline(always)]
+fn get_handle_uuid<T>(handle: &Handle<T>) -> Result<Uuid, Box<dyn Error + Send + Sync + 'static>>
+where
+    T: Asset,
+{
+    let AssetId::Uuid { uuid } = handle.id() else {
+        return Err("Asset was not registered with UUID.".into());
+    };
+    Ok(uuid)
+}
+
 impl<T> BevyAssetIdentifier for Handle<T>
 where
     T: Asset,
@@ -2751,10 +4103,7 @@ where
     type TIdentifier = Uuid;
 
     fn get_identifier(&self, _world: &World) -> Result<Self::TIdentifier, Box<dyn Error + Send + Sync + 'static>> {
-        let AssetId::Uuid { uuid } = self.id() else {
-            return Err("Asset was not registered with UUID.".into());
-        };
-        Ok(uuid)
+        get_handle_uuid(self)
     }
     fn from_identifier(world: &mut World, identifier: Self::TIdentifier) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
         let asset_id = AssetId::Uuid { uuid: identifier };
@@ -2764,30 +4113,749 @@ where
     }
 }
 
+impl<T> ContextualByteConverter<World> for Handle<T>
+where
+    T: Asset,
+{
+    fn append_to_bytes_with(&self, context: &World, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.get_identifier(context)?.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut World, bytes: &'a TBytes, index: &mut usize) -> std::result::Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let identifier = Uuid::extract_from_bytes(bytes, index)?;
+        Self::from_identifier(context, identifier)
+    }
+}
+
+// allows for systems to encode with Res<Assets<T>> and decode with ResMut<Assets<T>> without access to the whole World
+impl<T> ContextualByteConverter<Assets<T>> for Handle<T>
+where
+    T: Asset,
+{
+    fn append_to_bytes_with(&self, _context: &Assets<T>, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        get_handle_uuid(self)?.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut Assets<T>, bytes: &'a TBytes, index: &mut usize) -> std::result::Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let uuid = Uuid::extract_from_bytes(bytes, index)?;
+        let asset_id = AssetId::Uuid { uuid };
+        let handle = context.get_strong_handle(asset_id).ok_or("Failed to find handle by UUID.")?;
+        Ok(handle)
+    }
+}
+
+// falls back to whichever World was set on the current thread by BevyWorldRefSingleton or BevyWorldMutSingleton
 impl<T> ByteConverter for Handle<T>
 where
     T: Asset,
 {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> std::result::Result<(), Box<dyn Error + Send + Sync + 'static>> {
-        let identifier = if WORLD.is_set() {
+        if WORLD.is_set() {
             WORLD.with(|world| {
-                self.get_identifier(world)
-            })?
+                self.append_to_bytes_with(world, bytes)
+            })
         } else if WORLD_MUT.is_set() {
             WORLD_MUT.with(|world| {
-                self.get_identifier(world)
-            })?
+                self.append_to_bytes_with(world, bytes)
+            })
         } else {
-            return Err("Neither BevyWorldRefSingleton nor BevyWorldMutSingleton is set.".into());
-        };
-        identifier.append_to_bytes(bytes)?;
-        Ok(())
+            Err("Neither BevyWorldRefSingleton nor BevyWorldMutSingleton is set.".into())
+        }
     }
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> std::result::Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
-        let identifier = Uuid::extract_from_bytes(bytes, index)?;
-        let handle = WORLD_MUT.with(|world| {
-            Self::from_identifier(world, identifier)
+        if !WORLD_MUT.is_set() {
+            return Err("BevyWorldMutSingleton is not set.".into());
+        }
+        WORLD_MUT.with(|world| {
+            Self::extract_from_bytes_with(world, bytes, index)
+        })
+    }
+}
+
+pub trait ByteConverterEventSender: Send + Sync + 'static {
+    fn send_bytes(&self, bytes: Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+}
+
+// returns None when there are no more bytes available this frame
+pub trait ByteConverterEventReceiver: Send + 'static {
+    fn try_receive_bytes(&mut self) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>>;
+}
+
+impl ByteConverterEventSender for std::sync::mpsc::Sender<Vec<u8>> {
+    fn send_bytes(&self, bytes: Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.send(bytes)?;
+        Ok(())
+    }
+}
+
+impl ByteConverterEventSender for std::sync::mpsc::SyncSender<Vec<u8>> {
+    fn send_bytes(&self, bytes: Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.try_send(bytes)?;
+        Ok(())
+    }
+}
+
+impl ByteConverterEventReceiver for std::sync::mpsc::Receiver<Vec<u8>> {
+    fn try_receive_bytes(&mut self) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>> {
+        match self.try_recv() {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(std::sync::mpsc::TryRecvError::Empty) => Ok(None),
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => Err(BevyByteConError::EventChannelDisconnected.into()),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ByteConverterEventSender for tokio::sync::mpsc::Sender<Vec<u8>> {
+    fn send_bytes(&self, bytes: Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.try_send(bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ByteConverterEventSender for tokio::sync::mpsc::UnboundedSender<Vec<u8>> {
+    fn send_bytes(&self, bytes: Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.send(bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ByteConverterEventReceiver for tokio::sync::mpsc::Receiver<Vec<u8>> {
+    fn try_receive_bytes(&mut self) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>> {
+        match self.try_recv() {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(tokio::sync::mpsc::error::TryRecvError::Empty) => Ok(None),
+            Err(tokio::sync::mpsc::error::TryRecvError::Disconnected) => Err(BevyByteConError::EventChannelDisconnected.into()),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ByteConverterEventReceiver for tokio::sync::mpsc::UnboundedReceiver<Vec<u8>> {
+    fn try_receive_bytes(&mut self) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>> {
+        match self.try_recv() {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(tokio::sync::mpsc::error::TryRecvError::Empty) => Ok(None),
+            Err(tokio::sync::mpsc::error::TryRecvError::Disconnected) => Err(BevyByteConError::EventChannelDisconnected.into()),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct ByteConverterEventSenderResource<T> {
+    sender: Box<dyn ByteConverterEventSender>,
+    phantom_event: PhantomData<fn() -> T>,
+}
+
+#[derive(Resource)]
+struct ByteConverterEventReceiverResource<T> {
+    receiver: Mutex<Box<dyn ByteConverterEventReceiver>>,
+    is_disconnected: bool,
+    phantom_event: PhantomData<fn() -> T>,
+}
+
+fn send_byte_converter_events<T>(
+    mut event_reader: EventReader<T>,
+    sender_resource: Res<ByteConverterEventSenderResource<T>>,
+)
+where
+    T: Event + ByteConverter,
+{
+    for event in event_reader.read() {
+        let send_result = event.to_vec_bytes()
+            .and_then(|bytes| {
+                sender_resource.sender.send_bytes(bytes)
+            });
+        if let Err(error) = send_result {
+            error!("Failed to send event {}: {}", std::any::type_name::<T>(), error);
+        }
+    }
+}
+
+fn receive_byte_converter_events<T>(
+    mut event_writer: EventWriter<T>,
+    mut receiver_resource: ResMut<ByteConverterEventReceiverResource<T>>,
+)
+where
+    T: Event + ByteConverter,
+{
+    if receiver_resource.is_disconnected {
+        return;
+    }
+    let receiver = receiver_resource.receiver
+        .get_mut()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut is_disconnected = false;
+    loop {
+        match receiver.try_receive_bytes() {
+            Ok(Some(bytes)) => {
+                match T::deserialize_from_bytes(&bytes) {
+                    Ok(event) => {
+                        event_writer.write(event);
+                    },
+                    Err(error) => {
+                        error!("Failed to deserialize event {}: {}", std::any::type_name::<T>(), error);
+                    },
+                }
+            },
+            Ok(None) => {
+                break;
+            },
+            Err(error) => {
+                // the receiver is not polled again after it fails so that the error is only reported once
+                error!("Failed to receive event {}: {}", std::any::type_name::<T>(), error);
+                is_disconnected = true;
+                break;
+            },
+        }
+    }
+    receiver_resource.is_disconnected = is_disconnected;
+}
+
+type ByteConverterEventBridgeRegistration = Box<dyn FnOnce(&mut App) + Send + 'static>;
+
+// mirrors events between apps by sending each event read in Last and writing each received event in PreUpdate
+#[derive(Default)]
+pub struct ByteConverterEventBridgePlugin {
+    registrations: Mutex<Vec<ByteConverterEventBridgeRegistration>>,
+}
+
+impl ByteConverterEventBridgePlugin {
+    pub fn with_sender<T>(self, sender: impl ByteConverterEventSender) -> Self
+    where
+        T: Event + ByteConverter,
+    {
+        let sender_resource = ByteConverterEventSenderResource::<T> {
+            sender: Box::new(sender),
+            phantom_event: PhantomData,
+        };
+        self.with_registration(move |app| {
+            app
+                .add_event::<T>()
+                .insert_resource(sender_resource)
+                .add_systems(Last, send_byte_converter_events::<T>);
+        })
+    }
+    pub fn with_receiver<T>(self, receiver: impl ByteConverterEventReceiver) -> Self
+    where
+        T: Event + ByteConverter,
+    {
+        let receiver_resource = ByteConverterEventReceiverResource::<T> {
+            receiver: Mutex::new(Box::new(receiver)),
+            is_disconnected: false,
+            phantom_event: PhantomData,
+        };
+        self.with_registration(move |app| {
+            app
+                .add_event::<T>()
+                .insert_resource(receiver_resource)
+                .add_systems(PreUpdate, receive_byte_converter_events::<T>);
+        })
+    }
+    fn with_registration(self, registration: impl FnOnce(&mut App) + Send + 'static) -> Self {
+        self.registrations
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(Box::new(registration));
+        self
+    }
+}
+
+impl Plugin for ByteConverterEventBridgePlugin {
+    fn build(&self, app: &mut App) {
+        let registrations = std::mem::take(&mut *self.registrations
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()));
+        for registration in registrations {
+            registration(app);
+        }
+    }
+}
+
+// decodes the resource without touching the World so that a load can fail before any resource is inserted
+type ResourceInsertion = Box<dyn FnOnce(&mut World) + Send>;
+
+fn extract_resource_bytes<T>(world: &World) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>>
+where
+    T: Resource + ByteConverter,
+{
+    let resource = world.get_resource::<T>()
+        .ok_or(BevyByteConError::ResourceNotFound {
+            type_name: std::any::type_name::<T>(),
         })?;
-        Ok(handle)
+    resource.to_vec_bytes()
+}
+
+fn get_resource_insertion<T>(resource_bytes: &[u8]) -> Result<ResourceInsertion, Box<dyn Error + Send + Sync + 'static>>
+where
+    T: Resource + ByteConverter,
+{
+    let resource = T::deserialize_from_bytes(&resource_bytes)?;
+    Ok(Box::new(move |world: &mut World| {
+        world.insert_resource(resource);
+    }))
+}
+
+type ContainsResourceFunction = fn(&World) -> bool;
+type ExtractResourceBytesFunction = fn(&World) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>>;
+type GetResourceInsertionFunction = fn(&[u8]) -> Result<ResourceInsertion, Box<dyn Error + Send + Sync + 'static>>;
+
+struct RegisteredResource {
+    key: &'static str,
+    type_id: TypeId,
+    contains_resource_function: ContainsResourceFunction,
+    extract_resource_bytes_function: ExtractResourceBytesFunction,
+    get_resource_insertion_function: GetResourceInsertionFunction,
+}
+
+// saves the registered resources that currently exist into a single blob keyed by the caller supplied key
+// the keys are stored in the blob, so they should stay the same across builds even when the resource types are renamed or moved
+#[derive(Resource, Default)]
+pub struct ByteConverterResourceRegistry {
+    registered_resources: Vec<RegisteredResource>,
+}
+
+impl ByteConverterResourceRegistry {
+    // registering the same type under the same key again is a no-op, but reusing a key for a different type fails
+    pub fn register<T>(&mut self, key: &'static str) -> Result<&mut Self, Box<dyn Error + Send + Sync + 'static>>
+    where
+        T: Resource + ByteConverter,
+    {
+        if let Some(registered_resource) = self.registered_resources.iter().find(|registered_resource| registered_resource.key == key) {
+            if registered_resource.type_id != TypeId::of::<T>() {
+                return Err(BevyByteConError::ResourceKeyConflict {
+                    key,
+                }.into());
+            }
+            return Ok(self);
+        }
+        self.registered_resources.push(RegisteredResource {
+            key,
+            type_id: TypeId::of::<T>(),
+            contains_resource_function: World::contains_resource::<T>,
+            extract_resource_bytes_function: extract_resource_bytes::<T>,
+            get_resource_insertion_function: get_resource_insertion::<T>,
+        });
+        Ok(self)
+    }
+    pub fn get_registered_keys(&self) -> Vec<&'static str> {
+        self.registered_resources.iter()
+            .map(|registered_resource| registered_resource.key)
+            .collect()
+    }
+    pub fn save(&self, world: &World) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        let mut resource_bytes_per_key = Vec::with_capacity(self.registered_resources.len());
+        for registered_resource in self.registered_resources.iter() {
+            if !(registered_resource.contains_resource_function)(world) {
+                continue;
+            }
+            let resource_bytes = (registered_resource.extract_resource_bytes_function)(world)?;
+            resource_bytes_per_key.push((String::from(registered_resource.key), resource_bytes));
+        }
+        resource_bytes_per_key.to_vec_bytes()
+    }
+    // every resource is decoded before any is inserted, so a failed load leaves the World unchanged
+    pub fn load<TBytes: AsRef<[u8]>>(&self, world: &mut World, bytes: &TBytes) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let resource_bytes_per_key = Vec::<(String, Vec<u8>)>::deserialize_from_bytes(bytes)?;
+        let mut resource_insertions = Vec::with_capacity(resource_bytes_per_key.len());
+        for (key, resource_bytes) in resource_bytes_per_key.iter() {
+            let registered_resource = self.registered_resources.iter()
+                .find(|registered_resource| registered_resource.key == key.as_str())
+                .ok_or_else(|| BevyByteConError::ResourceKeyNotRegistered {
+                    key: key.clone(),
+                })?;
+            resource_insertions.push((registered_resource.get_resource_insertion_function)(resource_bytes)?);
+        }
+        for resource_insertion in resource_insertions {
+            resource_insertion(world);
+        }
+        Ok(())
+    }
+}
+
+pub trait ByteConverterResourceAppExt {
+    // panics if the key is already registered to a different resource type, like other App setup mistakes
+    fn register_byte_converter_resource<T>(&mut self, key: &'static str) -> &mut Self
+    where
+        T: Resource + ByteConverter;
+}
+
+impl ByteConverterResourceAppExt for App {
+    fn register_byte_converter_resource<T>(&mut self, key: &'static str) -> &mut Self
+    where
+        T: Resource + ByteConverter,
+    {
+        if let Err(error) = self.world_mut()
+            .get_resource_or_init::<ByteConverterResourceRegistry>()
+            .register::<T>(key) {
+            panic!("{error}");
+        }
+        self
+    }
+}
+
+#[inline(always)]
+fn save_resources(world: &mut World) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+    let registry = world.get_resource::<ByteConverterResourceRegistry>()
+        .ok_or(BevyByteConError::ResourceRegistryNotFound)?;
+    registry.save(world)
+}
+
+#[inline(always)]
+fn load_resources(world: &mut World, bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    world.resource_scope::<ByteConverterResourceRegistry, _>(|world, registry| {
+        registry.load(world, &bytes)
+    })
+}
+
+// exposes the ByteConverterResourceRegistry as commands, logging any failure when the command is applied
+pub trait ByteConverterResourceCommandsExt {
+    fn save_resources_to_file(&mut self, path: impl Into<PathBuf>);
+    fn load_resources_from_file(&mut self, path: impl Into<PathBuf>);
+    fn load_resources_from_bytes(&mut self, bytes: Vec<u8>);
+}
+
+impl ByteConverterResourceCommandsExt for Commands<'_, '_> {
+    fn save_resources_to_file(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        self.queue(move |world: &mut World| {
+            let save_result = save_resources(world)
+                .and_then(|bytes| {
+                    std::fs::write(&path, bytes)?;
+                    Ok(())
+                });
+            if let Err(error) = save_result {
+                error!("Failed to save resources to {}: {}", path.display(), error);
+            }
+        });
+    }
+    fn load_resources_from_file(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        self.queue(move |world: &mut World| {
+            let load_result = std::fs::read(&path)
+                .map_err(|error| error.into())
+                .and_then(|bytes| {
+                    if !world.contains_resource::<ByteConverterResourceRegistry>() {
+                        return Err(BevyByteConError::ResourceRegistryNotFound.into());
+                    }
+                    load_resources(world, &bytes)
+                });
+            if let Err(error) = load_result {
+                error!("Failed to load resources from {}: {}", path.display(), error);
+            }
+        });
+    }
+    fn load_resources_from_bytes(&mut self, bytes: Vec<u8>) {
+        self.queue(move |world: &mut World| {
+            if !world.contains_resource::<ByteConverterResourceRegistry>() {
+                error!("Failed to load resources: {}", BevyByteConError::ResourceRegistryNotFound);
+                return;
+            }
+            if let Err(error) = load_resources(world, &bytes) {
+                error!("Failed to load resources: {}", error);
+            }
+        });
+    }
+}
+
+// the input that was sent to an app during a single frame along with the real time that passed and the rng state at the start of the frame
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputRecordingFrame {
+    pub delta: Duration,
+    pub rng_bytes: Option<Vec<u8>>,
+    pub keyboard_inputs: Vec<KeyboardInput>,
+    pub mouse_button_inputs: Vec<MouseButtonInput>,
+    pub mouse_motions: Vec<MouseMotion>,
+    pub mouse_wheels: Vec<MouseWheel>,
+    pub gamepad_events: Vec<GamepadEvent>,
+    pub touch_inputs: Vec<TouchInput>,
+}
+
+impl ByteConverter for InputRecordingFrame {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.delta.append_to_bytes(bytes)?;
+        self.rng_bytes.append_to_bytes(bytes)?;
+        self.keyboard_inputs.append_to_bytes(bytes)?;
+        self.mouse_button_inputs.append_to_bytes(bytes)?;
+        self.mouse_motions.append_to_bytes(bytes)?;
+        self.mouse_wheels.append_to_bytes(bytes)?;
+        self.gamepad_events.append_to_bytes(bytes)?;
+        self.touch_inputs.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            delta: Duration::extract_from_bytes(bytes, index)?,
+            rng_bytes: Option::<Vec<u8>>::extract_from_bytes(bytes, index)?,
+            keyboard_inputs: Vec::<KeyboardInput>::extract_from_bytes(bytes, index)?,
+            mouse_button_inputs: Vec::<MouseButtonInput>::extract_from_bytes(bytes, index)?,
+            mouse_motions: Vec::<MouseMotion>::extract_from_bytes(bytes, index)?,
+            mouse_wheels: Vec::<MouseWheel>::extract_from_bytes(bytes, index)?,
+            gamepad_events: Vec::<GamepadEvent>::extract_from_bytes(bytes, index)?,
+            touch_inputs: Vec::<TouchInput>::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputRecording {
+    pub frames: Vec<InputRecordingFrame>,
+}
+
+impl InputRecording {
+    pub fn save_to_file(&self, path: impl AsRef<std::path::Path>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        std::fs::write(path, self.to_vec_bytes()?)?;
+        Ok(())
+    }
+    pub fn load_from_file(path: impl AsRef<std::path::Path>) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let bytes = std::fs::read(path)?;
+        Self::deserialize_from_bytes(&bytes)
+    }
+}
+
+impl ByteConverter for InputRecording {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.frames.append_to_bytes(bytes)?;
+        Ok(())
     }
-}
\ No newline at end of file
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            frames: Vec::<InputRecordingFrame>::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+fn get_rng_resource_bytes<T>(world: &World) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>>
+where
+    T: Resource + ByteConverter,
+{
+    world.get_resource::<T>()
+        .map(|resource| resource.to_vec_bytes())
+        .transpose()
+}
+
+fn insert_rng_resource_from_bytes<T>(world: &mut World, bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>>
+where
+    T: Resource + ByteConverter,
+{
+    world.insert_resource(T::deserialize_from_bytes(&bytes)?);
+    Ok(())
+}
+
+type GetRngBytesFunction = fn(&World) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>>;
+type InsertRngFromBytesFunction = fn(&mut World, &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+
+#[derive(Resource)]
+pub struct InputRecorder {
+    recording: InputRecording,
+    is_recording: bool,
+    get_rng_bytes_function: Option<GetRngBytesFunction>,
+}
+
+impl InputRecorder {
+    pub fn get_recording(&self) -> &InputRecording {
+        &self.recording
+    }
+    pub fn take_recording(&mut self) -> InputRecording {
+        std::mem::take(&mut self.recording)
+    }
+    pub fn is_recording(&self) -> bool {
+        self.is_recording
+    }
+    pub fn pause(&mut self) {
+        self.is_recording = false;
+    }
+    pub fn resume(&mut self) {
+        self.is_recording = true;
+    }
+    pub fn save_to_file(&self, path: impl AsRef<std::path::Path>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.recording.save_to_file(path)
+    }
+}
+
+// the rng state is taken before anything else in the frame can advance it
+fn begin_input_recording_frame(world: &mut World) {
+    world.resource_scope::<InputRecorder, _>(|world, mut input_recorder| {
+        if !input_recorder.is_recording {
+            return;
+        }
+        let rng_bytes = match input_recorder.get_rng_bytes_function {
+            Some(get_rng_bytes_function) => match get_rng_bytes_function(world) {
+                Ok(rng_bytes) => rng_bytes,
+                Err(error) => {
+                    error!("Failed to record rng state: {}", error);
+                    None
+                },
+            },
+            None => None,
+        };
+        input_recorder.recording.frames.push(InputRecordingFrame {
+            rng_bytes,
+            ..Default::default()
+        });
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn end_input_recording_frame(
+    mut input_recorder: ResMut<InputRecorder>,
+    real_time: Res<Time<Real>>,
+    mut keyboard_inputs: EventReader<KeyboardInput>,
+    mut mouse_button_inputs: EventReader<MouseButtonInput>,
+    mut mouse_motions: EventReader<MouseMotion>,
+    mut mouse_wheels: EventReader<MouseWheel>,
+    mut gamepad_events: EventReader<GamepadEvent>,
+    mut touch_inputs: EventReader<TouchInput>,
+) {
+    if !input_recorder.is_recording {
+        return;
+    }
+    let Some(frame) = input_recorder.recording.frames.last_mut() else {
+        return;
+    };
+    frame.delta = real_time.delta();
+    frame.keyboard_inputs.extend(keyboard_inputs.read().cloned());
+    frame.mouse_button_inputs.extend(mouse_button_inputs.read().cloned());
+    frame.mouse_motions.extend(mouse_motions.read().cloned());
+    frame.mouse_wheels.extend(mouse_wheels.read().cloned());
+    frame.gamepad_events.extend(gamepad_events.read().cloned());
+    frame.touch_inputs.extend(touch_inputs.read().cloned());
+}
+
+fn add_input_events(app: &mut App) {
+    app
+        .add_event::<KeyboardInput>()
+        .add_event::<MouseButtonInput>()
+        .add_event::<MouseMotion>()
+        .add_event::<MouseWheel>()
+        .add_event::<GamepadEvent>()
+        .add_event::<TouchInput>();
+}
+
+// records the input events, Time<Real> deltas and optionally the state of an rng resource of every frame
+#[derive(Default)]
+pub struct InputRecorderPlugin {
+    get_rng_bytes_function: Option<GetRngBytesFunction>,
+}
+
+impl InputRecorderPlugin {
+    pub fn with_rng_resource<T>(mut self) -> Self
+    where
+        T: Resource + ByteConverter,
+    {
+        self.get_rng_bytes_function = Some(get_rng_resource_bytes::<T>);
+        self
+    }
+}
+
+impl Plugin for InputRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        add_input_events(app);
+        app
+            .insert_resource(InputRecorder {
+                recording: InputRecording::default(),
+                is_recording: true,
+                get_rng_bytes_function: self.get_rng_bytes_function,
+            })
+            .add_systems(First, begin_input_recording_frame)
+            .add_systems(Last, end_input_recording_frame);
+    }
+}
+
+#[derive(Resource)]
+pub struct InputPlayer {
+    recording: InputRecording,
+    next_frame_index: usize,
+    insert_rng_from_bytes_function: Option<InsertRngFromBytesFunction>,
+}
+
+impl InputPlayer {
+    pub fn get_recording(&self) -> &InputRecording {
+        &self.recording
+    }
+    pub fn get_next_frame_index(&self) -> usize {
+        self.next_frame_index
+    }
+    pub fn is_finished(&self) -> bool {
+        self.next_frame_index >= self.recording.frames.len()
+    }
+}
+
+// runs before the time system so that the recorded delta is applied to the frame it was recorded in
+fn play_input_recording_frame(world: &mut World) {
+    world.resource_scope::<InputPlayer, _>(|world, mut input_player| {
+        let Some(frame) = input_player.recording.frames.get(input_player.next_frame_index) else {
+            return;
+        };
+        world.insert_resource(TimeUpdateStrategy::ManualDuration(frame.delta));
+        if let (Some(insert_rng_from_bytes_function), Some(rng_bytes)) = (input_player.insert_rng_from_bytes_function, frame.rng_bytes.as_ref()) {
+            if let Err(error) = insert_rng_from_bytes_function(world, rng_bytes) {
+                error!("Failed to restore recorded rng state: {}", error);
+            }
+        }
+        world.send_event_batch(frame.keyboard_inputs.iter().cloned());
+        world.send_event_batch(frame.mouse_button_inputs.iter().cloned());
+        world.send_event_batch(frame.mouse_motions.iter().cloned());
+        world.send_event_batch(frame.mouse_wheels.iter().cloned());
+        world.send_event_batch(frame.gamepad_events.iter().cloned());
+        world.send_event_batch(frame.touch_inputs.iter().cloned());
+        input_player.next_frame_index += 1;
+    });
+}
+
+// feeds an InputRecording back into an app one frame per update, meant for headless apps without real input devices
+pub struct InputPlayerPlugin {
+    recording: InputRecording,
+    insert_rng_from_bytes_function: Option<InsertRngFromBytesFunction>,
+}
+
+impl InputPlayerPlugin {
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            insert_rng_from_bytes_function: None,
+        }
+    }
+    pub fn with_rng_resource<T>(mut self) -> Self
+    where
+        T: Resource + ByteConverter,
+    {
+        self.insert_rng_from_bytes_function = Some(insert_rng_resource_from_bytes::<T>);
+        self
+    }
+}
+
+impl Plugin for InputPlayerPlugin {
+    fn build(&self, app: &mut App) {
+        add_input_events(app);
+        app
+            .insert_resource(InputPlayer {
+                recording: self.recording.clone(),
+                next_frame_index: 0,
+                insert_rng_from_bytes_function: self.insert_rng_from_bytes_function,
+            })
+            .add_systems(First, play_input_recording_frame.before(TimeSystem));
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+enum BevyByteConError {
+    #[error("Event channel disconnected.")]
+    EventChannelDisconnected,
+    #[error("Resource {type_name} not found in World.")]
+    ResourceNotFound {
+        type_name: &'static str,
+    },
+    #[error("ByteConverterResourceRegistry not found in World.")]
+    ResourceRegistryNotFound,
+    #[error("Resource key {key} is not registered.")]
+    ResourceKeyNotRegistered {
+        key: String,
+    },
+    #[error("Resource key {key} is already registered to a different resource type.")]
+    ResourceKeyConflict {
+        key: &'static str,
+    },
+}
@@ -0,0 +1,178 @@
+use std::{error::Error, io::{ErrorKind, Read, Write}, net::TcpStream, time::Duration};
+use crate::{base::{append_stream_frame, STREAM_READ_CHUNK_SIZE}, ByteConverter, ByteStreamReader, ByteStreamWriter};
+
+const LENGTH_PREFIX_LENGTH: usize = 8;
+
+// reads ask for at least this many bytes so that the frames following a small frame are usually buffered by the same read
+const READ_AHEAD_LENGTH: usize = 8 * 1024;
+
+// buffers that grew beyond this for a large frame are shrunk back once that frame is done with
+const MAX_RETAINED_BUFFER_LENGTH: usize = STREAM_READ_CHUNK_SIZE;
+
+// reads and writes the same u64 length prefixed frames as the blanket std::io impls while reusing its buffers across messages
+// unlike the blanket impls, every write is flushed
+// bytes are read ahead into the buffer, so a read that fails part way through a frame, such as from a read timeout, resumes that frame on the next read
+pub struct BlockingByteStream<TStream> {
+    stream: TStream,
+    read_bytes: Vec<u8>,
+    read_index: usize,
+    read_end_index: usize,
+    write_bytes: Vec<u8>,
+}
+
+impl<TStream> BlockingByteStream<TStream> {
+    pub fn new(stream: TStream) -> Self {
+        Self {
+            stream,
+            read_bytes: Vec::new(),
+            read_index: 0,
+            read_end_index: 0,
+            write_bytes: Vec::new(),
+        }
+    }
+    pub fn get_ref(&self) -> &TStream {
+        &self.stream
+    }
+    // reading from the inner stream directly loses track of the frames, since some of their bytes may already be buffered
+    pub fn get_mut(&mut self) -> &mut TStream {
+        &mut self.stream
+    }
+    pub fn into_inner(self) -> TStream {
+        self.stream
+    }
+    // true when a previous read failed after some of the next frame was already read
+    pub fn has_partial_frame(&self) -> bool {
+        match self.get_buffered_frame_length() {
+            Ok(Some(frame_length)) => frame_length > self.buffered_length(),
+            Ok(None) => self.buffered_length() != 0,
+            Err(_) => true,
+        }
+    }
+    // the number of bytes read from the stream that have not been returned as part of a value yet
+    pub fn buffered_length(&self) -> usize {
+        self.read_end_index - self.read_index
+    }
+    // the capacity held by the read buffer, which only stays above its usual size while a large frame is being read
+    pub fn read_buffer_capacity(&self) -> usize {
+        self.read_bytes.capacity()
+    }
+    // the length of the next frame, including its length prefix, once its length prefix is buffered
+    fn get_buffered_frame_length(&self) -> Result<Option<usize>, Box<dyn Error + Send + Sync + 'static>> {
+        if self.buffered_length() < LENGTH_PREFIX_LENGTH {
+            return Ok(None);
+        }
+        let mut length_prefix_bytes = [0u8; LENGTH_PREFIX_LENGTH];
+        length_prefix_bytes.copy_from_slice(&self.read_bytes[self.read_index..self.read_index + LENGTH_PREFIX_LENGTH]);
+        let frame_length = usize::try_from(u64::from_le_bytes(length_prefix_bytes))?
+            .checked_add(LENGTH_PREFIX_LENGTH)
+            .ok_or_else(|| std::io::Error::new(ErrorKind::InvalidData, "Frame length overflowed."))?;
+        Ok(Some(frame_length))
+    }
+    // deserializes the next frame, which must already be fully buffered
+    fn take_buffered_frame<T: ByteConverter>(&mut self, frame_length: usize) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        let payload_index = self.read_index + LENGTH_PREFIX_LENGTH;
+        self.read_index += frame_length;
+        let value = T::deserialize_from_bytes(&&self.read_bytes[payload_index..self.read_index]);
+        if self.buffered_length() == 0 && self.read_bytes.capacity() > MAX_RETAINED_BUFFER_LENGTH {
+            self.read_bytes = Vec::new();
+            self.read_index = 0;
+            self.read_end_index = 0;
+        }
+        value
+    }
+}
+
+impl BlockingByteStream<TcpStream> {
+    // a read that times out fails with WouldBlock or TimedOut, depending on the platform, and can be retried
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.stream.set_read_timeout(timeout)?;
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+impl BlockingByteStream<std::os::unix::net::UnixStream> {
+    // a read that times out fails with WouldBlock and can be retried
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.stream.set_read_timeout(timeout)?;
+        Ok(())
+    }
+}
+
+// unlike Read::read_exact, the bytes read before an error are kept by the caller
+#[inline(always)]
+fn read_some<TRead: Read>(stream: &mut TRead, buffer: &mut [u8]) -> Result<usize, std::io::Error> {
+    loop {
+        match stream.read(buffer) {
+            Ok(0) => return Err(std::io::Error::new(ErrorKind::UnexpectedEof, "Stream ended before the whole frame was read.")),
+            Ok(read_length) => return Ok(read_length),
+            Err(error) if error.kind() == ErrorKind::Interrupted => {},
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+impl<TStream: Read> BlockingByteStream<TStream> {
+    // the buffer only grows a chunk at a time so that a corrupted length cannot allocate more than the stream provides
+    fn fill_buffer(&mut self, length: usize) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        if self.buffered_length() >= length {
+            return Ok(());
+        }
+
+        // the unread bytes are moved to the front so that the buffer is reused instead of growing
+        self.read_bytes.copy_within(self.read_index..self.read_end_index, 0);
+        self.read_end_index -= self.read_index;
+        self.read_index = 0;
+        while self.buffered_length() < length {
+            let chunk_length = (length - self.buffered_length())
+                .clamp(READ_AHEAD_LENGTH, STREAM_READ_CHUNK_SIZE);
+            if self.read_bytes.len() < self.read_end_index + chunk_length {
+                self.read_bytes.resize(self.read_end_index + chunk_length, 0);
+            }
+            self.read_end_index += read_some(&mut self.stream, &mut self.read_bytes[self.read_end_index..])?;
+        }
+        Ok(())
+    }
+}
+
+impl<TStream: Read> ByteStreamReader for BlockingByteStream<TStream> {
+    fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        self.fill_buffer(LENGTH_PREFIX_LENGTH)?;
+        let frame_length = self.get_buffered_frame_length()?
+            .ok_or_else(|| std::io::Error::new(ErrorKind::UnexpectedEof, "Stream ended before the length prefix was read."))?;
+        self.fill_buffer(frame_length)?;
+        self.take_buffered_frame(frame_length)
+    }
+    fn read_many<T: ByteConverter>(&mut self, max: usize) -> Result<Vec<T>, Box<dyn Error + Send + Sync + 'static>> {
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+        let mut values = vec![self.read_to_byte_converter()?];
+        while values.len() < max {
+            match self.get_buffered_frame_length()? {
+                Some(frame_length) if frame_length <= self.buffered_length() => values.push(self.take_buffered_frame(frame_length)?),
+                _ => break,
+            }
+        }
+        Ok(values)
+    }
+}
+
+impl<TStream: Write> ByteStreamWriter for BlockingByteStream<TStream> {
+    // a failed write may have written part of the frame, after which the peer can no longer read the stream
+    fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.write_many(std::slice::from_ref(byte_converter))
+    }
+    fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.write_bytes.clear();
+        for byte_converter in byte_converters {
+            append_stream_frame(&mut self.write_bytes, byte_converter)?;
+        }
+        self.stream.write_all(&self.write_bytes)?;
+        self.stream.flush()?;
+        if self.write_bytes.capacity() > MAX_RETAINED_BUFFER_LENGTH {
+            self.write_bytes = Vec::new();
+        }
+        Ok(())
+    }
+}
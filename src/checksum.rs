@@ -0,0 +1,270 @@
+use std::{error::Error, marker::PhantomData};
+use crate::{ByteConverter, ByteStreamReader, ByteStreamWriter};
+#[cfg(feature = "tokio")]
+use crate::{ByteStreamReaderAsync, ByteStreamWriterAsync};
+
+pub const DEFAULT_MAX_FRAME_LENGTH: usize = 16 * 1024 * 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithmKind {
+    Crc32c,
+    Xxh3,
+}
+
+impl ChecksumAlgorithmKind {
+    // the number of bytes that the checksum takes up after the bytes that it covers
+    pub fn checksum_length(&self) -> usize {
+        match self {
+            Self::Crc32c => 4,
+            Self::Xxh3 => 8,
+        }
+    }
+    pub fn checksum(&self, bytes: &[u8]) -> u64 {
+        match self {
+            Self::Crc32c => Crc32c::checksum(bytes),
+            Self::Xxh3 => Xxh3::checksum(bytes),
+        }
+    }
+    fn append_checksum_to_bytes(&self, checksum: u64, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&checksum.to_le_bytes()[..self.checksum_length()]);
+    }
+    fn verify_checksum(&self, covered_bytes: &[u8], checksum_bytes: &[u8]) -> Result<(), ChecksumMismatch> {
+        let mut expected_bytes = [0u8; 8];
+        expected_bytes[..checksum_bytes.len()].copy_from_slice(checksum_bytes);
+        let expected = u64::from_le_bytes(expected_bytes);
+        let actual = self.checksum(covered_bytes);
+        if expected != actual {
+            return Err(ChecksumMismatch {
+                algorithm: *self,
+                expected,
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl ByteConverter for ChecksumAlgorithmKind {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            Self::Crc32c => 0u8.append_to_bytes(bytes)?,
+            Self::Xxh3 => 1u8.append_to_bytes(bytes)?,
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::Crc32c),
+            1u8 => Ok(Self::Xxh3),
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
+    }
+}
+
+pub trait ChecksumAlgorithm {
+    const KIND: ChecksumAlgorithmKind;
+    fn checksum(bytes: &[u8]) -> u64;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Crc32c;
+
+impl ChecksumAlgorithm for Crc32c {
+    const KIND: ChecksumAlgorithmKind = ChecksumAlgorithmKind::Crc32c;
+    fn checksum(bytes: &[u8]) -> u64 {
+        crc32c::crc32c(bytes) as u64
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Xxh3;
+
+impl ChecksumAlgorithm for Xxh3 {
+    const KIND: ChecksumAlgorithmKind = ChecksumAlgorithmKind::Xxh3;
+    fn checksum(bytes: &[u8]) -> u64 {
+        xxhash_rust::xxh3::xxh3_64(bytes)
+    }
+}
+
+// returned when the bytes do not match their checksum, which can be found by downcasting the boxed error
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq)]
+#[error("Checksum mismatch for {algorithm:?} where {expected:#x} was expected but the bytes hash to {actual:#x}.")]
+pub struct ChecksumMismatch {
+    pub algorithm: ChecksumAlgorithmKind,
+    pub expected: u64,
+    pub actual: u64,
+}
+
+// returned when a verified frame length is above the maximum of the stream, which can be found by downcasting the boxed error
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq)]
+#[error("Frame length {frame_length} is above the maximum of {max_frame_length}.")]
+pub struct FrameLengthExceeded {
+    pub frame_length: u64,
+    pub max_frame_length: usize,
+}
+
+// the bytes of the value are length prefixed and followed by their checksum
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Checksummed<T, TAlgorithm: ChecksumAlgorithm = Crc32c> {
+    pub value: T,
+    phantom_algorithm: PhantomData<TAlgorithm>,
+}
+
+impl<T, TAlgorithm: ChecksumAlgorithm> Checksummed<T, TAlgorithm> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            phantom_algorithm: PhantomData,
+        }
+    }
+}
+
+impl<T, TAlgorithm: ChecksumAlgorithm> From<T> for Checksummed<T, TAlgorithm> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: ByteConverter, TAlgorithm: ChecksumAlgorithm> ByteConverter for Checksummed<T, TAlgorithm> {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let value_bytes = self.value.to_vec_bytes()?;
+        value_bytes.append_to_bytes(bytes)?;
+        TAlgorithm::KIND.append_checksum_to_bytes(TAlgorithm::checksum(&value_bytes), bytes);
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let value_bytes_length = usize::extract_from_bytes(bytes, index)?;
+        let value_bytes = crate::get_multiple_bytes(bytes, index, value_bytes_length)?;
+        let checksum_bytes = crate::get_multiple_bytes(bytes, index, TAlgorithm::KIND.checksum_length())?;
+        TAlgorithm::KIND.verify_checksum(value_bytes, checksum_bytes)?;
+        Ok(Self::new(T::deserialize_from_bytes(&value_bytes)?))
+    }
+}
+
+// frames each message as a length with its own checksum, followed by the message bytes and their checksum
+// the length is verified before anything is allocated for the message so that a corrupted length is reported instead of read
+// a length that passes its checksum is still rejected when it is above the maximum frame length, since the sender may not be trusted
+pub struct ChecksummedByteStream<TStream> {
+    stream: TStream,
+    algorithm: ChecksumAlgorithmKind,
+    max_frame_length: usize,
+}
+
+impl<TStream> ChecksummedByteStream<TStream> {
+    pub fn new(stream: TStream, algorithm: ChecksumAlgorithmKind) -> Self {
+        Self {
+            stream,
+            algorithm,
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+        }
+    }
+    pub fn with_max_frame_length(mut self, max_frame_length: usize) -> Self {
+        self.max_frame_length = max_frame_length;
+        self
+    }
+    pub fn algorithm(&self) -> ChecksumAlgorithmKind {
+        self.algorithm
+    }
+    pub fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+    pub fn get_ref(&self) -> &TStream {
+        &self.stream
+    }
+    pub fn get_mut(&mut self) -> &mut TStream {
+        &mut self.stream
+    }
+    pub fn into_inner(self) -> TStream {
+        self.stream
+    }
+    fn header_length(&self) -> usize {
+        8 + self.algorithm.checksum_length()
+    }
+    fn frame_bytes(&self, byte_converter: &impl ByteConverter) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        let message_bytes = byte_converter.to_vec_bytes()?;
+        let checksum_length = self.algorithm.checksum_length();
+        let mut bytes = Vec::with_capacity(self.header_length() + message_bytes.len() + checksum_length);
+        let length_bytes = (message_bytes.len() as u64).to_le_bytes();
+        bytes.extend_from_slice(&length_bytes);
+        self.algorithm.append_checksum_to_bytes(self.algorithm.checksum(&length_bytes), &mut bytes);
+        bytes.extend_from_slice(&message_bytes);
+        self.algorithm.append_checksum_to_bytes(self.algorithm.checksum(&message_bytes), &mut bytes);
+        Ok(bytes)
+    }
+    // returns the length of the message bytes and their checksum that follow the header
+    fn verify_header(&self, header_bytes: &[u8]) -> Result<usize, Box<dyn Error + Send + Sync + 'static>> {
+        let (length_bytes, checksum_bytes) = header_bytes.split_at(8);
+        self.algorithm.verify_checksum(length_bytes, checksum_bytes)?;
+        let frame_length = u64::from_le_bytes(length_bytes.try_into()?);
+        let frame_length_exceeded = FrameLengthExceeded {
+            frame_length,
+            max_frame_length: self.max_frame_length,
+        };
+        let message_bytes_length = usize::try_from(frame_length)
+            .ok()
+            .filter(|message_bytes_length| *message_bytes_length <= self.max_frame_length)
+            .ok_or(frame_length_exceeded)?;
+        Ok(message_bytes_length.checked_add(self.algorithm.checksum_length())
+            .ok_or(frame_length_exceeded)?)
+    }
+    fn verify_message<T: ByteConverter>(&self, message_and_checksum_bytes: &[u8]) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        let (message_bytes, checksum_bytes) = message_and_checksum_bytes.split_at(message_and_checksum_bytes.len() - self.algorithm.checksum_length());
+        self.algorithm.verify_checksum(message_bytes, checksum_bytes)?;
+        T::deserialize_from_bytes(&message_bytes)
+    }
+}
+
+impl<TStream: std::io::Read> ByteStreamReader for ChecksummedByteStream<TStream> {
+    fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        let mut header_bytes = vec![0u8; self.header_length()];
+        self.stream.read_exact(&mut header_bytes)?;
+        let message_and_checksum_bytes_length = self.verify_header(&header_bytes)?;
+        let mut message_and_checksum_bytes = vec![0u8; message_and_checksum_bytes_length];
+        self.stream.read_exact(&mut message_and_checksum_bytes)?;
+        self.verify_message(&message_and_checksum_bytes)
+    }
+}
+
+impl<TStream: std::io::Write> ByteStreamWriter for ChecksummedByteStream<TStream> {
+    fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let bytes = self.frame_bytes(byte_converter)?;
+        self.stream.write_all(&bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<TStream: tokio::io::AsyncRead + Unpin> ByteStreamReaderAsync for ChecksummedByteStream<TStream> {
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        use tokio::io::AsyncReadExt;
+
+        let mut header_bytes = vec![0u8; self.header_length()];
+        self.stream.read_exact(&mut header_bytes)
+            .await?;
+        let message_and_checksum_bytes_length = self.verify_header(&header_bytes)?;
+        let mut message_and_checksum_bytes = vec![0u8; message_and_checksum_bytes_length];
+        self.stream.read_exact(&mut message_and_checksum_bytes)
+            .await?;
+        self.verify_message(&message_and_checksum_bytes)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<TStream: tokio::io::AsyncWrite + Unpin> ByteStreamWriterAsync for ChecksummedByteStream<TStream> {
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        use tokio::io::AsyncWriteExt;
+
+        let bytes = self.frame_bytes(byte_converter)?;
+        self.stream.write_all(&bytes)
+            .await?;
+        // tls streams hold on to written bytes until they are flushed
+        self.stream.flush()
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,477 @@
+use std::{error::Error, marker::PhantomData};
+use crate::{ByteConverter, ByteStreamReader, ByteStreamWriter};
+#[cfg(feature = "tokio")]
+use crate::{ByteStreamReaderAsync, ByteStreamWriterAsync};
+
+// the largest uncompressed length that is decompressed unless configured otherwise, since the recorded length comes from the sender
+pub const DEFAULT_MAX_UNCOMPRESSED_LENGTH: usize = 16 * 1024 * 1024;
+
+// the algorithm byte that is recorded with every compressed payload, where 0 means that the payload is not compressed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompressionAlgorithmKind {
+    Zstd,
+    Lz4,
+    Deflate,
+}
+
+impl CompressionAlgorithmKind {
+    // the algorithms that were enabled by features, in order of preference
+    pub fn supported() -> Vec<Self> {
+        let mut supported = Vec::new();
+        if cfg!(feature = "zstd") {
+            supported.push(Self::Zstd);
+        }
+        if cfg!(feature = "lz4") {
+            supported.push(Self::Lz4);
+        }
+        if cfg!(feature = "deflate") {
+            supported.push(Self::Deflate);
+        }
+        supported
+    }
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::Zstd => cfg!(feature = "zstd"),
+            Self::Lz4 => cfg!(feature = "lz4"),
+            Self::Deflate => cfg!(feature = "deflate"),
+        }
+    }
+    #[allow(unused_variables)]
+    pub fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            #[cfg(feature = "zstd")]
+            Self::Zstd => Zstd::compress(bytes),
+            #[cfg(feature = "lz4")]
+            Self::Lz4 => Lz4::compress(bytes),
+            #[cfg(feature = "deflate")]
+            Self::Deflate => Deflate::compress(bytes),
+            #[allow(unreachable_patterns)]
+            _ => Err(CompressionByteConError::UnsupportedAlgorithm {
+                algorithm: *self,
+            }.into()),
+        }
+    }
+    #[allow(unused_variables)]
+    pub fn decompress(&self, bytes: &[u8], uncompressed_length: usize, max_uncompressed_length: usize) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            #[cfg(feature = "zstd")]
+            Self::Zstd => Zstd::decompress(bytes, uncompressed_length, max_uncompressed_length),
+            #[cfg(feature = "lz4")]
+            Self::Lz4 => Lz4::decompress(bytes, uncompressed_length, max_uncompressed_length),
+            #[cfg(feature = "deflate")]
+            Self::Deflate => Deflate::decompress(bytes, uncompressed_length, max_uncompressed_length),
+            #[allow(unreachable_patterns)]
+            _ => Err(CompressionByteConError::UnsupportedAlgorithm {
+                algorithm: *self,
+            }.into()),
+        }
+    }
+}
+
+impl ByteConverter for CompressionAlgorithmKind {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            Self::Zstd => 1u8.append_to_bytes(bytes)?,
+            Self::Lz4 => 2u8.append_to_bytes(bytes)?,
+            Self::Deflate => 3u8.append_to_bytes(bytes)?,
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            1u8 => Ok(Self::Zstd),
+            2u8 => Ok(Self::Lz4),
+            3u8 => Ok(Self::Deflate),
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
+    }
+}
+
+pub trait CompressionAlgorithm {
+    const KIND: CompressionAlgorithmKind;
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>>;
+    // fails with UncompressedLengthExceeded before allocating anything when the recorded length is above the maximum
+    fn decompress(bytes: &[u8], uncompressed_length: usize, max_uncompressed_length: usize) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>>;
+}
+
+#[cfg(feature = "zstd")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Zstd;
+
+#[cfg(feature = "zstd")]
+impl CompressionAlgorithm for Zstd {
+    const KIND: CompressionAlgorithmKind = CompressionAlgorithmKind::Zstd;
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(zstd::bulk::compress(bytes, zstd::DEFAULT_COMPRESSION_LEVEL)?)
+    }
+    fn decompress(bytes: &[u8], uncompressed_length: usize, max_uncompressed_length: usize) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        verify_max_uncompressed_length(uncompressed_length, max_uncompressed_length)?;
+        let decompressed_bytes = zstd::bulk::decompress(bytes, uncompressed_length)?;
+        verify_uncompressed_length(&decompressed_bytes, uncompressed_length)?;
+        Ok(decompressed_bytes)
+    }
+}
+
+#[cfg(feature = "lz4")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Lz4;
+
+#[cfg(feature = "lz4")]
+impl CompressionAlgorithm for Lz4 {
+    const KIND: CompressionAlgorithmKind = CompressionAlgorithmKind::Lz4;
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(lz4_flex::block::compress(bytes))
+    }
+    fn decompress(bytes: &[u8], uncompressed_length: usize, max_uncompressed_length: usize) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        verify_max_uncompressed_length(uncompressed_length, max_uncompressed_length)?;
+        let decompressed_bytes = lz4_flex::block::decompress(bytes, uncompressed_length)?;
+        verify_uncompressed_length(&decompressed_bytes, uncompressed_length)?;
+        Ok(decompressed_bytes)
+    }
+}
+
+#[cfg(feature = "deflate")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Deflate;
+
+#[cfg(feature = "deflate")]
+impl CompressionAlgorithm for Deflate {
+    const KIND: CompressionAlgorithmKind = CompressionAlgorithmKind::Deflate;
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        use std::io::Write;
+        let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(bytes)?;
+        Ok(encoder.finish()?)
+    }
+    fn decompress(bytes: &[u8], uncompressed_length: usize, max_uncompressed_length: usize) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        use std::io::Read;
+        verify_max_uncompressed_length(uncompressed_length, max_uncompressed_length)?;
+        let mut decompressed_bytes = Vec::with_capacity(uncompressed_length);
+        // reading one byte past the expected length detects payloads that decompress into more than was recorded
+        flate2::read::DeflateDecoder::new(bytes)
+            .take(uncompressed_length as u64 + 1)
+            .read_to_end(&mut decompressed_bytes)?;
+        verify_uncompressed_length(&decompressed_bytes, uncompressed_length)?;
+        Ok(decompressed_bytes)
+    }
+}
+
+#[inline(always)]
+fn verify_max_uncompressed_length(uncompressed_length: usize, max_uncompressed_length: usize) -> Result<(), UncompressedLengthExceeded> {
+    if uncompressed_length > max_uncompressed_length {
+        return Err(UncompressedLengthExceeded {
+            uncompressed_length,
+            max_uncompressed_length,
+        });
+    }
+    Ok(())
+}
+
+#[allow(dead_code)]
+#[inline(always)]
+fn verify_uncompressed_length(decompressed_bytes: &[u8], uncompressed_length: usize) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    if decompressed_bytes.len() != uncompressed_length {
+        return Err(CompressionByteConError::UnexpectedUncompressedLength {
+            expected: uncompressed_length,
+            actual: decompressed_bytes.len(),
+        }.into());
+    }
+    Ok(())
+}
+
+// returned when a recorded uncompressed length is above the maximum, which can be found by downcasting the boxed error
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq)]
+#[error("Uncompressed length {uncompressed_length} is above the maximum of {max_uncompressed_length}.")]
+pub struct UncompressedLengthExceeded {
+    pub uncompressed_length: usize,
+    pub max_uncompressed_length: usize,
+}
+
+// the algorithm and uncompressed length are recorded ahead of the compressed bytes of the value
+// values whose recorded uncompressed length is above MAX_UNCOMPRESSED_LENGTH are rejected before being decompressed
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Compressed<T, TAlgorithm: CompressionAlgorithm, const MAX_UNCOMPRESSED_LENGTH: usize = DEFAULT_MAX_UNCOMPRESSED_LENGTH> {
+    pub value: T,
+    phantom_algorithm: PhantomData<TAlgorithm>,
+}
+
+impl<T, TAlgorithm: CompressionAlgorithm, const MAX_UNCOMPRESSED_LENGTH: usize> Compressed<T, TAlgorithm, MAX_UNCOMPRESSED_LENGTH> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            phantom_algorithm: PhantomData,
+        }
+    }
+}
+
+impl<T, TAlgorithm: CompressionAlgorithm, const MAX_UNCOMPRESSED_LENGTH: usize> From<T> for Compressed<T, TAlgorithm, MAX_UNCOMPRESSED_LENGTH> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: ByteConverter, TAlgorithm: CompressionAlgorithm, const MAX_UNCOMPRESSED_LENGTH: usize> ByteConverter for Compressed<T, TAlgorithm, MAX_UNCOMPRESSED_LENGTH> {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let uncompressed_bytes = self.value.to_vec_bytes()?;
+        TAlgorithm::KIND.append_to_bytes(bytes)?;
+        uncompressed_bytes.len().append_to_bytes(bytes)?;
+        TAlgorithm::compress(&uncompressed_bytes)?.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let algorithm = CompressionAlgorithmKind::extract_from_bytes(bytes, index)?;
+        if algorithm != TAlgorithm::KIND {
+            return Err(CompressionByteConError::UnexpectedAlgorithm {
+                expected: TAlgorithm::KIND,
+                actual: algorithm,
+            }.into());
+        }
+        let uncompressed_length = usize::extract_from_bytes(bytes, index)?;
+        verify_max_uncompressed_length(uncompressed_length, MAX_UNCOMPRESSED_LENGTH)?;
+        let compressed_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+        let uncompressed_bytes = TAlgorithm::decompress(&compressed_bytes, uncompressed_length, MAX_UNCOMPRESSED_LENGTH)?;
+        Ok(Self::new(T::deserialize_from_bytes(&uncompressed_bytes)?))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompressionSettings {
+    // the algorithms in order of preference, where the first that the peer supports is used
+    pub algorithms: &'static [CompressionAlgorithmKind],
+    // messages with fewer bytes than this are sent uncompressed
+    pub threshold: usize,
+    // received messages that claim to decompress into more bytes than this are rejected before being decompressed
+    pub max_uncompressed_length: usize,
+}
+
+impl Default for CompressionSettings {
+    fn default() -> Self {
+        Self {
+            algorithms: &[
+                CompressionAlgorithmKind::Zstd,
+                CompressionAlgorithmKind::Lz4,
+                CompressionAlgorithmKind::Deflate,
+            ],
+            threshold: 512,
+            max_uncompressed_length: DEFAULT_MAX_UNCOMPRESSED_LENGTH,
+        }
+    }
+}
+
+// the message frame sent through the inner stream, recording whether and how the message bytes were compressed
+struct CompressedFrame {
+    algorithm: Option<CompressionAlgorithmKind>,
+    uncompressed_length: usize,
+    bytes: Vec<u8>,
+}
+
+impl CompressedFrame {
+    fn new(message_bytes: Vec<u8>, algorithm: Option<CompressionAlgorithmKind>, threshold: usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let uncompressed_length = message_bytes.len();
+        if let Some(algorithm) = algorithm {
+            if uncompressed_length >= threshold {
+                let compressed_bytes = algorithm.compress(&message_bytes)?;
+                // incompressible messages are kept as they are instead of growing
+                if compressed_bytes.len() < uncompressed_length {
+                    return Ok(Self {
+                        algorithm: Some(algorithm),
+                        uncompressed_length,
+                        bytes: compressed_bytes,
+                    });
+                }
+            }
+        }
+        Ok(Self {
+            algorithm: None,
+            uncompressed_length,
+            bytes: message_bytes,
+        })
+    }
+    fn into_message<T: ByteConverter>(self, max_uncompressed_length: usize) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        match self.algorithm {
+            Some(algorithm) => T::deserialize_from_bytes(&algorithm.decompress(&self.bytes, self.uncompressed_length, max_uncompressed_length)?),
+            None => T::deserialize_from_bytes(&self.bytes),
+        }
+    }
+}
+
+impl ByteConverter for CompressedFrame {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        match self.algorithm {
+            Some(algorithm) => {
+                algorithm.append_to_bytes(bytes)?;
+                self.uncompressed_length.append_to_bytes(bytes)?;
+            },
+            None => 0u8.append_to_bytes(bytes)?,
+        }
+        // the bytes take up the rest of the frame
+        bytes.extend_from_slice(&self.bytes);
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let algorithm = if bytes.as_ref().get(*index) == Some(&0u8) {
+            *index += 1;
+            None
+        }
+        else {
+            Some(CompressionAlgorithmKind::extract_from_bytes(bytes, index)?)
+        };
+        let uncompressed_length = match algorithm {
+            Some(_) => usize::extract_from_bytes(bytes, index)?,
+            None => bytes.as_ref().len().saturating_sub(*index),
+        };
+        let frame_bytes = crate::get_multiple_bytes(bytes, index, bytes.as_ref().len().saturating_sub(*index))?.to_vec();
+        Ok(Self {
+            algorithm,
+            uncompressed_length,
+            bytes: frame_bytes,
+        })
+    }
+}
+
+// each side sends the algorithms that it supports so that only algorithms that the peer can decompress are used
+struct CompressionHandshake {
+    algorithms: Vec<CompressionAlgorithmKind>,
+}
+
+impl ByteConverter for CompressionHandshake {
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.algorithms.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        // algorithms that this side does not know of are skipped instead of failing the handshake
+        let algorithm_bytes = Vec::<u8>::extract_from_bytes(bytes, index)?;
+        let algorithms = algorithm_bytes.into_iter()
+            .filter_map(|algorithm_byte| CompressionAlgorithmKind::deserialize_from_bytes(&[algorithm_byte]).ok())
+            .collect();
+        Ok(Self {
+            algorithms,
+        })
+    }
+}
+
+fn choose_algorithm(settings: &CompressionSettings, peer_algorithms: &[CompressionAlgorithmKind]) -> Option<CompressionAlgorithmKind> {
+    settings.algorithms.iter()
+        .find(|algorithm| algorithm.is_supported() && peer_algorithms.contains(algorithm))
+        .copied()
+}
+
+// wraps a stream so that messages at or above the threshold are compressed with an algorithm that both sides support
+// messages are still framed by the inner stream, so both sides must wrap their stream in the same way
+// the inner stream can be any byte stream, including another wrapper such as a ChecksummedByteStream
+pub struct CompressedByteStream<TStream> {
+    stream: TStream,
+    settings: CompressionSettings,
+    algorithm: Option<CompressionAlgorithmKind>,
+}
+
+impl<TStream> CompressedByteStream<TStream> {
+    // skips the handshake, so the peer must support the first supported algorithm of the settings
+    pub fn new(stream: TStream, settings: CompressionSettings) -> Self {
+        let algorithm = choose_algorithm(&settings, &CompressionAlgorithmKind::supported());
+        Self {
+            stream,
+            settings,
+            algorithm,
+        }
+    }
+    pub fn algorithm(&self) -> Option<CompressionAlgorithmKind> {
+        self.algorithm
+    }
+    pub fn get_ref(&self) -> &TStream {
+        &self.stream
+    }
+    pub fn get_mut(&mut self) -> &mut TStream {
+        &mut self.stream
+    }
+    pub fn into_inner(self) -> TStream {
+        self.stream
+    }
+}
+
+impl<TStream: ByteStreamReader + ByteStreamWriter> CompressedByteStream<TStream> {
+    pub fn negotiate(mut stream: TStream, settings: CompressionSettings) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        stream.write_from_byte_converter(&CompressionHandshake {
+            algorithms: CompressionAlgorithmKind::supported(),
+        })?;
+        let peer_handshake = stream.read_to_byte_converter::<CompressionHandshake>()?;
+        let algorithm = choose_algorithm(&settings, &peer_handshake.algorithms);
+        Ok(Self {
+            stream,
+            settings,
+            algorithm,
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<TStream: ByteStreamReaderAsync + ByteStreamWriterAsync> CompressedByteStream<TStream> {
+    pub async fn negotiate_async(mut stream: TStream, settings: CompressionSettings) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        ByteStreamWriterAsync::write_from_byte_converter(&mut stream, &CompressionHandshake {
+            algorithms: CompressionAlgorithmKind::supported(),
+        }).await?;
+        let peer_handshake = ByteStreamReaderAsync::read_to_byte_converter::<CompressionHandshake>(&mut stream).await?;
+        let algorithm = choose_algorithm(&settings, &peer_handshake.algorithms);
+        Ok(Self {
+            stream,
+            settings,
+            algorithm,
+        })
+    }
+}
+
+impl<TStream: ByteStreamReader> ByteStreamReader for CompressedByteStream<TStream> {
+    fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        self.stream.read_to_byte_converter::<CompressedFrame>()?
+            .into_message(self.settings.max_uncompressed_length)
+    }
+}
+
+impl<TStream: ByteStreamWriter> ByteStreamWriter for CompressedByteStream<TStream> {
+    fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let frame = CompressedFrame::new(byte_converter.to_vec_bytes()?, self.algorithm, self.settings.threshold)?;
+        self.stream.write_from_byte_converter(&frame)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<TStream: ByteStreamReaderAsync> ByteStreamReaderAsync for CompressedByteStream<TStream> {
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        self.stream.read_to_byte_converter::<CompressedFrame>()
+            .await?
+            .into_message(self.settings.max_uncompressed_length)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<TStream: ByteStreamWriterAsync> ByteStreamWriterAsync for CompressedByteStream<TStream> {
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let frame = CompressedFrame::new(byte_converter.to_vec_bytes()?, self.algorithm, self.settings.threshold)?;
+        self.stream.write_from_byte_converter(&frame)
+            .await
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+enum CompressionByteConError {
+    #[error("Compression algorithm {algorithm:?} is not supported without its feature enabled.")]
+    UnsupportedAlgorithm {
+        algorithm: CompressionAlgorithmKind,
+    },
+    #[error("Expected compression algorithm {expected:?} but found {actual:?}.")]
+    UnexpectedAlgorithm {
+        expected: CompressionAlgorithmKind,
+        actual: CompressionAlgorithmKind,
+    },
+    #[error("Expected {expected} uncompressed bytes but decompressed {actual}.")]
+    UnexpectedUncompressedLength {
+        expected: usize,
+        actual: usize,
+    },
+}
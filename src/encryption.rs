@@ -0,0 +1,330 @@
+use std::{collections::HashMap, error::Error};
+use zeroize::Zeroize;
+use crate::{ByteConverter, ContextualByteConverter};
+
+#[cfg(feature = "chacha20poly1305")]
+use chacha20poly1305::aead::{rand_core::RngCore, AeadInPlace, KeyInit, OsRng};
+#[cfg(all(feature = "aes_gcm", not(feature = "chacha20poly1305")))]
+use aes_gcm::aead::{rand_core::RngCore, AeadInPlace, KeyInit, OsRng};
+
+// both ChaCha20-Poly1305 and AES-256-GCM use the same key, nonce and tag sizes
+pub const ENCRYPTION_KEY_LENGTH: usize = 32;
+pub const ENCRYPTION_NONCE_LENGTH: usize = 12;
+pub const ENCRYPTION_TAG_LENGTH: usize = 16;
+
+// bytes that are overwritten with zeros when dropped so that key material does not linger in freed memory
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretBytes(Vec<u8>);
+
+impl SecretBytes {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl std::fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretBytes([REDACTED; {}])", self.0.len())
+    }
+}
+
+impl From<Vec<u8>> for SecretBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl ByteConverter for SecretBytes {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.0.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self(Vec::<u8>::extract_from_bytes(bytes, index)?))
+    }
+}
+
+#[cfg(feature = "rustls")]
+impl From<rustls::PrivateKey> for SecretBytes {
+    fn from(private_key: rustls::PrivateKey) -> Self {
+        Self(private_key.0)
+    }
+}
+
+#[cfg(feature = "rustls")]
+impl From<SecretBytes> for rustls::PrivateKey {
+    fn from(mut secret_bytes: SecretBytes) -> Self {
+        Self(std::mem::take(&mut secret_bytes.0))
+    }
+}
+
+// the key bytes are only ever encoded encrypted, passing through SecretBytes so that no plaintext copy is left behind
+#[cfg(feature = "rustls")]
+impl ContextualByteConverter<EncryptionKeyring> for rustls::PrivateKey {
+    fn append_to_bytes_with(&self, context: &EncryptionKeyring, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        Encrypted::new(SecretBytes::new(self.0.clone())).append_to_bytes_with(context, bytes)
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut EncryptionKeyring, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Encrypted::<SecretBytes>::extract_from_bytes_with(context, bytes, index)?.value.into())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionAlgorithmKind {
+    ChaCha20Poly1305,
+    Aes256Gcm,
+}
+
+impl EncryptionAlgorithmKind {
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::ChaCha20Poly1305 => cfg!(feature = "chacha20poly1305"),
+            Self::Aes256Gcm => cfg!(feature = "aes_gcm"),
+        }
+    }
+    fn encrypt_in_place(&self, key: &SecretBytes, nonce: &[u8; ENCRYPTION_NONCE_LENGTH], associated_data: &[u8], buffer: &mut [u8]) -> Result<[u8; ENCRYPTION_TAG_LENGTH], Box<dyn Error + Send + Sync + 'static>> {
+        let tag = match self {
+            #[cfg(feature = "chacha20poly1305")]
+            Self::ChaCha20Poly1305 => chacha20poly1305::ChaCha20Poly1305::new_from_slice(key.expose_secret())
+                .map_err(|_| EncryptionByteConError::UnexpectedKeyLength { length: key.len() })?
+                .encrypt_in_place_detached(nonce.into(), associated_data, buffer)
+                .map_err(|_| EncryptionByteConError::EncryptionFailed)?,
+            #[cfg(feature = "aes_gcm")]
+            Self::Aes256Gcm => aes_gcm::Aes256Gcm::new_from_slice(key.expose_secret())
+                .map_err(|_| EncryptionByteConError::UnexpectedKeyLength { length: key.len() })?
+                .encrypt_in_place_detached(nonce.into(), associated_data, buffer)
+                .map_err(|_| EncryptionByteConError::EncryptionFailed)?,
+            #[allow(unreachable_patterns)]
+            _ => return Err(EncryptionByteConError::UnsupportedAlgorithm { algorithm: *self }.into()),
+        };
+        Ok(tag.into())
+    }
+    fn decrypt_in_place(&self, key: &SecretBytes, nonce: &[u8; ENCRYPTION_NONCE_LENGTH], associated_data: &[u8], buffer: &mut [u8], tag: &[u8; ENCRYPTION_TAG_LENGTH]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            #[cfg(feature = "chacha20poly1305")]
+            Self::ChaCha20Poly1305 => chacha20poly1305::ChaCha20Poly1305::new_from_slice(key.expose_secret())
+                .map_err(|_| EncryptionByteConError::UnexpectedKeyLength { length: key.len() })?
+                .decrypt_in_place_detached(nonce.into(), associated_data, buffer, tag.into())
+                .map_err(|_| EncryptionByteConError::DecryptionFailed)?,
+            #[cfg(feature = "aes_gcm")]
+            Self::Aes256Gcm => aes_gcm::Aes256Gcm::new_from_slice(key.expose_secret())
+                .map_err(|_| EncryptionByteConError::UnexpectedKeyLength { length: key.len() })?
+                .decrypt_in_place_detached(nonce.into(), associated_data, buffer, tag.into())
+                .map_err(|_| EncryptionByteConError::DecryptionFailed)?,
+            #[allow(unreachable_patterns)]
+            _ => return Err(EncryptionByteConError::UnsupportedAlgorithm { algorithm: *self }.into()),
+        }
+        Ok(())
+    }
+}
+
+impl ByteConverter for EncryptionAlgorithmKind {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            Self::ChaCha20Poly1305 => 0u8.append_to_bytes(bytes)?,
+            Self::Aes256Gcm => 1u8.append_to_bytes(bytes)?,
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::ChaCha20Poly1305),
+            1u8 => Ok(Self::Aes256Gcm),
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncryptionKey {
+    id: u32,
+    algorithm: EncryptionAlgorithmKind,
+    secret: SecretBytes,
+}
+
+impl EncryptionKey {
+    pub fn new(id: u32, algorithm: EncryptionAlgorithmKind, secret: SecretBytes) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        if !algorithm.is_supported() {
+            return Err(EncryptionByteConError::UnsupportedAlgorithm { algorithm }.into());
+        }
+        if secret.len() != ENCRYPTION_KEY_LENGTH {
+            return Err(EncryptionByteConError::UnexpectedKeyLength { length: secret.len() }.into());
+        }
+        Ok(Self {
+            id,
+            algorithm,
+            secret,
+        })
+    }
+    pub fn generate(id: u32, algorithm: EncryptionAlgorithmKind) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let mut secret = SecretBytes::new(vec![0u8; ENCRYPTION_KEY_LENGTH]);
+        fill_random_bytes(&mut secret.0)?;
+        Self::new(id, algorithm, secret)
+    }
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+    pub fn algorithm(&self) -> EncryptionAlgorithmKind {
+        self.algorithm
+    }
+    pub fn secret(&self) -> &SecretBytes {
+        &self.secret
+    }
+}
+
+// the keys that payloads can be decrypted with, where new payloads are encrypted with the active key
+// older keys can be kept after rotating to a new active key so that previously stored payloads can still be read
+#[derive(Clone, Debug, Default)]
+pub struct EncryptionKeyring {
+    keys: HashMap<u32, EncryptionKey>,
+    active_key_id: Option<u32>,
+}
+
+impl EncryptionKeyring {
+    pub fn new(active_key: EncryptionKey) -> Self {
+        let mut keyring = Self::default();
+        keyring.insert_active(active_key);
+        keyring
+    }
+    pub fn insert(&mut self, key: EncryptionKey) -> Option<EncryptionKey> {
+        self.keys.insert(key.id, key)
+    }
+    pub fn insert_active(&mut self, key: EncryptionKey) -> Option<EncryptionKey> {
+        self.active_key_id = Some(key.id);
+        self.insert(key)
+    }
+    pub fn remove(&mut self, id: u32) -> Option<EncryptionKey> {
+        if self.active_key_id == Some(id) {
+            self.active_key_id = None;
+        }
+        self.keys.remove(&id)
+    }
+    pub fn get(&self, id: u32) -> Option<&EncryptionKey> {
+        self.keys.get(&id)
+    }
+    pub fn active_key(&self) -> Option<&EncryptionKey> {
+        self.active_key_id.and_then(|id| self.keys.get(&id))
+    }
+}
+
+fn fill_random_bytes(bytes: &mut [u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    OsRng.try_fill_bytes(bytes)
+        .map_err(|_| EncryptionByteConError::RandomGenerationFailed)?;
+    Ok(())
+}
+
+// the algorithm and key id are authenticated along with the ciphertext so that they cannot be swapped out
+fn get_associated_data(algorithm: EncryptionAlgorithmKind, key_id: u32) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+    let mut associated_data = Vec::with_capacity(5);
+    algorithm.append_to_bytes(&mut associated_data)?;
+    key_id.append_to_bytes(&mut associated_data)?;
+    Ok(associated_data)
+}
+
+// encodes the algorithm, key id, nonce, ciphertext and tag so that the keyring can find the key to decrypt with
+// the plaintext bytes only ever exist as SecretBytes so that they are zeroed once encrypted or deserialized
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Encrypted<T> {
+    pub value: T,
+}
+
+impl<T> Encrypted<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+        }
+    }
+}
+
+impl<T> From<T> for Encrypted<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: ByteConverter> ContextualByteConverter<EncryptionKeyring> for Encrypted<T> {
+    fn append_to_bytes_with(&self, context: &EncryptionKeyring, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let key = context.active_key()
+            .ok_or(EncryptionByteConError::MissingActiveKey)?;
+        let mut nonce = [0u8; ENCRYPTION_NONCE_LENGTH];
+        fill_random_bytes(&mut nonce)?;
+        let associated_data = get_associated_data(key.algorithm, key.id)?;
+        let mut buffer = SecretBytes::new(self.value.to_vec_bytes()?);
+        let tag = key.algorithm.encrypt_in_place(&key.secret, &nonce, &associated_data, &mut buffer.0)?;
+
+        key.algorithm.append_to_bytes(bytes)?;
+        key.id.append_to_bytes(bytes)?;
+        nonce.append_to_bytes(bytes)?;
+        buffer.0.append_to_bytes(bytes)?;
+        tag.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut EncryptionKeyring, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let algorithm = EncryptionAlgorithmKind::extract_from_bytes(bytes, index)?;
+        let key_id = u32::extract_from_bytes(bytes, index)?;
+        let nonce = <[u8; ENCRYPTION_NONCE_LENGTH]>::extract_from_bytes(bytes, index)?;
+        let mut buffer = SecretBytes::new(Vec::<u8>::extract_from_bytes(bytes, index)?);
+        let tag = <[u8; ENCRYPTION_TAG_LENGTH]>::extract_from_bytes(bytes, index)?;
+
+        let key = context.get(key_id)
+            .ok_or(EncryptionByteConError::UnknownKeyId { key_id })?;
+        if key.algorithm != algorithm {
+            return Err(EncryptionByteConError::UnexpectedAlgorithm {
+                expected: key.algorithm,
+                actual: algorithm,
+            }.into());
+        }
+        let associated_data = get_associated_data(algorithm, key_id)?;
+        algorithm.decrypt_in_place(&key.secret, &nonce, &associated_data, &mut buffer.0, &tag)?;
+        Ok(Self::new(T::deserialize_from_bytes(&buffer.0)?))
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+enum EncryptionByteConError {
+    #[error("Encryption algorithm {algorithm:?} is not supported without its feature enabled.")]
+    UnsupportedAlgorithm {
+        algorithm: EncryptionAlgorithmKind,
+    },
+    #[error("Expected encryption algorithm {expected:?} for the key but found {actual:?}.")]
+    UnexpectedAlgorithm {
+        expected: EncryptionAlgorithmKind,
+        actual: EncryptionAlgorithmKind,
+    },
+    #[error("Unexpected encryption key length {length} that expects {ENCRYPTION_KEY_LENGTH}.")]
+    UnexpectedKeyLength {
+        length: usize,
+    },
+    #[error("Encryption keyring does not contain key {key_id}.")]
+    UnknownKeyId {
+        key_id: u32,
+    },
+    #[error("Encryption keyring does not have an active key to encrypt with.")]
+    MissingActiveKey,
+    #[error("Failed to generate random bytes from the operating system.")]
+    RandomGenerationFailed,
+    #[error("Failed to encrypt the payload.")]
+    EncryptionFailed,
+    #[error("Failed to decrypt the payload since it was tampered with or encrypted with a different key.")]
+    DecryptionFailed,
+}
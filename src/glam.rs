@@ -1,5 +1,7 @@
-use crate::ByteConverter;
-use glam::{Affine2, BVec2, BVec3, BVec3A, BVec4, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4};
+use std::{f32::consts::FRAC_1_SQRT_2, marker::PhantomData};
+use crate::{ByteConverter, DeltaByteConverter};
+use glam::{Affine2, Affine3A, BVec2, BVec3, BVec3A, BVec4, BVec4A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, EulerRot, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4};
+use half::f16;
 
 impl ByteConverter for Quat {
     #[inline(always)]
@@ -105,6 +107,22 @@ impl ByteConverter for Affine2 {
     }
 }
 
+impl ByteConverter for Affine3A {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.matrix3.append_to_bytes(bytes)?;
+        self.translation.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            matrix3: Mat3A::extract_from_bytes(bytes, index)?,
+            translation: Vec3A::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
 impl ByteConverter for BVec2 {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
@@ -181,6 +199,31 @@ impl ByteConverter for BVec4 {
     }
 }
 
+impl ByteConverter for BVec4A {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let bitmask = self.bitmask();
+        let x = (bitmask & (1 << 0)) != 0;
+        let y = (bitmask & (1 << 1)) != 0;
+        let z = (bitmask & (1 << 2)) != 0;
+        let w = (bitmask & (1 << 3)) != 0;
+        x.append_to_bytes(bytes)?;
+        y.append_to_bytes(bytes)?;
+        z.append_to_bytes(bytes)?;
+        w.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self::new(
+            bool::extract_from_bytes(bytes, index)?,
+            bool::extract_from_bytes(bytes, index)?,
+            bool::extract_from_bytes(bytes, index)?,
+            bool::extract_from_bytes(bytes, index)?,
+        ))
+    }
+}
+
 impl ByteConverter for DVec2 {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
@@ -252,6 +295,7 @@ impl ByteConverter for DMat3 {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
         self.x_axis.append_to_bytes(bytes)?;
         self.y_axis.append_to_bytes(bytes)?;
+        self.z_axis.append_to_bytes(bytes)?;
         Ok(())
     }
     #[inline(always)]
@@ -340,6 +384,70 @@ impl ByteConverter for DQuat {
     }
 }
 
+impl ByteConverter for EulerRot {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            Self::ZYX => 0u8.append_to_bytes(bytes)?,
+            Self::ZXY => 1u8.append_to_bytes(bytes)?,
+            Self::YXZ => 2u8.append_to_bytes(bytes)?,
+            Self::YZX => 3u8.append_to_bytes(bytes)?,
+            Self::XYZ => 4u8.append_to_bytes(bytes)?,
+            Self::XZY => 5u8.append_to_bytes(bytes)?,
+            Self::ZYZ => 6u8.append_to_bytes(bytes)?,
+            Self::ZXZ => 7u8.append_to_bytes(bytes)?,
+            Self::YXY => 8u8.append_to_bytes(bytes)?,
+            Self::YZY => 9u8.append_to_bytes(bytes)?,
+            Self::XYX => 10u8.append_to_bytes(bytes)?,
+            Self::XZX => 11u8.append_to_bytes(bytes)?,
+            Self::ZYXEx => 12u8.append_to_bytes(bytes)?,
+            Self::ZXYEx => 13u8.append_to_bytes(bytes)?,
+            Self::YXZEx => 14u8.append_to_bytes(bytes)?,
+            Self::YZXEx => 15u8.append_to_bytes(bytes)?,
+            Self::XYZEx => 16u8.append_to_bytes(bytes)?,
+            Self::XZYEx => 17u8.append_to_bytes(bytes)?,
+            Self::ZYZEx => 18u8.append_to_bytes(bytes)?,
+            Self::ZXZEx => 19u8.append_to_bytes(bytes)?,
+            Self::YXYEx => 20u8.append_to_bytes(bytes)?,
+            Self::YZYEx => 21u8.append_to_bytes(bytes)?,
+            Self::XYXEx => 22u8.append_to_bytes(bytes)?,
+            Self::XZXEx => 23u8.append_to_bytes(bytes)?,
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::ZYX),
+            1u8 => Ok(Self::ZXY),
+            2u8 => Ok(Self::YXZ),
+            3u8 => Ok(Self::YZX),
+            4u8 => Ok(Self::XYZ),
+            5u8 => Ok(Self::XZY),
+            6u8 => Ok(Self::ZYZ),
+            7u8 => Ok(Self::ZXZ),
+            8u8 => Ok(Self::YXY),
+            9u8 => Ok(Self::YZY),
+            10u8 => Ok(Self::XYX),
+            11u8 => Ok(Self::XZX),
+            12u8 => Ok(Self::ZYXEx),
+            13u8 => Ok(Self::ZXYEx),
+            14u8 => Ok(Self::YXZEx),
+            15u8 => Ok(Self::YZXEx),
+            16u8 => Ok(Self::XYZEx),
+            17u8 => Ok(Self::XZYEx),
+            18u8 => Ok(Self::ZYZEx),
+            19u8 => Ok(Self::ZXZEx),
+            20u8 => Ok(Self::YXYEx),
+            21u8 => Ok(Self::YZYEx),
+            22u8 => Ok(Self::XYXEx),
+            23u8 => Ok(Self::XZXEx),
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
+    }
+}
+
 impl ByteConverter for I16Vec2 {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
@@ -846,4 +954,309 @@ impl ByteConverter for UVec4 {
             w: u32::extract_from_bytes(bytes, index)?,
         })
     }
-}
\ No newline at end of file
+}
+// writes the lowest bit_count bits of the packed value using as few whole bytes as possible
+#[inline(always)]
+fn append_packed_bits_to_bytes(packed: u64, bit_count: u32, bytes: &mut Vec<u8>) {
+    for byte_index in 0..bit_count.div_ceil(8) {
+        bytes.push((packed >> (byte_index * 8)) as u8);
+    }
+}
+
+#[inline(always)]
+fn extract_packed_bits_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize, bit_count: u32) -> Result<u64, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let packed_bytes = crate::get_multiple_bytes(bytes, index, bit_count.div_ceil(8) as usize)?;
+    let mut packed = 0u64;
+    for (byte_index, byte) in packed_bytes.iter().enumerate() {
+        packed |= (*byte as u64) << (byte_index * 8);
+    }
+    Ok(packed)
+}
+
+// values outside of the range are clamped to it
+#[inline(always)]
+fn quantize_f32(value: f32, min: f32, max: f32, bits: u32) -> u64 {
+    let max_step = ((1u64 << bits) - 1) as f32;
+    let normalized = (value.clamp(min, max) - min) / (max - min);
+    (normalized * max_step).round() as u64
+}
+
+#[inline(always)]
+fn dequantize_f32(step: u64, min: f32, max: f32, bits: u32) -> f32 {
+    let max_step = ((1u64 << bits) - 1) as f32;
+    min + (step as f32 / max_step) * (max - min)
+}
+
+// the bounds that every component of a Quantized value is clamped to
+pub trait QuantizationRange {
+    const MIN: f32;
+    const MAX: f32;
+}
+
+// -1.0 to 1.0, suitable for normals and other unit vectors
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SignedUnitRange;
+
+impl QuantizationRange for SignedUnitRange {
+    const MIN: f32 = -1.0;
+    const MAX: f32 = 1.0;
+}
+
+// 0.0 to 1.0, suitable for colors and interpolation factors
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct UnitRange;
+
+impl QuantizationRange for UnitRange {
+    const MIN: f32 = 0.0;
+    const MAX: f32 = 1.0;
+}
+
+// stores each component in BITS bits (1 through 16) within TRange, packed together into the fewest bytes
+// the round-trip error of each component is at most (MAX - MIN) / (2^BITS - 1) / 2
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Quantized<T, TRange: QuantizationRange, const BITS: u32> {
+    pub value: T,
+    phantom_range: PhantomData<TRange>,
+}
+
+impl<T, TRange: QuantizationRange, const BITS: u32> Quantized<T, TRange, BITS> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            phantom_range: PhantomData,
+        }
+    }
+}
+
+impl<T, TRange: QuantizationRange, const BITS: u32> From<T> for Quantized<T, TRange, BITS> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+macro_rules! impl_quantized_byte_converter {
+    ($vector:ident, $length:literal) => {
+        impl<TRange: QuantizationRange, const BITS: u32> ByteConverter for Quantized<$vector, TRange, BITS> {
+            #[inline(always)]
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                const { assert!(BITS >= 1 && BITS <= 16, "Quantized values must use between 1 and 16 bits per component.") };
+                let mut packed = 0u64;
+                for (component_index, component) in self.value.to_array().into_iter().enumerate() {
+                    packed |= quantize_f32(component, TRange::MIN, TRange::MAX, BITS) << (component_index as u32 * BITS);
+                }
+                append_packed_bits_to_bytes(packed, $length * BITS, bytes);
+                Ok(())
+            }
+            #[inline(always)]
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                const { assert!(BITS >= 1 && BITS <= 16, "Quantized values must use between 1 and 16 bits per component.") };
+                let packed = extract_packed_bits_from_bytes(bytes, index, $length * BITS)?;
+                let step_mask = (1u64 << BITS) - 1;
+                let mut components = [0f32; $length];
+                for (component_index, component) in components.iter_mut().enumerate() {
+                    let step = (packed >> (component_index as u32 * BITS)) & step_mask;
+                    *component = dequantize_f32(step, TRange::MIN, TRange::MAX, BITS);
+                }
+                Ok(Self::new($vector::from_array(components)))
+            }
+        }
+    };
+}
+
+impl_quantized_byte_converter!(Vec2, 2);
+impl_quantized_byte_converter!(Vec3, 3);
+impl_quantized_byte_converter!(Vec4, 4);
+
+// stores each component as an IEEE 754 half-precision float, with a relative round-trip error of at most 2^-11
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HalfPrecision<T> {
+    pub value: T,
+}
+
+impl<T> HalfPrecision<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+        }
+    }
+}
+
+impl<T> From<T> for HalfPrecision<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+macro_rules! impl_half_precision_byte_converter {
+    ($vector:ident, $length:literal) => {
+        impl ByteConverter for HalfPrecision<$vector> {
+            #[inline(always)]
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                for component in self.value.to_array() {
+                    f16::from_f32(component).to_bits().append_to_bytes(bytes)?;
+                }
+                Ok(())
+            }
+            #[inline(always)]
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let mut components = [0f32; $length];
+                for component in components.iter_mut() {
+                    *component = f16::from_bits(u16::extract_from_bytes(bytes, index)?).to_f32();
+                }
+                Ok(Self::new($vector::from_array(components)))
+            }
+        }
+    };
+}
+
+impl_half_precision_byte_converter!(Vec2, 2);
+impl_half_precision_byte_converter!(Vec3, 3);
+impl_half_precision_byte_converter!(Vec4, 4);
+impl_half_precision_byte_converter!(Quat, 4);
+
+// stores a unit quaternion as the index of its largest component and the other three components in BITS bits each (2 through 20)
+// the largest component is rebuilt from the unit length, so the quaternion is normalized again when extracted
+// the round-trip error of each of the three components is at most sqrt(2) / (2^BITS - 1) / 2
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SmallestThreeQuat<const BITS: u32 = 10> {
+    pub value: Quat,
+}
+
+impl<const BITS: u32> SmallestThreeQuat<BITS> {
+    pub fn new(value: Quat) -> Self {
+        Self {
+            value,
+        }
+    }
+}
+
+impl<const BITS: u32> Default for SmallestThreeQuat<BITS> {
+    fn default() -> Self {
+        Self::new(Quat::IDENTITY)
+    }
+}
+
+impl<const BITS: u32> From<Quat> for SmallestThreeQuat<BITS> {
+    fn from(value: Quat) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const BITS: u32> ByteConverter for SmallestThreeQuat<BITS> {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        const { assert!(BITS >= 2 && BITS <= 20, "SmallestThreeQuat must use between 2 and 20 bits per component.") };
+        let components = self.value.to_array();
+        let mut largest_index = 0;
+        for component_index in 1..4 {
+            if components[component_index].abs() > components[largest_index].abs() {
+                largest_index = component_index;
+            }
+        }
+        // q and -q are the same rotation, so the sign is chosen such that the largest component is positive
+        let sign = if components[largest_index] < 0.0 {
+            -1.0
+        }
+        else {
+            1.0
+        };
+        let mut packed = largest_index as u64;
+        let mut shift = 2;
+        for (component_index, component) in components.into_iter().enumerate() {
+            if component_index != largest_index {
+                packed |= quantize_f32(component * sign, -FRAC_1_SQRT_2, FRAC_1_SQRT_2, BITS) << shift;
+                shift += BITS;
+            }
+        }
+        append_packed_bits_to_bytes(packed, 2 + 3 * BITS, bytes);
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+        const { assert!(BITS >= 2 && BITS <= 20, "SmallestThreeQuat must use between 2 and 20 bits per component.") };
+        let packed = extract_packed_bits_from_bytes(bytes, index, 2 + 3 * BITS)?;
+        let largest_index = (packed & 0b11) as usize;
+        let step_mask = (1u64 << BITS) - 1;
+        let mut components = [0f32; 4];
+        let mut shift = 2;
+        let mut sum_of_squares = 0.0;
+        for (component_index, component) in components.iter_mut().enumerate() {
+            if component_index != largest_index {
+                *component = dequantize_f32((packed >> shift) & step_mask, -FRAC_1_SQRT_2, FRAC_1_SQRT_2, BITS);
+                sum_of_squares += *component * *component;
+                shift += BITS;
+            }
+        }
+        components[largest_index] = (1.0 - sum_of_squares).max(0.0).sqrt();
+        Ok(Self::new(Quat::from_array(components).normalize()))
+    }
+}
+
+// a bitmask of the changed components is followed by each changed component, compared by its bits
+macro_rules! impl_component_delta_byte_converter {
+    ($glam_type:ident, $component:ty, $bitmask:ty, $to_components:ident, $from_slice:ident) => {
+        impl DeltaByteConverter for $glam_type {
+            #[inline(always)]
+            fn has_changed(&self, baseline: &Self) -> bool {
+                self.$to_components().into_iter()
+                    .zip(baseline.$to_components())
+                    .any(|(component, baseline_component)| component.has_changed(&baseline_component))
+            }
+            #[inline(always)]
+            fn append_delta(&self, baseline: &Self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let components = self.$to_components();
+                let mut changed_components_bitmask: $bitmask = 0;
+                for (component_index, (component, baseline_component)) in components.iter().zip(baseline.$to_components()).enumerate() {
+                    if component.has_changed(&baseline_component) {
+                        changed_components_bitmask |= 1 << component_index;
+                    }
+                }
+                changed_components_bitmask.append_to_bytes(bytes)?;
+                for (component_index, component) in components.iter().enumerate() {
+                    if (changed_components_bitmask & (1 << component_index)) != 0 {
+                        component.append_to_bytes(bytes)?;
+                    }
+                }
+                Ok(())
+            }
+            #[inline(always)]
+            fn apply_delta<'a, TBytes: AsRef<[u8]>>(baseline: &Self, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let changed_components_bitmask = <$bitmask>::extract_from_bytes(bytes, index)?;
+                let mut components = baseline.$to_components();
+                for (component_index, component) in components.iter_mut().enumerate() {
+                    if (changed_components_bitmask & (1 << component_index)) != 0 {
+                        *component = <$component>::extract_from_bytes(bytes, index)?;
+                    }
+                }
+                Ok(Self::$from_slice(&components))
+            }
+        }
+    };
+}
+
+impl_component_delta_byte_converter!(Vec2, f32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(Vec3, f32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(Vec3A, f32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(Vec4, f32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(Quat, f32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(DVec2, f64, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(DVec3, f64, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(DVec4, f64, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(DQuat, f64, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(IVec2, i32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(IVec3, i32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(IVec4, i32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(UVec2, u32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(UVec3, u32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(UVec4, u32, u8, to_array, from_slice);
+impl_component_delta_byte_converter!(Mat2, f32, u8, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(Mat3, f32, u16, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(Mat3A, f32, u16, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(Mat4, f32, u16, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(Affine2, f32, u8, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(Affine3A, f32, u16, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(DMat2, f64, u8, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(DMat3, f64, u16, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(DMat4, f64, u16, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(DAffine2, f64, u8, to_cols_array, from_cols_slice);
+impl_component_delta_byte_converter!(DAffine3, f64, u16, to_cols_array, from_cols_slice);
@@ -7,6 +7,12 @@ use serde::{de::DeserializeOwned, Serialize};
 //      this can be used to match on within the extract so that changes in format across versions of this crate are unaffected
 // TODO replace string errors with usage of ByteConverterError variants
 
+#[cfg(any(feature = "avian2d", feature = "avian3d"))]
+mod avian;
+
+#[cfg(feature = "avian2d")]
+pub mod avian2d;
+
 #[cfg(feature = "avian3d")]
 pub mod avian3d;
 
@@ -16,6 +22,9 @@ pub mod base;
 #[cfg(feature = "bevy")]
 pub mod bevy;
 
+#[cfg(feature = "base")]
+pub mod blocking;
+
 #[cfg(all(feature = "bincode", not(feature = "burn_dtype")))]
 pub mod bincode;
 
@@ -25,18 +34,48 @@ pub mod burn;
 #[cfg(feature = "burn_dtype")]
 pub mod burn_dtype;
 
+#[cfg(feature = "checksum")]
+pub mod checksum;
+
+#[cfg(any(feature = "zstd", feature = "lz4", feature = "deflate"))]
+pub mod compression;
+
+#[cfg(any(feature = "chacha20poly1305", feature = "aes_gcm"))]
+pub mod encryption;
+
 #[cfg(feature = "glam")]
 pub mod glam;
 
 #[cfg(feature = "rand")]
 pub mod rand;
 
+#[cfg(feature = "tokio")]
+pub mod pubsub;
+
+#[cfg(feature = "tokio")]
+pub mod reliable;
+
+#[cfg(feature = "tokio")]
+pub mod rpc;
+
+#[cfg(feature = "tokio")]
+pub mod udp;
+
 #[cfg(feature = "rustls")]
 pub mod rustls;
 
+#[cfg(feature = "rustls23")]
+pub mod rustls23;
+
+#[cfg(feature = "tls")]
+pub mod tls;
+
 #[cfg(feature = "tokio")]
 pub mod tokio;
 
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
 pub trait ByteConverter {
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized;
@@ -75,20 +114,348 @@ pub trait ByteConverter {
         TByteConverter::deserialize_from_bytes(&bytes)
     }
 }
+// this is useful for types that cannot be encoded or decoded without outside state, like asset handles needing the asset storage
+pub trait ContextualByteConverter<TContext: ?Sized> {
+    fn append_to_bytes_with(&self, context: &TContext, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut TContext, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized;
+    #[inline(always)]
+    fn to_vec_bytes_with(&self, context: &TContext) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        let mut bytes = Vec::new();
+        self.append_to_bytes_with(context, &mut bytes)?;
+        Ok(bytes)
+    }
+    #[inline(always)]
+    fn clone_via_bytes_with(&self, context: &mut TContext) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let bytes = self.to_vec_bytes_with(context)?;
+        Self::deserialize_from_bytes_with(context, &bytes)
+    }
+    #[inline(always)]
+    fn deserialize_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut TContext, bytes: &'a TBytes) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let bytes_ref = bytes.as_ref();
+        let mut index = 0;
+        let instance = Self::extract_from_bytes_with(context, bytes, &mut index)?;
+        if index != bytes_ref.len() {
+            return Err("The provided bytes contained more than one instance of a type. Deserializing did not exhaust the total length of the provided bytes.".into());
+        }
+        Ok(instance)
+    }
+}
+
+// encodes a value as the difference from a baseline that the receiving side already has, like the last acknowledged value
+pub trait DeltaByteConverter: ByteConverter {
+    fn has_changed(&self, baseline: &Self) -> bool;
+    fn append_delta(&self, baseline: &Self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+    fn apply_delta<'a, TBytes: AsRef<[u8]>>(baseline: &Self, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized;
+    // used by containers that already recorded that the value changed, so that types which flag whether they changed can leave the flag out
+    #[inline(always)]
+    fn append_changed_delta(&self, baseline: &Self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.append_delta(baseline, bytes)
+    }
+    #[inline(always)]
+    fn apply_changed_delta<'a, TBytes: AsRef<[u8]>>(baseline: &Self, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Self::apply_delta(baseline, bytes, index)
+    }
+    #[inline(always)]
+    fn to_delta_bytes(&self, baseline: &Self) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        let mut bytes = Vec::new();
+        self.append_delta(baseline, &mut bytes)?;
+        Ok(bytes)
+    }
+    // this is useful if you know that there is only one delta contained within the collection of bytes
+    #[inline(always)]
+    fn deserialize_delta_from_bytes<'a, TBytes: AsRef<[u8]>>(baseline: &Self, bytes: &'a TBytes) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let bytes_ref = bytes.as_ref();
+        let mut index = 0;
+        let instance = Self::apply_delta(baseline, bytes, &mut index)?;
+        if index != bytes_ref.len() {
+            return Err("The provided bytes contained more than one delta of a type. Applying the delta did not exhaust the total length of the provided bytes.".into());
+        }
+        Ok(instance)
+    }
+}
+
+// implements DeltaByteConverter for a struct whose fields all implement DeltaByteConverter and Clone
+// a bitmask of the changed fields is written first, followed by the changed delta of each changed field
+#[macro_export]
+macro_rules! impl_delta_byte_converter {
+    ($struct_type:ty { $($field:ident),+ $(,)? }) => {
+        impl $crate::DeltaByteConverter for $struct_type {
+            #[inline(always)]
+            fn has_changed(&self, baseline: &Self) -> bool {
+                $(
+                    $crate::DeltaByteConverter::has_changed(&self.$field, &baseline.$field)
+                )||+
+            }
+            #[inline(always)]
+            fn append_delta(&self, baseline: &Self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                let is_field_changed = [$(
+                    $crate::DeltaByteConverter::has_changed(&self.$field, &baseline.$field),
+                )+];
+                let mut changed_fields_bitmask = vec![0u8; is_field_changed.len().div_ceil(8)];
+                for (field_index, is_changed) in is_field_changed.iter().enumerate() {
+                    if *is_changed {
+                        changed_fields_bitmask[field_index / 8] |= 1 << (field_index % 8);
+                    }
+                }
+                bytes.extend_from_slice(&changed_fields_bitmask);
+                let mut is_field_changed_iter = is_field_changed.into_iter();
+                $(
+                    if is_field_changed_iter.next().unwrap_or(false) {
+                        $crate::DeltaByteConverter::append_changed_delta(&self.$field, &baseline.$field, bytes)?;
+                    }
+                )+
+                Ok(())
+            }
+            #[inline(always)]
+            fn apply_delta<'a, TBytes: AsRef<[u8]>>(baseline: &Self, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                let field_names = [$(stringify!($field)),+];
+                let mut changed_fields_bitmask = Vec::with_capacity(field_names.len().div_ceil(8));
+                for _ in 0..field_names.len().div_ceil(8) {
+                    changed_fields_bitmask.push(<u8 as $crate::ByteConverter>::extract_from_bytes(bytes, index)?);
+                }
+                let mut is_field_changed_iter = (0..field_names.len())
+                    .map(|field_index| (changed_fields_bitmask[field_index / 8] & (1 << (field_index % 8))) != 0);
+                Ok(Self {
+                    $(
+                        $field: {
+                            if is_field_changed_iter.next().unwrap_or(false) {
+                                $crate::DeltaByteConverter::apply_changed_delta(&baseline.$field, bytes, index)?
+                            }
+                            else {
+                                baseline.$field.clone()
+                            }
+                        },
+                    )+
+                })
+            }
+        }
+    };
+}
+
+// packs values at the bit level, least significant bit first, so that flags and small enum tags do not each cost a whole byte
+#[derive(Clone, Debug, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    bit_length: usize,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_capacity(bit_capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(bit_capacity.div_ceil(8)),
+            bit_length: 0,
+        }
+    }
+    // writes the lowest bit_count bits of the value, up to 64
+    pub fn write_bits(&mut self, value: u64, bit_count: u32) {
+        for bit_index in 0..bit_count.min(64) {
+            if self.bit_length.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> bit_index) & 1 == 1 {
+                let last_byte_index = self.bytes.len() - 1;
+                self.bytes[last_byte_index] |= 1 << (self.bit_length % 8);
+            }
+            self.bit_length += 1;
+        }
+    }
+    #[inline(always)]
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_bits(value as u64, 1);
+    }
+    #[inline(always)]
+    pub fn bit_length(&self) -> usize {
+        self.bit_length
+    }
+    // the unused bits of the last byte are zero
+    #[inline(always)]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    bit_index: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            bit_index: 0,
+        }
+    }
+    // reads bit_count bits, up to 64, into the lowest bits of the output
+    pub fn read_bits(&mut self, bit_count: u32) -> Result<u64, Box<dyn Error + Send + Sync + 'static>> {
+        if bit_count > 64 {
+            return Err("Unable to read more than 64 bits at a time.".into());
+        }
+        let next_bit_index = self.bit_index + bit_count as usize;
+        if next_bit_index > self.bytes.len() * 8 {
+            return Err(ByteConverterError::IndexOutOfRange {
+                index: next_bit_index.div_ceil(8),
+                length: self.bytes.len(),
+            }.into());
+        }
+        let mut value = 0u64;
+        for output_bit_index in 0..bit_count {
+            let byte = self.bytes[self.bit_index / 8];
+            if (byte >> (self.bit_index % 8)) & 1 == 1 {
+                value |= 1 << output_bit_index;
+            }
+            self.bit_index += 1;
+        }
+        Ok(value)
+    }
+    #[inline(always)]
+    pub fn read_bool(&mut self) -> Result<bool, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(self.read_bits(1)? == 1)
+    }
+    #[inline(always)]
+    pub fn bit_index(&self) -> usize {
+        self.bit_index
+    }
+}
+
+// a type that always encodes in exactly BIT_LENGTH bits
+pub trait BitConverter {
+    const BIT_LENGTH: u32;
+    fn append_to_bits(&self, bit_writer: &mut BitWriter) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+    fn extract_from_bits(bit_reader: &mut BitReader) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized;
+}
+
+// writes the value in the fewest whole bytes that fit BIT_LENGTH bits
+#[inline(always)]
+pub fn append_bit_packed_to_bytes<T: BitConverter>(value: &T, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let mut bit_writer = BitWriter::with_capacity(T::BIT_LENGTH as usize);
+    value.append_to_bits(&mut bit_writer)?;
+    bytes.extend_from_slice(&bit_writer.into_bytes());
+    Ok(())
+}
+
+#[inline(always)]
+pub fn extract_bit_packed_from_bytes<'a, T: BitConverter, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+    let packed_bytes = get_multiple_bytes(bytes, index, T::BIT_LENGTH.div_ceil(8) as usize)?;
+    let mut bit_reader = BitReader::new(packed_bytes);
+    T::extract_from_bits(&mut bit_reader)
+}
+
+// implements BitConverter for a struct whose fields all implement BitConverter
+// the struct is also given a ByteConverter that packs all of its fields together instead of aligning each to a byte
+#[macro_export]
+macro_rules! impl_bit_packed_byte_converter {
+    ($struct_type:ty { $($field:ident: $field_type:ty),+ $(,)? }) => {
+        impl $crate::BitConverter for $struct_type {
+            const BIT_LENGTH: u32 = 0 $(+ <$field_type as $crate::BitConverter>::BIT_LENGTH)+;
+            #[inline(always)]
+            fn append_to_bits(&self, bit_writer: &mut $crate::BitWriter) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                $(
+                    $crate::BitConverter::append_to_bits(&self.$field, bit_writer)?;
+                )+
+                Ok(())
+            }
+            #[inline(always)]
+            fn extract_from_bits(bit_reader: &mut $crate::BitReader) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    $(
+                        $field: <$field_type as $crate::BitConverter>::extract_from_bits(bit_reader)?,
+                    )+
+                })
+            }
+        }
+
+        impl $crate::ByteConverter for $struct_type {
+            #[inline(always)]
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+                $crate::append_bit_packed_to_bytes(self, bytes)
+            }
+            #[inline(always)]
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+                $crate::extract_bit_packed_from_bytes(bytes, index)
+            }
+        }
+    };
+}
+
 pub trait ByteStreamReader {
     fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>>;
+    // waits for one value and then only returns more, up to max, when their frames are already buffered
+    // streams that cannot tell whether more frames are buffered return just the one value
+    #[inline(always)]
+    fn read_many<T: ByteConverter>(&mut self, max: usize) -> Result<Vec<T>, Box<dyn Error + Send + Sync + 'static>> {
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+        Ok(vec![self.read_to_byte_converter()?])
+    }
 }
 
 pub trait ByteStreamReaderAsync {
     fn read_to_byte_converter<T: ByteConverter>(&mut self) -> impl Future<Output = Result<T, Box<dyn Error + Send + Sync + 'static>>>;
+    // waits for one value and then only returns more, up to max, when their frames are already buffered
+    // streams that cannot tell whether more frames are buffered return just the one value
+    #[inline(always)]
+    fn read_many<T: ByteConverter>(&mut self, max: usize) -> impl Future<Output = Result<Vec<T>, Box<dyn Error + Send + Sync + 'static>>> {
+        async move {
+            if max == 0 {
+                return Ok(Vec::new());
+            }
+            Ok(vec![self.read_to_byte_converter().await?])
+        }
+    }
 }
 
 pub trait ByteStreamWriter {
     fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>>;
+    // streams that can coalesce the frames into a single write override this per value loop
+    #[inline(always)]
+    fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        for byte_converter in byte_converters {
+            self.write_from_byte_converter(byte_converter)?;
+        }
+        Ok(())
+    }
 }
 
 pub trait ByteStreamWriterAsync {
     fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> impl Future<Output = Result<(), Box<dyn Error + Send + Sync + 'static>>>;
+    // writes bytes that are already the encoding of a value as that value's frame, so that a value sent to many streams is only encoded once
+    // streams that can write the bytes without copying them into a frame first override this
+    #[inline(always)]
+    fn write_encoded_bytes(&mut self, encoded_bytes: &[u8]) -> impl Future<Output = Result<(), Box<dyn Error + Send + Sync + 'static>>> {
+        async move {
+            self.write_from_byte_converter(&EncodedBytes(encoded_bytes)).await
+        }
+    }
+    // streams that can coalesce the frames into a single write override this per value loop
+    #[inline(always)]
+    fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> impl Future<Output = Result<(), Box<dyn Error + Send + Sync + 'static>>> {
+        async move {
+            for byte_converter in byte_converters {
+                self.write_from_byte_converter(byte_converter).await?;
+            }
+            Ok(())
+        }
+    }
+}
+
+// bytes that are written as they are, since they already hold an encoded value
+struct EncodedBytes<'a>(&'a [u8]);
+
+impl ByteConverter for EncodedBytes<'_> {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        bytes.extend_from_slice(self.0);
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_: &'a TBytes, _: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Err(ByteConverterError::EncodedBytesNotExtractable.into())
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -119,6 +486,8 @@ enum ByteConverterError {
     },
     #[error("Failed to lock mutex.")]
     FailedToLockMutex,
+    #[error("Encoded bytes are only written and cannot be extracted, since their length is not recorded.")]
+    EncodedBytesNotExtractable,
 }
 
 #[inline(always)]
@@ -0,0 +1,342 @@
+use std::{collections::{HashSet, VecDeque}, error::Error, sync::{Arc, Mutex}};
+use tokio::sync::Notify;
+use crate::{ByteConverter, ByteConverterError, ByteStreamReaderAsync, ByteStreamWriterAsync};
+
+// a topic and payload that are encoded once when published and shared by every subscriber that receives them
+// the payload is only kept within the encoded bytes, which are written to each subscriber as they are
+#[derive(Clone, Debug, PartialEq)]
+pub struct PubSubMessage {
+    topic: String,
+    encoded_bytes: Vec<u8>,
+    payload_index: usize,
+}
+
+impl PubSubMessage {
+    pub fn new<T: ByteConverter>(topic: impl Into<String>, value: &T) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let topic = topic.into();
+        let mut encoded_bytes = Vec::new();
+        topic.append_to_bytes(&mut encoded_bytes)?;
+        let payload = value.to_vec_bytes()?;
+
+        // the same bytes as the payload appended as a Vec<u8>
+        payload.len().append_to_bytes(&mut encoded_bytes)?;
+        let payload_index = encoded_bytes.len();
+        encoded_bytes.extend_from_slice(&payload);
+        Ok(Self {
+            topic,
+            encoded_bytes,
+            payload_index,
+        })
+    }
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+    pub fn payload(&self) -> &[u8] {
+        &self.encoded_bytes[self.payload_index..]
+    }
+    pub fn encoded_bytes(&self) -> &[u8] {
+        &self.encoded_bytes
+    }
+    pub fn deserialize_payload<T: ByteConverter>(&self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        T::deserialize_from_bytes(&self.payload())
+    }
+}
+
+impl ByteConverter for PubSubMessage {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        bytes.extend_from_slice(&self.encoded_bytes);
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let start_index = *index;
+        let topic = String::extract_from_bytes(bytes, index)?;
+        let payload_length = usize::extract_from_bytes(bytes, index)?;
+        let payload_index = *index - start_index;
+        if bytes.as_ref().len() - *index < payload_length {
+            return Err(ByteConverterError::IndexOutOfRange {
+                index: index.saturating_add(payload_length),
+                length: bytes.as_ref().len(),
+            }.into());
+        }
+        *index += payload_length;
+        Ok(Self {
+            topic,
+            encoded_bytes: bytes.as_ref()[start_index..*index].to_vec(),
+            payload_index,
+        })
+    }
+}
+
+// what happens to a subscriber whose queue is full when another message is published to it
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LagPolicy {
+    #[default]
+    DropOldest,
+    Disconnect,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SubscriberSettings {
+    pub capacity: usize,
+    pub lag_policy: LagPolicy,
+}
+
+impl Default for SubscriberSettings {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            lag_policy: LagPolicy::default(),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PubSubError {
+    #[error("Subscriber was disconnected for falling behind its queue capacity.")]
+    Lagged,
+    #[error("Publish/subscribe hub was closed.")]
+    Closed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SubscriberStatus {
+    Open,
+    Lagged,
+    Closed,
+}
+
+struct SubscriberQueueState {
+    messages: VecDeque<Arc<PubSubMessage>>,
+    dropped_count: u64,
+    status: SubscriberStatus,
+}
+
+struct SubscriberQueue {
+    topics: HashSet<String>,
+    settings: SubscriberSettings,
+    state: Mutex<SubscriberQueueState>,
+    notify: Notify,
+}
+
+impl SubscriberQueue {
+    // returns false once the subscriber is no longer open so that the hub can forget it
+    fn push(&self, message: &Arc<PubSubMessage>) -> bool {
+        let mut state = self.state.lock()
+            .unwrap();
+        if state.status != SubscriberStatus::Open {
+            return false;
+        }
+        if state.messages.len() >= self.settings.capacity {
+            match self.settings.lag_policy {
+                LagPolicy::DropOldest => {
+                    state.messages.pop_front();
+                    state.dropped_count += 1;
+                },
+                LagPolicy::Disconnect => {
+                    state.messages.clear();
+                    state.status = SubscriberStatus::Lagged;
+                    drop(state);
+                    self.notify.notify_one();
+                    return false;
+                },
+            }
+        }
+        state.messages.push_back(message.clone());
+        drop(state);
+        self.notify.notify_one();
+        true
+    }
+    fn close(&self, status: SubscriberStatus) {
+        let mut state = self.state.lock()
+            .unwrap();
+        if state.status == SubscriberStatus::Open {
+            state.status = status;
+        }
+        drop(state);
+        self.notify.notify_one();
+    }
+}
+
+#[derive(Default)]
+struct PubSubHubInner {
+    subscribers: Mutex<Vec<Arc<SubscriberQueue>>>,
+}
+
+impl Drop for PubSubHubInner {
+    fn drop(&mut self) {
+        for subscriber in self.subscribers.get_mut().unwrap().drain(..) {
+            subscriber.close(SubscriberStatus::Closed);
+        }
+    }
+}
+
+// fans published messages out to the bounded queue of every subscriber of the topic
+// subscribers are closed once every clone of the hub is dropped
+#[derive(Clone, Default)]
+pub struct PubSubHub {
+    inner: Arc<PubSubHubInner>,
+}
+
+impl PubSubHub {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    // returns the number of subscribers that the message was queued for
+    pub fn publish<T: ByteConverter>(&self, topic: impl Into<String>, value: &T) -> Result<usize, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(self.publish_message(PubSubMessage::new(topic, value)?))
+    }
+    pub fn publish_message(&self, message: PubSubMessage) -> usize {
+        let message = Arc::new(message);
+        let mut queued_count = 0;
+        self.inner.subscribers.lock()
+            .unwrap()
+            .retain(|subscriber| {
+                if !subscriber.topics.contains(message.topic()) {
+                    return subscriber.state.lock().unwrap().status == SubscriberStatus::Open;
+                }
+                let is_open = subscriber.push(&message);
+                if is_open {
+                    queued_count += 1;
+                }
+                is_open
+            });
+        queued_count
+    }
+    pub fn subscribe<TTopic: Into<String>>(&self, topics: impl IntoIterator<Item = TTopic>, settings: SubscriberSettings) -> PubSubSubscription {
+        let queue = Arc::new(SubscriberQueue {
+            topics: topics.into_iter().map(Into::into).collect(),
+            settings,
+            state: Mutex::new(SubscriberQueueState {
+                messages: VecDeque::new(),
+                dropped_count: 0,
+                status: SubscriberStatus::Open,
+            }),
+            notify: Notify::new(),
+        });
+        self.inner.subscribers.lock()
+            .unwrap()
+            .push(queue.clone());
+        PubSubSubscription {
+            queue,
+        }
+    }
+    pub fn subscriber_count(&self) -> usize {
+        let mut subscribers = self.inner.subscribers.lock()
+            .unwrap();
+        subscribers.retain(|subscriber| subscriber.state.lock().unwrap().status == SubscriberStatus::Open);
+        subscribers.len()
+    }
+    // reads the topics from a PubSubRemoteSubscriber and forwards their messages until the connection fails or the subscriber lags behind
+    pub async fn serve_subscriber<TReader: ByteStreamReaderAsync, TWriter: ByteStreamWriterAsync>(&self, mut reader: TReader, mut writer: TWriter, settings: SubscriberSettings) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let topics = reader.read_to_byte_converter::<Vec<String>>()
+            .await?;
+        let mut subscription = self.subscribe(topics, settings);
+        loop {
+            let message = subscription.receive()
+                .await?;
+            writer.write_encoded_bytes(message.encoded_bytes())
+                .await?;
+        }
+    }
+    // publishes every message sent by a PubSubRemotePublisher until the connection fails
+    pub async fn serve_publisher<TReader: ByteStreamReaderAsync>(&self, mut reader: TReader) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        loop {
+            let message = reader.read_to_byte_converter::<PubSubMessage>()
+                .await?;
+            self.publish_message(message);
+        }
+    }
+}
+
+pub struct PubSubSubscription {
+    queue: Arc<SubscriberQueue>,
+}
+
+impl PubSubSubscription {
+    pub async fn receive(&mut self) -> Result<Arc<PubSubMessage>, PubSubError> {
+        loop {
+            {
+                let mut state = self.queue.state.lock()
+                    .unwrap();
+                if let Some(message) = state.messages.pop_front() {
+                    return Ok(message);
+                }
+                match state.status {
+                    SubscriberStatus::Open => {},
+                    SubscriberStatus::Lagged => return Err(PubSubError::Lagged),
+                    SubscriberStatus::Closed => return Err(PubSubError::Closed),
+                }
+            }
+            // the permit stored by notify_one is not lost if the message arrived between the check and this wait
+            self.queue.notify.notified()
+                .await;
+        }
+    }
+    pub async fn receive_value<T: ByteConverter>(&mut self) -> Result<(String, T), Box<dyn Error + Send + Sync + 'static>> {
+        let message = self.receive()
+            .await?;
+        Ok((String::from(message.topic()), message.deserialize_payload()?))
+    }
+    pub fn topics(&self) -> &HashSet<String> {
+        &self.queue.topics
+    }
+    // the number of messages dropped by the DropOldest lag policy
+    pub fn dropped_count(&self) -> u64 {
+        self.queue.state.lock()
+            .unwrap()
+            .dropped_count
+    }
+}
+
+impl Drop for PubSubSubscription {
+    fn drop(&mut self) {
+        self.queue.close(SubscriberStatus::Closed);
+    }
+}
+
+// subscribes to a hub that is served on the other end of the connection by PubSubHub::serve_subscriber
+pub struct PubSubRemoteSubscriber<TReader> {
+    reader: TReader,
+}
+
+impl<TReader: ByteStreamReaderAsync> PubSubRemoteSubscriber<TReader> {
+    pub async fn subscribe<TWriter: ByteStreamWriterAsync, TTopic: Into<String>>(reader: TReader, writer: &mut TWriter, topics: impl IntoIterator<Item = TTopic>) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let topics = topics.into_iter()
+            .map(Into::into)
+            .collect::<Vec<String>>();
+        writer.write_from_byte_converter(&topics)
+            .await?;
+        Ok(Self {
+            reader,
+        })
+    }
+    pub async fn receive(&mut self) -> Result<PubSubMessage, Box<dyn Error + Send + Sync + 'static>> {
+        self.reader.read_to_byte_converter::<PubSubMessage>()
+            .await
+    }
+    pub async fn receive_value<T: ByteConverter>(&mut self) -> Result<(String, T), Box<dyn Error + Send + Sync + 'static>> {
+        let message = self.receive()
+            .await?;
+        let value = message.deserialize_payload()?;
+        Ok((message.topic, value))
+    }
+}
+
+// publishes to a hub that is served on the other end of the connection by PubSubHub::serve_publisher
+pub struct PubSubRemotePublisher<TWriter> {
+    writer: TWriter,
+}
+
+impl<TWriter: ByteStreamWriterAsync> PubSubRemotePublisher<TWriter> {
+    pub fn new(writer: TWriter) -> Self {
+        Self {
+            writer,
+        }
+    }
+    pub async fn publish<T: ByteConverter>(&mut self, topic: impl Into<String>, value: &T) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.writer.write_from_byte_converter(&PubSubMessage::new(topic, value)?)
+            .await
+    }
+}
@@ -43,4 +43,60 @@ impl ByteConverter for ChaCha20Rng {
         let seed: [u8; 32] = get_multiple_bytes(bytes, index, 32)?.try_into()?;
         Ok(Self::from_seed(seed))
     }
-}
\ No newline at end of file
+}
+
+// the plain rng impls above only hold the seed, which restores the rng at the start of its stream
+// this wrapper also holds the stream and word position so that the restored rng continues where the original left off
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChaChaRngState<TRng>(pub TRng);
+
+impl ByteConverter for ChaChaRngState<ChaCha8Rng> {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.0.append_to_bytes(bytes)?;
+        self.0.get_stream().append_to_bytes(bytes)?;
+        self.0.get_word_pos().append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let mut rng = ChaCha8Rng::extract_from_bytes(bytes, index)?;
+        rng.set_stream(u64::extract_from_bytes(bytes, index)?);
+        rng.set_word_pos(u128::extract_from_bytes(bytes, index)?);
+        Ok(Self(rng))
+    }
+}
+
+impl ByteConverter for ChaChaRngState<ChaCha12Rng> {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.0.append_to_bytes(bytes)?;
+        self.0.get_stream().append_to_bytes(bytes)?;
+        self.0.get_word_pos().append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let mut rng = ChaCha12Rng::extract_from_bytes(bytes, index)?;
+        rng.set_stream(u64::extract_from_bytes(bytes, index)?);
+        rng.set_word_pos(u128::extract_from_bytes(bytes, index)?);
+        Ok(Self(rng))
+    }
+}
+
+impl ByteConverter for ChaChaRngState<ChaCha20Rng> {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.0.append_to_bytes(bytes)?;
+        self.0.get_stream().append_to_bytes(bytes)?;
+        self.0.get_word_pos().append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let mut rng = ChaCha20Rng::extract_from_bytes(bytes, index)?;
+        rng.set_stream(u64::extract_from_bytes(bytes, index)?);
+        rng.set_word_pos(u128::extract_from_bytes(bytes, index)?);
+        Ok(Self(rng))
+    }
+}
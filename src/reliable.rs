@@ -0,0 +1,522 @@
+use std::{collections::{BTreeMap, VecDeque}, error::Error, net::SocketAddr, time::{Duration, Instant}};
+use crate::{udp::ByteConUdpSocket, ByteConverter};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelKind {
+    // sent once and delivered as it arrives, so messages may be lost, duplicated or reordered
+    Unreliable,
+    // resent until acknowledged and delivered once each as they arrive
+    ReliableUnordered,
+    // resent until acknowledged and delivered once each in the order they were sent
+    ReliableOrdered,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReliableSettings {
+    // the kind of each channel, where the index is the channel id used when sending and receiving
+    pub channels: Vec<ChannelKind>,
+    pub resend_interval: Duration,
+    // reliable messages this far ahead of the next expected sequence are ignored and left for the sender to resend
+    pub receive_window: u32,
+    // messages are packed together into packets up to this size, while larger messages are sent alone
+    pub max_packet_size: usize,
+    // reliable messages sent but not acknowledged yet per channel, where later messages wait for acks before they are first sent
+    pub max_in_flight_messages: usize,
+    // reliable messages waiting for an ack per channel, including those not sent yet, beyond which send fails
+    pub max_queued_messages: usize,
+    // due resends beyond this are left for the next poll_transmit so that a burst of losses does not flood the link
+    pub max_resends_per_transmit: usize,
+    // unreliable messages waiting for poll_transmit per channel, beyond which the oldest are dropped
+    pub max_unsent_unreliable_messages: usize,
+    // received messages waiting for receive per channel, beyond which unreliable messages are dropped and reliable ones are left unacked for the sender to resend
+    pub max_delivered_messages: usize,
+}
+
+impl Default for ReliableSettings {
+    fn default() -> Self {
+        Self {
+            channels: vec![ChannelKind::Unreliable, ChannelKind::ReliableUnordered, ChannelKind::ReliableOrdered],
+            resend_interval: Duration::from_millis(100),
+            receive_window: 1024,
+            max_packet_size: 1100,
+            max_in_flight_messages: 256,
+            max_queued_messages: 4096,
+            max_resends_per_transmit: 64,
+            max_unsent_unreliable_messages: 1024,
+            max_delivered_messages: 4096,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+enum ReliableByteConError {
+    #[error("Channel {channel} is not one of the {channels_length} configured channels.")]
+    UnknownChannel {
+        channel: u8,
+        channels_length: usize,
+    },
+    #[error("Channel {channel} already has {max_queued_messages} reliable messages waiting for an ack.")]
+    SendQueueFull {
+        channel: u8,
+        max_queued_messages: usize,
+    },
+}
+
+struct ChannelAck {
+    channel: u8,
+    sequence: u32,
+}
+
+impl ByteConverter for ChannelAck {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.channel.append_to_bytes(bytes)?;
+        self.sequence.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            channel: u8::extract_from_bytes(bytes, index)?,
+            sequence: u32::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+#[derive(Clone)]
+struct ChannelMessage {
+    channel: u8,
+    sequence: u32,
+    payload: Vec<u8>,
+}
+
+impl ByteConverter for ChannelMessage {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.channel.append_to_bytes(bytes)?;
+        self.sequence.append_to_bytes(bytes)?;
+        self.payload.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            channel: u8::extract_from_bytes(bytes, index)?,
+            sequence: u32::extract_from_bytes(bytes, index)?,
+            payload: Vec::<u8>::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+struct ReliablePacket {
+    acks: Vec<ChannelAck>,
+    messages: Vec<ChannelMessage>,
+}
+
+impl ByteConverter for ReliablePacket {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.acks.append_to_bytes(bytes)?;
+        self.messages.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            acks: Vec::<ChannelAck>::extract_from_bytes(bytes, index)?,
+            messages: Vec::<ChannelMessage>::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+struct UnackedMessage {
+    payload: Vec<u8>,
+    last_sent_at: Option<Instant>,
+}
+
+struct ChannelState {
+    kind: ChannelKind,
+    next_send_sequence: u32,
+    unsent_unreliable_messages: VecDeque<ChannelMessage>,
+    unacked_messages: BTreeMap<u32, UnackedMessage>,
+    // every sequence below this one has been received
+    next_receive_sequence: u32,
+    // received sequences above the next expected one, holding the payload until it can be delivered in order for ordered channels
+    received_ahead: BTreeMap<u32, Option<Vec<u8>>>,
+    delivered_payloads: VecDeque<Vec<u8>>,
+}
+
+// one side of a connection that multiplexes unreliable and reliable channels over packets sent through any unreliable transport
+// the endpoint does no IO itself: packets from the peer are passed to receive_packet and packets returned from poll_transmit are sent to the peer
+pub struct ReliableEndpoint {
+    settings: ReliableSettings,
+    channels: Vec<ChannelState>,
+    pending_acks: Vec<ChannelAck>,
+}
+
+impl ReliableEndpoint {
+    pub fn new(settings: ReliableSettings) -> Self {
+        let channels = settings.channels.iter()
+            .map(|kind| ChannelState {
+                kind: *kind,
+                next_send_sequence: 0,
+                unsent_unreliable_messages: VecDeque::new(),
+                unacked_messages: BTreeMap::new(),
+                next_receive_sequence: 0,
+                received_ahead: BTreeMap::new(),
+                delivered_payloads: VecDeque::new(),
+            })
+            .collect();
+        Self {
+            settings,
+            channels,
+            pending_acks: Vec::new(),
+        }
+    }
+    pub fn settings(&self) -> &ReliableSettings {
+        &self.settings
+    }
+    fn get_channel_mut(&mut self, channel: u8) -> Result<&mut ChannelState, Box<dyn Error + Send + Sync + 'static>> {
+        let channels_length = self.channels.len();
+        self.channels.get_mut(channel as usize)
+            .ok_or_else(|| ReliableByteConError::UnknownChannel {
+                channel,
+                channels_length,
+            }.into())
+    }
+    // queues the value to be sent by the next poll_transmit
+    pub fn send<T: ByteConverter>(&mut self, channel: u8, value: &T) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let payload = value.to_vec_bytes()?;
+        let max_queued_messages = self.settings.max_queued_messages;
+        let max_unsent_unreliable_messages = self.settings.max_unsent_unreliable_messages;
+        let channel_state = self.get_channel_mut(channel)?;
+        if channel_state.unacked_messages.len() >= max_queued_messages {
+            return Err(ReliableByteConError::SendQueueFull {
+                channel,
+                max_queued_messages,
+            }.into());
+        }
+        let sequence = channel_state.next_send_sequence;
+        channel_state.next_send_sequence = channel_state.next_send_sequence.wrapping_add(1);
+        match channel_state.kind {
+            ChannelKind::Unreliable => {
+                // unreliable messages may be lost anyway, so the oldest make room instead of failing the send
+                if channel_state.unsent_unreliable_messages.len() >= max_unsent_unreliable_messages {
+                    channel_state.unsent_unreliable_messages.pop_front();
+                }
+                channel_state.unsent_unreliable_messages.push_back(ChannelMessage {
+                    channel,
+                    sequence,
+                    payload,
+                });
+            },
+            ChannelKind::ReliableUnordered | ChannelKind::ReliableOrdered => {
+                channel_state.unacked_messages.insert(sequence, UnackedMessage {
+                    payload,
+                    last_sent_at: None,
+                });
+            },
+        }
+        Ok(())
+    }
+    pub fn receive<T: ByteConverter>(&mut self, channel: u8) -> Result<Option<T>, Box<dyn Error + Send + Sync + 'static>> {
+        match self.receive_bytes(channel)? {
+            Some(payload) => Ok(Some(T::deserialize_from_bytes(&payload)?)),
+            None => Ok(None),
+        }
+    }
+    pub fn receive_bytes(&mut self, channel: u8) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(self.get_channel_mut(channel)?
+            .delivered_payloads
+            .pop_front())
+    }
+    // the number of reliable messages that the peer has not acknowledged yet
+    pub fn unacked_message_count(&self) -> usize {
+        self.channels.iter()
+            .map(|channel_state| channel_state.unacked_messages.len())
+            .sum()
+    }
+    pub fn receive_packet(&mut self, packet_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let packet = ReliablePacket::deserialize_from_bytes(&packet_bytes)?;
+
+        // the whole packet is checked before any of it is applied so that a bad packet leaves the endpoint untouched
+        let channels = packet.acks.iter()
+            .map(|ack| ack.channel)
+            .chain(packet.messages.iter().map(|message| message.channel));
+        for channel in channels {
+            self.get_channel_mut(channel)?;
+        }
+        for ack in packet.acks {
+            self.get_channel_mut(ack.channel)?
+                .unacked_messages
+                .remove(&ack.sequence);
+        }
+        let receive_window = self.settings.receive_window;
+        let max_delivered_messages = self.settings.max_delivered_messages;
+        for message in packet.messages {
+            let channel_state = self.get_channel_mut(message.channel)?;
+            let is_delivered_full = channel_state.delivered_payloads.len() >= max_delivered_messages;
+            if channel_state.kind == ChannelKind::Unreliable {
+                if !is_delivered_full {
+                    channel_state.delivered_payloads.push_back(message.payload);
+                }
+                continue;
+            }
+            let offset = message.sequence.wrapping_sub(channel_state.next_receive_sequence);
+            if offset >= receive_window && offset <= u32::MAX - receive_window {
+                continue;
+            }
+
+            // a new message is left unacked while the delivered ones are not being received, so the sender keeps it until there is room
+            if offset < receive_window && is_delivered_full && !channel_state.received_ahead.contains_key(&message.sequence) {
+                continue;
+            }
+            self.pending_acks.push(ChannelAck {
+                channel: message.channel,
+                sequence: message.sequence,
+            });
+            let channel_state = self.get_channel_mut(message.channel)?;
+
+            // sequences behind the window were already received and only needed to be acknowledged again
+            if offset >= receive_window || channel_state.received_ahead.contains_key(&message.sequence) {
+                continue;
+            }
+            match channel_state.kind {
+                ChannelKind::ReliableUnordered => {
+                    channel_state.delivered_payloads.push_back(message.payload);
+                    channel_state.received_ahead.insert(message.sequence, None);
+                },
+                _ => {
+                    channel_state.received_ahead.insert(message.sequence, Some(message.payload));
+                },
+            }
+            while let Some(payload) = channel_state.received_ahead.remove(&channel_state.next_receive_sequence) {
+                if let Some(payload) = payload {
+                    channel_state.delivered_payloads.push_back(payload);
+                }
+                channel_state.next_receive_sequence = channel_state.next_receive_sequence.wrapping_add(1);
+            }
+        }
+        Ok(())
+    }
+    // returns the packets to send to the peer now: new messages, reliable messages whose resend interval elapsed and pending acks
+    // only the oldest unacked messages of each channel, up to the in-flight limit, are sent, and at most the resend limit of them are resends
+    pub fn poll_transmit(&mut self, now: Instant) -> Result<Vec<Vec<u8>>, Box<dyn Error + Send + Sync + 'static>> {
+        let mut messages = Vec::new();
+        for channel_state in self.channels.iter_mut() {
+            messages.extend(channel_state.unsent_unreliable_messages.drain(..));
+        }
+        let mut resends_length = 0;
+        for (channel, channel_state) in self.channels.iter_mut().enumerate() {
+            for (sequence, unacked_message) in channel_state.unacked_messages.iter_mut().take(self.settings.max_in_flight_messages) {
+                let is_due = match unacked_message.last_sent_at {
+                    Some(last_sent_at) => {
+                        let is_resend_due = now.saturating_duration_since(last_sent_at) >= self.settings.resend_interval && resends_length < self.settings.max_resends_per_transmit;
+                        if is_resend_due {
+                            resends_length += 1;
+                        }
+                        is_resend_due
+                    },
+                    None => true,
+                };
+                if is_due {
+                    unacked_message.last_sent_at = Some(now);
+                    messages.push(ChannelMessage {
+                        channel: channel as u8,
+                        sequence: *sequence,
+                        payload: unacked_message.payload.clone(),
+                    });
+                }
+            }
+        }
+
+        // acks are split across packets like messages, where messages fill up the room left after the acks
+        let mut packets = Vec::new();
+        let mut packet = ReliablePacket {
+            acks: Vec::new(),
+            messages: Vec::new(),
+        };
+        let empty_packet_length = packet.to_vec_bytes()?.len();
+        let mut packet_length = empty_packet_length;
+        for ack in std::mem::take(&mut self.pending_acks) {
+            let ack_length = ack.to_vec_bytes()?.len();
+            if !packet.acks.is_empty() && packet_length + ack_length > self.settings.max_packet_size {
+                packets.push(std::mem::replace(&mut packet, ReliablePacket {
+                    acks: Vec::new(),
+                    messages: Vec::new(),
+                }).to_vec_bytes()?);
+                packet_length = empty_packet_length;
+            }
+            packet_length += ack_length;
+            packet.acks.push(ack);
+        }
+        for message in messages {
+            let message_length = message.to_vec_bytes()?.len();
+            let is_packet_empty = packet.acks.is_empty() && packet.messages.is_empty();
+            if !is_packet_empty && packet_length + message_length > self.settings.max_packet_size {
+                packets.push(std::mem::replace(&mut packet, ReliablePacket {
+                    acks: Vec::new(),
+                    messages: Vec::new(),
+                }).to_vec_bytes()?);
+                packet_length = empty_packet_length;
+            }
+            packet_length += message_length;
+            packet.messages.push(message);
+        }
+        if !packet.acks.is_empty() || !packet.messages.is_empty() {
+            packets.push(packet.to_vec_bytes()?);
+        }
+        Ok(packets)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LossyLinkSettings {
+    pub drop_probability: f64,
+    pub duplicate_probability: f64,
+    // reordered packets are held back and delivered after the packets of the next transmit call
+    pub reorder_probability: f64,
+    pub seed: u64,
+}
+
+impl Default for LossyLinkSettings {
+    fn default() -> Self {
+        Self {
+            drop_probability: 0.0,
+            duplicate_probability: 0.0,
+            reorder_probability: 0.0,
+            seed: 0,
+        }
+    }
+}
+
+// simulates a lossy one-way link for deterministic tests, where the same seed always drops, duplicates and reorders the same packets
+pub struct LossyLink {
+    settings: LossyLinkSettings,
+    random_state: u64,
+    delayed_packets: Vec<Vec<u8>>,
+}
+
+impl LossyLink {
+    pub fn new(settings: LossyLinkSettings) -> Self {
+        Self {
+            settings,
+            random_state: settings.seed,
+            delayed_packets: Vec::new(),
+        }
+    }
+    // splitmix64
+    fn next_probability(&mut self) -> f64 {
+        self.random_state = self.random_state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut value = self.random_state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D049BB133111EB);
+        value ^= value >> 31;
+        (value >> 11) as f64 / (1u64 << 53) as f64
+    }
+    // returns the packets that arrive at the other end of the link
+    pub fn transmit(&mut self, packets: impl IntoIterator<Item = Vec<u8>>) -> Vec<Vec<u8>> {
+        let previously_delayed_packets = std::mem::take(&mut self.delayed_packets);
+        let mut delivered_packets = Vec::new();
+        for packet in packets {
+            if self.next_probability() < self.settings.drop_probability {
+                continue;
+            }
+            let copies_length = if self.next_probability() < self.settings.duplicate_probability {
+                2
+            }
+            else {
+                1
+            };
+            for _ in 0..copies_length {
+                if self.next_probability() < self.settings.reorder_probability {
+                    self.delayed_packets.push(packet.clone());
+                }
+                else {
+                    delivered_packets.push(packet.clone());
+                }
+            }
+        }
+        delivered_packets.extend(previously_delayed_packets);
+        delivered_packets
+    }
+}
+
+// drives a ReliableEndpoint over a ByteConUdpSocket that only exchanges packets with a single peer
+pub struct ReliableUdpConnection {
+    socket: ByteConUdpSocket,
+    peer_address: SocketAddr,
+    endpoint: ReliableEndpoint,
+}
+
+impl ReliableUdpConnection {
+    pub fn new(socket: ByteConUdpSocket, peer_address: SocketAddr, settings: ReliableSettings) -> Self {
+        Self {
+            socket,
+            peer_address,
+            endpoint: ReliableEndpoint::new(settings),
+        }
+    }
+    pub fn get_ref(&self) -> &ByteConUdpSocket {
+        &self.socket
+    }
+    pub fn endpoint(&self) -> &ReliableEndpoint {
+        &self.endpoint
+    }
+    pub async fn send<T: ByteConverter>(&mut self, channel: u8, value: &T) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.endpoint.send(channel, value)?;
+        self.flush()
+            .await
+    }
+    // sends whatever the endpoint has ready, which includes acks for received messages and due resends
+    pub async fn flush(&mut self) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        for packet in self.endpoint.poll_transmit(Instant::now())? {
+            self.socket.send_to(&RawPacket(packet), self.peer_address)
+                .await?;
+        }
+        Ok(())
+    }
+    // keeps exchanging packets with the peer, resending as needed, until a message arrives on the channel
+    pub async fn receive<T: ByteConverter>(&mut self, channel: u8) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        loop {
+            if let Some(value) = self.endpoint.receive(channel)? {
+                self.flush()
+                    .await?;
+                return Ok(value);
+            }
+            self.receive_once()
+                .await?;
+        }
+    }
+    // waits up to one resend interval for a packet and then flushes, which is also how queued acks and resends go out while no message is awaited
+    pub async fn receive_once(&mut self) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let resend_interval = self.endpoint.settings().resend_interval;
+        if let Ok(received) = tokio::time::timeout(resend_interval, self.socket.receive_bytes_from()).await {
+            let (packet_bytes, address) = received?;
+
+            // malformed packets are dropped like lost ones instead of ending the connection
+            if address == self.peer_address {
+                let _ = self.endpoint.receive_packet(&packet_bytes);
+            }
+        }
+        self.flush()
+            .await
+    }
+}
+
+// sends packet bytes as the whole datagram message instead of with a length prefix
+struct RawPacket(Vec<u8>);
+
+impl ByteConverter for RawPacket {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        bytes.extend_from_slice(&self.0);
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let packet = bytes.as_ref()[*index..].to_vec();
+        *index = bytes.as_ref().len();
+        Ok(Self(packet))
+    }
+}
@@ -0,0 +1,290 @@
+use std::{any::TypeId, collections::HashMap, error::Error, future::Future, pin::Pin, sync::{atomic::{AtomicU64, Ordering}, Arc, Mutex}, time::Duration};
+use tokio::sync::{mpsc, oneshot};
+use crate::{ByteConverter, ByteStreamReaderAsync, ByteStreamWriterAsync};
+
+// a request type that the dispatcher routes by its name to the handler registered for it
+// the name is sent with every request, so it must be stable across builds and unique among the registered request types
+pub trait RpcRequest: ByteConverter + Send + 'static {
+    const NAME: &'static str;
+    type Response: ByteConverter + Send + 'static;
+}
+
+// returned from calls so that timeouts and remote failures can be told apart by downcasting the boxed error
+#[derive(thiserror::Error, Clone, Debug, PartialEq)]
+pub enum RpcError {
+    #[error("RPC request timed out before a response was received.")]
+    Timeout,
+    #[error("RPC connection closed before a response was received.")]
+    ConnectionClosed,
+    #[error("RPC request failed remotely: {message}")]
+    Remote {
+        message: String,
+    },
+}
+
+struct RpcRequestFrame {
+    id: u64,
+    name: String,
+    request_bytes: Vec<u8>,
+}
+
+impl ByteConverter for RpcRequestFrame {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.id.append_to_bytes(bytes)?;
+        self.name.append_to_bytes(bytes)?;
+        self.request_bytes.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            id: u64::extract_from_bytes(bytes, index)?,
+            name: String::extract_from_bytes(bytes, index)?,
+            request_bytes: Vec::<u8>::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+struct RpcResponseFrame {
+    id: u64,
+    result: Result<Vec<u8>, String>,
+}
+
+impl ByteConverter for RpcResponseFrame {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.id.append_to_bytes(bytes)?;
+        match &self.result {
+            Ok(response_bytes) => {
+                0u8.append_to_bytes(bytes)?;
+                response_bytes.append_to_bytes(bytes)?;
+            },
+            Err(message) => {
+                1u8.append_to_bytes(bytes)?;
+                message.append_to_bytes(bytes)?;
+            },
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let id = u64::extract_from_bytes(bytes, index)?;
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        let result = match enum_variant_byte {
+            0u8 => Ok(Vec::<u8>::extract_from_bytes(bytes, index)?),
+            1u8 => Err(String::extract_from_bytes(bytes, index)?),
+            _ => return Err("Unexpected enum variant byte.".into()),
+        };
+        Ok(Self {
+            id,
+            result,
+        })
+    }
+}
+
+type PendingResponses = Arc<Mutex<HashMap<u64, oneshot::Sender<Result<Vec<u8>, RpcError>>>>>;
+
+// sends requests over a single connection, matching each response to its request by correlation id so that many calls can be in flight at once
+// cloned clients share the connection, which is closed once every clone is dropped
+#[derive(Clone)]
+pub struct RpcClient {
+    outgoing_sender: mpsc::UnboundedSender<RpcRequestFrame>,
+    pending_responses: PendingResponses,
+    next_id: Arc<AtomicU64>,
+    timeout: Option<Duration>,
+}
+
+impl RpcClient {
+    // the returned future drives the connection and must be spawned or awaited alongside the calls
+    pub fn new<TReader: ByteStreamReaderAsync, TWriter: ByteStreamWriterAsync>(mut reader: TReader, mut writer: TWriter) -> (Self, impl Future<Output = Result<(), Box<dyn Error + Send + Sync + 'static>>>) {
+        let (outgoing_sender, mut outgoing_receiver) = mpsc::unbounded_channel::<RpcRequestFrame>();
+        let pending_responses = PendingResponses::default();
+        let client = Self {
+            outgoing_sender,
+            pending_responses: pending_responses.clone(),
+            next_id: Arc::new(AtomicU64::new(0)),
+            timeout: None,
+        };
+        let driver = async move {
+            let read_loop = async {
+                loop {
+                    let response_frame = reader.read_to_byte_converter::<RpcResponseFrame>()
+                        .await?;
+
+                    // responses for requests that already timed out are dropped
+                    let response_sender = pending_responses.lock()
+                        .unwrap()
+                        .remove(&response_frame.id);
+                    if let Some(response_sender) = response_sender {
+                        let _ = response_sender.send(response_frame.result.map_err(|message| RpcError::Remote { message }));
+                    }
+                }
+            };
+            let write_loop = async {
+                while let Some(request_frame) = outgoing_receiver.recv().await {
+                    writer.write_from_byte_converter(&request_frame)
+                        .await?;
+                }
+                Ok(())
+            };
+            let result: Result<(), Box<dyn Error + Send + Sync + 'static>> = tokio::select! {
+                result = read_loop => result,
+                result = write_loop => result,
+            };
+
+            // closing the channel first makes later calls fail to send instead of waiting on a response that never comes
+            // then dropping the senders lets every call that is already waiting know that the connection closed
+            outgoing_receiver.close();
+            pending_responses.lock()
+                .unwrap()
+                .clear();
+            result
+        };
+        (client, driver)
+    }
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    pub async fn call<TRequest: RpcRequest>(&self, request: &TRequest) -> Result<TRequest::Response, Box<dyn Error + Send + Sync + 'static>> {
+        self.call_with_optional_timeout(request, self.timeout)
+            .await
+    }
+    pub async fn call_with_timeout<TRequest: RpcRequest>(&self, request: &TRequest, timeout: Duration) -> Result<TRequest::Response, Box<dyn Error + Send + Sync + 'static>> {
+        self.call_with_optional_timeout(request, Some(timeout))
+            .await
+    }
+    async fn call_with_optional_timeout<TRequest: RpcRequest>(&self, request: &TRequest, timeout: Option<Duration>) -> Result<TRequest::Response, Box<dyn Error + Send + Sync + 'static>> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let request_frame = RpcRequestFrame {
+            id,
+            name: String::from(TRequest::NAME),
+            request_bytes: request.to_vec_bytes()?,
+        };
+        let (response_sender, response_receiver) = oneshot::channel();
+        self.pending_responses.lock()
+            .unwrap()
+            .insert(id, response_sender);
+        if self.outgoing_sender.send(request_frame).is_err() {
+            self.pending_responses.lock()
+                .unwrap()
+                .remove(&id);
+            return Err(RpcError::ConnectionClosed.into());
+        }
+
+        let response_result = match timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, response_receiver).await {
+                Ok(response_result) => response_result,
+                Err(_) => {
+                    self.pending_responses.lock()
+                        .unwrap()
+                        .remove(&id);
+                    return Err(RpcError::Timeout.into());
+                },
+            },
+            None => response_receiver.await,
+        };
+        let response_bytes = response_result.map_err(|_| RpcError::ConnectionClosed)??;
+        TRequest::Response::deserialize_from_bytes(&response_bytes)
+    }
+}
+
+type RpcHandlerFuture = Pin<Box<dyn Future<Output = Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>>> + Send>>;
+type RpcHandler = Arc<dyn Fn(Vec<u8>) -> RpcHandlerFuture + Send + Sync>;
+
+// routes each incoming request by its name to the handler registered for that request type
+#[derive(Clone, Default)]
+pub struct RpcDispatcher {
+    handler_per_name: HashMap<&'static str, (TypeId, RpcHandler)>,
+}
+
+impl RpcDispatcher {
+    // registering the same request type again replaces its handler, but reusing a name for a different request type fails
+    pub fn register<TRequest, THandler, TFuture>(&mut self, handler: THandler) -> Result<&mut Self, Box<dyn Error + Send + Sync + 'static>>
+    where
+        TRequest: RpcRequest,
+        THandler: Fn(TRequest) -> TFuture + Send + Sync + 'static,
+        TFuture: Future<Output = Result<TRequest::Response, Box<dyn Error + Send + Sync + 'static>>> + Send + 'static,
+    {
+        if let Some((type_id, _)) = self.handler_per_name.get(TRequest::NAME) {
+            if *type_id != TypeId::of::<TRequest>() {
+                return Err(RpcByteConError::RequestNameConflict {
+                    name: TRequest::NAME,
+                }.into());
+            }
+        }
+        let handler = Arc::new(handler);
+        self.handler_per_name.insert(
+            TRequest::NAME,
+            (TypeId::of::<TRequest>(), Arc::new(move |request_bytes: Vec<u8>| {
+                let handler = handler.clone();
+                Box::pin(async move {
+                    let request = TRequest::deserialize_from_bytes(&request_bytes)?;
+                    let response = handler(request)
+                        .await?;
+                    response.to_vec_bytes()
+                }) as RpcHandlerFuture
+            })),
+        );
+        Ok(self)
+    }
+    pub fn get_registered_names(&self) -> Vec<&'static str> {
+        self.handler_per_name.keys()
+            .cloned()
+            .collect::<Vec<_>>()
+    }
+    // handles requests concurrently until the reader fails, which is how a closed connection is reported, or until the writer fails
+    pub async fn serve<TReader: ByteStreamReaderAsync, TWriter: ByteStreamWriterAsync>(&self, mut reader: TReader, mut writer: TWriter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let (response_sender, mut response_receiver) = mpsc::unbounded_channel::<RpcResponseFrame>();
+        let read_loop = async move {
+            loop {
+                let request_frame = reader.read_to_byte_converter::<RpcRequestFrame>()
+                    .await?;
+                let Some((_, handler)) = self.handler_per_name.get(request_frame.name.as_str()).cloned() else {
+                    let _ = response_sender.send(RpcResponseFrame {
+                        id: request_frame.id,
+                        result: Err(format!("Request name {} is not registered to any handler.", request_frame.name)),
+                    });
+                    continue;
+                };
+                let response_sender = response_sender.clone();
+                tokio::spawn(async move {
+                    let result = handler(request_frame.request_bytes)
+                        .await
+                        .map_err(|error| error.to_string());
+                    let _ = response_sender.send(RpcResponseFrame {
+                        id: request_frame.id,
+                        result,
+                    });
+                });
+            }
+        };
+        // keeps writing until every spawned handler has sent its response
+        let write_loop = async move {
+            while let Some(response_frame) = response_receiver.recv().await {
+                writer.write_from_byte_converter(&response_frame)
+                    .await?;
+            }
+            Ok::<(), Box<dyn Error + Send + Sync + 'static>>(())
+        };
+        let mut write_loop = std::pin::pin!(write_loop);
+        let read_result: Result<(), Box<dyn Error + Send + Sync + 'static>> = tokio::select! {
+            read_result = read_loop => read_result,
+            // a failed writer stops reading too, since no response could be sent anymore
+            write_result = &mut write_loop => return write_result,
+        };
+
+        // the read loop has been dropped along with its response sender, so the writer ends once the spawned handlers are done
+        write_loop.await?;
+        read_result
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+enum RpcByteConError {
+    #[error("Request name {name} is already registered to a different request type.")]
+    RequestNameConflict {
+        name: &'static str,
+    },
+}
@@ -1,7 +1,9 @@
 use std::error::Error;
 use crate::ByteConverter;
 
-impl ByteConverter for rustls::Certificate {
+// deprecated since this writes the private key as plaintext, which the compiler cannot flag on a trait impl
+// with the "chacha20poly1305" or "aes_gcm" feature use the EncryptionKeyring context or wrap the key in Encrypted<SecretBytes> instead
+impl ByteConverter for rustls::PrivateKey {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         self.0.append_to_bytes(bytes)?;
@@ -13,7 +15,7 @@ impl ByteConverter for rustls::Certificate {
     }
 }
 
-impl ByteConverter for rustls::PrivateKey {
+impl ByteConverter for rustls::Certificate {
     #[inline(always)]
     fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         self.0.append_to_bytes(bytes)?;
@@ -23,4 +25,4 @@ impl ByteConverter for rustls::PrivateKey {
     fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
         Ok(Self(Vec::<u8>::extract_from_bytes(bytes, index)?))
     }
-}
\ No newline at end of file
+}
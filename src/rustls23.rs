@@ -0,0 +1,262 @@
+use std::{error::Error, net::IpAddr, sync::Arc};
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use rustls23::{client::WebPkiServerVerifier, crypto::CryptoProvider, server::WebPkiClientVerifier, ClientConfig, RootCertStore, ServerConfig};
+use crate::ByteConverter;
+#[cfg(any(feature = "aes_gcm", feature = "chacha20poly1305"))]
+use rustls_pki_types::{PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer};
+#[cfg(any(feature = "aes_gcm", feature = "chacha20poly1305"))]
+use crate::{encryption::{Encrypted, EncryptionKeyring, SecretBytes}, ContextualByteConverter};
+
+impl ByteConverter for CertificateDer<'static> {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.as_ref().to_vec().append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self::from(Vec::<u8>::extract_from_bytes(bytes, index)?))
+    }
+}
+
+// private keys, and the config descriptions holding them, have no plain ByteConverter so that they are never written to disk unencrypted
+// with the "chacha20poly1305" or "aes_gcm" feature they are encoded with an EncryptionKeyring context instead
+
+// the format byte and key der only ever exist unencrypted as SecretBytes so that no plaintext copy is left behind
+#[cfg(any(feature = "aes_gcm", feature = "chacha20poly1305"))]
+impl ContextualByteConverter<EncryptionKeyring> for PrivateKeyDer<'static> {
+    fn append_to_bytes_with(&self, context: &EncryptionKeyring, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let format_byte = match self {
+            Self::Pkcs1(_) => 0u8,
+            Self::Sec1(_) => 1u8,
+            Self::Pkcs8(_) => 2u8,
+            _ => return Err(Rustls23ByteConError::UnsupportedPrivateKeyFormat.into()),
+        };
+        // reserved up front so that growing the Vec does not leave a copy of the key behind in freed memory
+        let secret_der = self.secret_der();
+        let mut secret_bytes = Vec::with_capacity(1 + secret_der.len());
+        secret_bytes.push(format_byte);
+        secret_bytes.extend_from_slice(secret_der);
+        Encrypted::new(SecretBytes::new(secret_bytes)).append_to_bytes_with(context, bytes)
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut EncryptionKeyring, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let secret_bytes = Encrypted::<SecretBytes>::extract_from_bytes_with(context, bytes, index)?.value;
+        let (format_byte, secret_der) = secret_bytes.expose_secret()
+            .split_first()
+            .ok_or(Rustls23ByteConError::MissingPrivateKeyFormat)?;
+        match format_byte {
+            0u8 => Ok(Self::Pkcs1(PrivatePkcs1KeyDer::from(secret_der.to_vec()))),
+            1u8 => Ok(Self::Sec1(PrivateSec1KeyDer::from(secret_der.to_vec()))),
+            2u8 => Ok(Self::Pkcs8(PrivatePkcs8KeyDer::from(secret_der.to_vec()))),
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
+    }
+}
+
+impl ByteConverter for ServerName<'static> {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            Self::DnsName(dns_name) => {
+                0u8.append_to_bytes(bytes)?;
+                String::from(dns_name.as_ref()).append_to_bytes(bytes)?;
+            },
+            Self::IpAddress(ip_address) => {
+                match IpAddr::from(*ip_address) {
+                    IpAddr::V4(ipv4_address) => {
+                        1u8.append_to_bytes(bytes)?;
+                        ipv4_address.octets().append_to_bytes(bytes)?;
+                    },
+                    IpAddr::V6(ipv6_address) => {
+                        2u8.append_to_bytes(bytes)?;
+                        ipv6_address.octets().append_to_bytes(bytes)?;
+                    },
+                }
+            },
+            _ => return Err(Rustls23ByteConError::UnsupportedServerName.into()),
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        match enum_variant_byte {
+            0u8 => Ok(Self::try_from(String::extract_from_bytes(bytes, index)?)?),
+            1u8 => Ok(Self::from(IpAddr::from(<[u8; 4]>::extract_from_bytes(bytes, index)?))),
+            2u8 => Ok(Self::from(IpAddr::from(<[u8; 16]>::extract_from_bytes(bytes, index)?))),
+            _ => Err("Unexpected enum variant byte.".into()),
+        }
+    }
+}
+
+// the certificates and key that rebuild a ServerConfig, optionally requiring clients to present a certificate signed by one of the client auth roots
+#[derive(Debug)]
+pub struct ServerConfigDescription {
+    pub cert_chain: Vec<CertificateDer<'static>>,
+    pub private_key: PrivateKeyDer<'static>,
+    pub client_auth_roots: Option<Vec<CertificateDer<'static>>>,
+    pub alpn_protocols: Vec<Vec<u8>>,
+}
+
+impl ServerConfigDescription {
+    pub fn new(cert_chain: Vec<CertificateDer<'static>>, private_key: PrivateKeyDer<'static>) -> Self {
+        Self {
+            cert_chain,
+            private_key,
+            client_auth_roots: None,
+            alpn_protocols: Vec::new(),
+        }
+    }
+    pub fn build(&self) -> Result<Arc<ServerConfig>, Box<dyn Error + Send + Sync + 'static>> {
+        let provider = get_provider();
+        let builder = ServerConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()?;
+        let builder = match &self.client_auth_roots {
+            Some(client_auth_roots) => {
+                let client_verifier = WebPkiClientVerifier::builder_with_provider(get_root_cert_store(client_auth_roots)?, provider)
+                    .build()?;
+                builder.with_client_cert_verifier(client_verifier)
+            },
+            None => builder.with_no_client_auth(),
+        };
+        let mut server_config = builder.with_single_cert(self.cert_chain.clone(), self.private_key.clone_key())?;
+        server_config.alpn_protocols = self.alpn_protocols.clone();
+        Ok(Arc::new(server_config))
+    }
+}
+
+impl Clone for ServerConfigDescription {
+    fn clone(&self) -> Self {
+        Self {
+            cert_chain: self.cert_chain.clone(),
+            private_key: self.private_key.clone_key(),
+            client_auth_roots: self.client_auth_roots.clone(),
+            alpn_protocols: self.alpn_protocols.clone(),
+        }
+    }
+}
+
+// only the private key is encrypted, since the certificates and the rest of the description are public
+#[cfg(any(feature = "aes_gcm", feature = "chacha20poly1305"))]
+impl ContextualByteConverter<EncryptionKeyring> for ServerConfigDescription {
+    fn append_to_bytes_with(&self, context: &EncryptionKeyring, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.cert_chain.append_to_bytes(bytes)?;
+        self.private_key.append_to_bytes_with(context, bytes)?;
+        self.client_auth_roots.append_to_bytes(bytes)?;
+        self.alpn_protocols.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut EncryptionKeyring, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            cert_chain: Vec::<CertificateDer<'static>>::extract_from_bytes(bytes, index)?,
+            private_key: PrivateKeyDer::<'static>::extract_from_bytes_with(context, bytes, index)?,
+            client_auth_roots: Option::<Vec<CertificateDer<'static>>>::extract_from_bytes(bytes, index)?,
+            alpn_protocols: Vec::<Vec<u8>>::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+// the trusted roots that rebuild a ClientConfig, optionally presenting a client certificate chain and key to the server
+#[derive(Debug)]
+pub struct ClientConfigDescription {
+    pub root_certs: Vec<CertificateDer<'static>>,
+    pub client_auth: Option<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)>,
+    pub alpn_protocols: Vec<Vec<u8>>,
+}
+
+impl ClientConfigDescription {
+    pub fn new(root_certs: Vec<CertificateDer<'static>>) -> Self {
+        Self {
+            root_certs,
+            client_auth: None,
+            alpn_protocols: Vec::new(),
+        }
+    }
+    pub fn build(&self) -> Result<Arc<ClientConfig>, Box<dyn Error + Send + Sync + 'static>> {
+        let provider = get_provider();
+        let server_verifier = WebPkiServerVerifier::builder_with_provider(get_root_cert_store(&self.root_certs)?, provider.clone())
+            .build()?;
+        let builder = ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()?
+            .with_webpki_verifier(server_verifier);
+        let mut client_config = match &self.client_auth {
+            Some((cert_chain, private_key)) => builder.with_client_auth_cert(cert_chain.clone(), private_key.clone_key())?,
+            None => builder.with_no_client_auth(),
+        };
+        client_config.alpn_protocols = self.alpn_protocols.clone();
+        Ok(Arc::new(client_config))
+    }
+}
+
+impl Clone for ClientConfigDescription {
+    fn clone(&self) -> Self {
+        Self {
+            root_certs: self.root_certs.clone(),
+            client_auth: self.client_auth.as_ref()
+                .map(|(cert_chain, private_key)| (cert_chain.clone(), private_key.clone_key())),
+            alpn_protocols: self.alpn_protocols.clone(),
+        }
+    }
+}
+
+// the client auth is encoded like an Option, with only its private key encrypted
+#[cfg(any(feature = "aes_gcm", feature = "chacha20poly1305"))]
+impl ContextualByteConverter<EncryptionKeyring> for ClientConfigDescription {
+    fn append_to_bytes_with(&self, context: &EncryptionKeyring, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.root_certs.append_to_bytes(bytes)?;
+        match &self.client_auth {
+            Some((cert_chain, private_key)) => {
+                0u8.append_to_bytes(bytes)?;
+                cert_chain.append_to_bytes(bytes)?;
+                private_key.append_to_bytes_with(context, bytes)?;
+            },
+            None => {
+                1u8.append_to_bytes(bytes)?;
+            },
+        }
+        self.alpn_protocols.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    fn extract_from_bytes_with<'a, TBytes: AsRef<[u8]>>(context: &mut EncryptionKeyring, bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        let root_certs = Vec::<CertificateDer<'static>>::extract_from_bytes(bytes, index)?;
+        let enum_variant_byte = u8::extract_from_bytes(bytes, index)?;
+        let client_auth = match enum_variant_byte {
+            0u8 => Some((
+                Vec::<CertificateDer<'static>>::extract_from_bytes(bytes, index)?,
+                PrivateKeyDer::<'static>::extract_from_bytes_with(context, bytes, index)?,
+            )),
+            1u8 => None,
+            _ => return Err("Unexpected enum variant byte.".into()),
+        };
+        Ok(Self {
+            root_certs,
+            client_auth,
+            alpn_protocols: Vec::<Vec<u8>>::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+fn get_root_cert_store(root_certs: &[CertificateDer<'static>]) -> Result<Arc<RootCertStore>, Box<dyn Error + Send + Sync + 'static>> {
+    let mut root_cert_store = RootCertStore::empty();
+    for root_cert in root_certs {
+        root_cert_store.add(root_cert.clone())?;
+    }
+    Ok(Arc::new(root_cert_store))
+}
+
+// the ring provider is used explicitly so that building does not depend on a process-wide default provider being installed
+fn get_provider() -> Arc<CryptoProvider> {
+    Arc::new(rustls23::crypto::ring::default_provider())
+}
+
+#[derive(thiserror::Error, Debug)]
+enum Rustls23ByteConError {
+    #[cfg(any(feature = "aes_gcm", feature = "chacha20poly1305"))]
+    #[error("Private key format is not supported.")]
+    UnsupportedPrivateKeyFormat,
+    #[cfg(any(feature = "aes_gcm", feature = "chacha20poly1305"))]
+    #[error("Private key is missing its format byte.")]
+    MissingPrivateKeyFormat,
+    #[error("Server name kind is not supported.")]
+    UnsupportedServerName,
+}
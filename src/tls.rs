@@ -0,0 +1,216 @@
+use std::{error::Error, marker::PhantomData, net::SocketAddr, time::Duration};
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use tokio::{io::{AsyncRead, AsyncWrite}, net::{TcpListener, TcpStream, ToSocketAddrs}};
+use tokio_rustls26::{client, server, Accept, TlsAcceptor, TlsConnector, TlsStream};
+use crate::{rustls23::{ClientConfigDescription, ServerConfigDescription}, tokio::{read_to_byte_converter, write_encoded_bytes, write_from_byte_converter, write_many_from_byte_converters}, ByteConverter, ByteStreamReaderAsync, ByteStreamWriterAsync};
+
+impl<TStream: AsyncWrite + AsyncRead + Unpin> ByteStreamReaderAsync for TlsStream<TStream> {
+    #[inline(always)]
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(self).await
+    }
+}
+
+impl<TStream: AsyncWrite + AsyncRead + Unpin> ByteStreamWriterAsync for TlsStream<TStream> {
+    #[inline(always)]
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(self, byte_converter).await
+    }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+    #[inline(always)]
+    async fn write_encoded_bytes(&mut self, encoded_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_encoded_bytes(self, encoded_bytes).await
+    }
+}
+
+impl<TStream: AsyncWrite + AsyncRead + Unpin> ByteStreamReaderAsync for client::TlsStream<TStream> {
+    #[inline(always)]
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(self).await
+    }
+}
+
+impl<TStream: AsyncWrite + AsyncRead + Unpin> ByteStreamWriterAsync for client::TlsStream<TStream> {
+    #[inline(always)]
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(self, byte_converter).await
+    }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+    #[inline(always)]
+    async fn write_encoded_bytes(&mut self, encoded_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_encoded_bytes(self, encoded_bytes).await
+    }
+}
+
+impl<TStream: AsyncWrite + AsyncRead + Unpin> ByteStreamReaderAsync for server::TlsStream<TStream> {
+    #[inline(always)]
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(self).await
+    }
+}
+
+impl<TStream: AsyncWrite + AsyncRead + Unpin> ByteStreamWriterAsync for server::TlsStream<TStream> {
+    #[inline(always)]
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(self, byte_converter).await
+    }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+    #[inline(always)]
+    async fn write_encoded_bytes(&mut self, encoded_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_encoded_bytes(self, encoded_bytes).await
+    }
+}
+
+pub const DEFAULT_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+// accepts TLS connections whose clients send TRequest messages and receive TResponse messages
+pub struct ByteConTlsServer<TRequest, TResponse> {
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+    handshake_timeout: Duration,
+    phantom_messages: PhantomData<fn(TRequest) -> TResponse>,
+}
+
+impl<TRequest: ByteConverter, TResponse: ByteConverter> ByteConTlsServer<TRequest, TResponse> {
+    pub async fn bind(address: impl ToSocketAddrs, cert_chain: Vec<CertificateDer<'static>>, private_key: PrivateKeyDer<'static>) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        Self::bind_with_description(address, &ServerConfigDescription::new(cert_chain, private_key)).await
+    }
+    pub async fn bind_with_description(address: impl ToSocketAddrs, server_config_description: &ServerConfigDescription) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let acceptor = TlsAcceptor::from(server_config_description.build()?);
+        let listener = TcpListener::bind(address)
+            .await?;
+        Ok(Self {
+            listener,
+            acceptor,
+            handshake_timeout: DEFAULT_HANDSHAKE_TIMEOUT,
+            phantom_messages: PhantomData,
+        })
+    }
+    pub fn with_handshake_timeout(mut self, handshake_timeout: Duration) -> Self {
+        self.handshake_timeout = handshake_timeout;
+        self
+    }
+    pub fn local_addr(&self) -> Result<SocketAddr, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(self.listener.local_addr()?)
+    }
+    // only waits for the TCP connection, so a slow client cannot hold up later accepts while its handshake is completed separately
+    pub async fn accept(&self) -> Result<ByteConTlsServerHandshake<TRequest, TResponse>, Box<dyn Error + Send + Sync + 'static>> {
+        let (tcp_stream, peer_address) = self.listener.accept()
+            .await?;
+        Ok(ByteConTlsServerHandshake {
+            accept: self.acceptor.accept(tcp_stream),
+            peer_address,
+            handshake_timeout: self.handshake_timeout,
+            phantom_messages: PhantomData,
+        })
+    }
+}
+
+// the TLS handshake of an accepted TCP connection, usually completed on its own task
+pub struct ByteConTlsServerHandshake<TRequest, TResponse> {
+    accept: Accept<TcpStream>,
+    peer_address: SocketAddr,
+    handshake_timeout: Duration,
+    phantom_messages: PhantomData<fn(TRequest) -> TResponse>,
+}
+
+impl<TRequest: ByteConverter, TResponse: ByteConverter> ByteConTlsServerHandshake<TRequest, TResponse> {
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.peer_address
+    }
+    // fails when the client does not finish the handshake within the handshake timeout of the server
+    pub async fn complete(self) -> Result<ByteConTlsServerConnection<TRequest, TResponse>, Box<dyn Error + Send + Sync + 'static>> {
+        let stream = tokio::time::timeout(self.handshake_timeout, self.accept)
+            .await
+            .map_err(|_| TlsByteConError::HandshakeTimedOut {
+                peer_address: self.peer_address,
+            })??;
+        Ok(ByteConTlsServerConnection {
+            stream,
+            peer_address: self.peer_address,
+            phantom_messages: PhantomData,
+        })
+    }
+}
+
+pub struct ByteConTlsServerConnection<TRequest, TResponse> {
+    stream: server::TlsStream<TcpStream>,
+    peer_address: SocketAddr,
+    phantom_messages: PhantomData<fn(TRequest) -> TResponse>,
+}
+
+impl<TRequest: ByteConverter, TResponse: ByteConverter> ByteConTlsServerConnection<TRequest, TResponse> {
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.peer_address
+    }
+    pub async fn receive(&mut self) -> Result<TRequest, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(&mut self.stream).await
+    }
+    pub async fn send(&mut self, response: &TResponse) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(&mut self.stream, response).await
+    }
+    pub fn get_ref(&self) -> &server::TlsStream<TcpStream> {
+        &self.stream
+    }
+    pub fn into_inner(self) -> server::TlsStream<TcpStream> {
+        self.stream
+    }
+}
+
+// a TLS connection to a server that receives TRequest messages and sends TResponse messages
+pub struct ByteConTlsClient<TRequest, TResponse> {
+    stream: client::TlsStream<TcpStream>,
+    phantom_messages: PhantomData<fn(TRequest) -> TResponse>,
+}
+
+impl<TRequest: ByteConverter, TResponse: ByteConverter> ByteConTlsClient<TRequest, TResponse> {
+    pub async fn connect(address: impl ToSocketAddrs, server_name: ServerName<'static>, root_certs: Vec<CertificateDer<'static>>) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        Self::connect_with_description(address, server_name, &ClientConfigDescription::new(root_certs)).await
+    }
+    pub async fn connect_with_description(address: impl ToSocketAddrs, server_name: ServerName<'static>, client_config_description: &ClientConfigDescription) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let connector = TlsConnector::from(client_config_description.build()?);
+        let tcp_stream = TcpStream::connect(address)
+            .await?;
+        let stream = connector.connect(server_name, tcp_stream)
+            .await?;
+        Ok(Self {
+            stream,
+            phantom_messages: PhantomData,
+        })
+    }
+    pub async fn send(&mut self, request: &TRequest) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(&mut self.stream, request).await
+    }
+    pub async fn receive(&mut self) -> Result<TResponse, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(&mut self.stream).await
+    }
+    pub async fn request(&mut self, request: &TRequest) -> Result<TResponse, Box<dyn Error + Send + Sync + 'static>> {
+        self.send(request)
+            .await?;
+        self.receive()
+            .await
+    }
+    pub fn get_ref(&self) -> &client::TlsStream<TcpStream> {
+        &self.stream
+    }
+    pub fn into_inner(self) -> client::TlsStream<TcpStream> {
+        self.stream
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+enum TlsByteConError {
+    #[error("TLS handshake with {peer_address} did not complete before the handshake timeout.")]
+    HandshakeTimedOut {
+        peer_address: SocketAddr,
+    },
+}
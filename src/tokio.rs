@@ -1,10 +1,10 @@
-use std::error::Error;
-use tokio::{io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt}, net::TcpStream};
+use std::{error::Error, io::IoSlice};
+use tokio::{io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader, ReadHalf, WriteHalf}, net::{tcp::{OwnedReadHalf, OwnedWriteHalf}, TcpStream}};
 use tokio_rustls::TlsStream;
 use crate::{ByteConverter, ByteStreamReaderAsync, ByteStreamWriterAsync};
 
 #[inline(always)]
-async fn read_to_byte_converter<TOutput: ByteConverter, TStream: AsyncWrite + AsyncRead + Unpin>(stream: &mut TStream) -> Result<TOutput, Box<dyn Error + Send + Sync + 'static>> {
+pub(crate) async fn read_to_byte_converter<TOutput: ByteConverter, TStream: AsyncRead + Unpin>(stream: &mut TStream) -> Result<TOutput, Box<dyn Error + Send + Sync + 'static>> {
     let usize_length = {
         // byte
         let mut single_byte_chunk = [0u8; 1];
@@ -59,37 +59,98 @@ async fn read_to_byte_converter<TOutput: ByteConverter, TStream: AsyncWrite + As
         }
     };
 
+    // read exactly the expected bytes in bounded chunks so that neither the next frame nor a corrupted length is read into memory
     let mut bytes = Vec::new();
     let mut chunk = [0u8; 4096];
     while bytes.len() < expected_bytes_length {
-        let read_bytes_length_result = stream.read(&mut chunk)
+        let chunk_length = (expected_bytes_length - bytes.len()).min(chunk.len());
+        let read_result = stream.read_exact(&mut chunk[..chunk_length])
             .await;
-        if let Err(error) = read_bytes_length_result {
+        if let Err(error) = read_result {
             let result: Result<TOutput, Box<dyn Error + Send + Sync + 'static>> = Err(Box::new(error));
             return result;
         }
-
-        let read_bytes_length = read_bytes_length_result.unwrap();
-
-        if read_bytes_length != 0 {
-            bytes.extend_from_slice(&chunk[..read_bytes_length]);
-        }
+        bytes.extend_from_slice(&chunk[..chunk_length]);
     }
 
     TOutput::deserialize_from_bytes(&bytes)
 }
 
+// the usize length is written in front of the bytes in place so that any number of frames can go out in a single write_all
+#[inline(always)]
+pub(crate) fn append_stream_frame(bytes: &mut Vec<u8>, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let length_index = bytes.len();
+    0usize.append_to_bytes(bytes)?;
+    let payload_index = bytes.len();
+    byte_converter.append_to_bytes(bytes)?;
+
+    // the usize is a byte holding its length followed by that many little endian bytes, which are the leading bytes of the u64
+    let payload_length = (bytes.len() - payload_index) as u64;
+    bytes[length_index + 1..payload_index].copy_from_slice(&payload_length.to_le_bytes()[..payload_index - length_index - 1]);
+    Ok(())
+}
+
+#[inline(always)]
+pub(crate) async fn write_from_byte_converter<TStream: AsyncWrite + Unpin>(stream: &mut TStream, byte_converter: &impl crate::ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    write_many_from_byte_converters(stream, std::slice::from_ref(byte_converter)).await
+}
+
 #[inline(always)]
-async fn write_from_byte_converter<TStream: AsyncWrite + AsyncRead + Unpin>(stream: &mut TStream, byte_converter: &impl crate::ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
-    let byte_converter_bytes = byte_converter.to_vec_bytes()?;
-    let byte_converter_bytes_length_bytes = byte_converter_bytes.len().to_vec_bytes()?;
-    stream.write(&byte_converter_bytes_length_bytes)
+pub(crate) async fn write_many_from_byte_converters<TStream: AsyncWrite + Unpin, T: ByteConverter>(stream: &mut TStream, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let mut bytes = Vec::new();
+    for byte_converter in byte_converters {
+        append_stream_frame(&mut bytes, byte_converter)?;
+    }
+    stream.write_all(&bytes)
         .await?;
-    stream.write(&byte_converter_bytes)
+
+    // TLS streams hold written bytes until they are flushed
+    stream.flush()
         .await?;
     Ok(())
 }
 
+// the length is written ahead of the encoded bytes with a vectored write so that they are not copied into a frame first
+#[inline(always)]
+pub(crate) async fn write_encoded_bytes<TStream: AsyncWrite + Unpin>(stream: &mut TStream, encoded_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let mut length_bytes = Vec::with_capacity(9);
+    encoded_bytes.len().append_to_bytes(&mut length_bytes)?;
+    let mut written_length = 0;
+    while written_length < length_bytes.len() + encoded_bytes.len() {
+        let slices = if written_length < length_bytes.len() {
+            [IoSlice::new(&length_bytes[written_length..]), IoSlice::new(encoded_bytes)]
+        }
+        else {
+            [IoSlice::new(&encoded_bytes[written_length - length_bytes.len()..]), IoSlice::new(&[])]
+        };
+        let slice_written_length = stream.write_vectored(&slices)
+            .await?;
+        if slice_written_length == 0 {
+            return Err(std::io::Error::from(std::io::ErrorKind::WriteZero).into());
+        }
+        written_length += slice_written_length;
+    }
+
+    // TLS streams hold written bytes until they are flushed
+    stream.flush()
+        .await?;
+    Ok(())
+}
+
+// true when the bytes start with a whole frame, so that reading it will not wait on the stream
+fn is_stream_frame_buffered(bytes: &[u8]) -> bool {
+    let Some(usize_length) = bytes.first().map(|usize_length| *usize_length as usize) else {
+        return false;
+    };
+    if !matches!(usize_length, 4 | 8) || bytes.len() < 1 + usize_length {
+        return false;
+    }
+    let mut length_bytes = [0u8; 8];
+    length_bytes[..usize_length].copy_from_slice(&bytes[1..1 + usize_length]);
+    let expected_bytes_length = u64::from_le_bytes(length_bytes);
+    (bytes.len() - 1 - usize_length) as u64 >= expected_bytes_length
+}
+
 impl ByteStreamReaderAsync for TcpStream {
     #[inline(always)]
     async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
@@ -102,6 +163,14 @@ impl ByteStreamWriterAsync for TcpStream {
     async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         write_from_byte_converter(self, byte_converter).await
     }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+    #[inline(always)]
+    async fn write_encoded_bytes(&mut self, encoded_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_encoded_bytes(self, encoded_bytes).await
+    }
 }
 
 impl<TStream: AsyncWrite + AsyncRead + Unpin> ByteStreamReaderAsync for TlsStream<TStream> {
@@ -116,6 +185,92 @@ impl<TStream: AsyncWrite + AsyncRead + Unpin> ByteStreamWriterAsync for TlsStrea
     async fn write_from_byte_converter(&mut self, byte_converter: &impl crate::ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         write_from_byte_converter(self, byte_converter).await
     }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+    #[inline(always)]
+    async fn write_encoded_bytes(&mut self, encoded_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_encoded_bytes(self, encoded_bytes).await
+    }
+}
+
+impl ByteStreamReaderAsync for OwnedReadHalf {
+    #[inline(always)]
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(self).await
+    }
+}
+
+impl ByteStreamWriterAsync for OwnedWriteHalf {
+    #[inline(always)]
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(self, byte_converter).await
+    }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+    #[inline(always)]
+    async fn write_encoded_bytes(&mut self, encoded_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_encoded_bytes(self, encoded_bytes).await
+    }
+}
+
+impl<TStream: AsyncRead> ByteStreamReaderAsync for ReadHalf<TStream> {
+    #[inline(always)]
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(self).await
+    }
+}
+
+impl<TStream: AsyncWrite> ByteStreamWriterAsync for WriteHalf<TStream> {
+    #[inline(always)]
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(self, byte_converter).await
+    }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+    #[inline(always)]
+    async fn write_encoded_bytes(&mut self, encoded_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_encoded_bytes(self, encoded_bytes).await
+    }
+}
+
+impl<TStream: AsyncRead + Unpin> ByteStreamReaderAsync for BufReader<TStream> {
+    #[inline(always)]
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(self).await
+    }
+    // frames after the first are only read while they are already whole within the buffer
+    #[inline(always)]
+    async fn read_many<T: ByteConverter>(&mut self, max: usize) -> Result<Vec<T>, Box<dyn Error + Send + Sync + 'static>> {
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+        let mut values = vec![read_to_byte_converter(self).await?];
+        while values.len() < max && is_stream_frame_buffered(self.buffer()) {
+            values.push(read_to_byte_converter(self).await?);
+        }
+        Ok(values)
+    }
+}
+
+impl<TStream: AsyncRead + AsyncWrite + Unpin> ByteStreamWriterAsync for BufReader<TStream> {
+    #[inline(always)]
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(self, byte_converter).await
+    }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+    #[inline(always)]
+    async fn write_encoded_bytes(&mut self, encoded_bytes: &[u8]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_encoded_bytes(self, encoded_bytes).await
+    }
 }
 
 impl ByteStreamReaderAsync for tokio::sync::mpsc::Receiver<Vec<u8>> {
@@ -126,6 +281,20 @@ impl ByteStreamReaderAsync for tokio::sync::mpsc::Receiver<Vec<u8>> {
             .ok_or(TokioByteConError::OptionVariantNoneReceivedFromReceiver)?;
         T::deserialize_from_bytes(&bytes)
     }
+    #[inline(always)]
+    async fn read_many<T: ByteConverter>(&mut self, max: usize) -> Result<Vec<T>, Box<dyn Error + Send + Sync + 'static>> {
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+        let mut values = vec![self.read_to_byte_converter().await?];
+        while values.len() < max {
+            let Ok(bytes) = self.try_recv() else {
+                break;
+            };
+            values.push(T::deserialize_from_bytes(&bytes)?);
+        }
+        Ok(values)
+    }
 }
 
 impl ByteStreamWriterAsync for tokio::sync::mpsc::Sender<Vec<u8>> {
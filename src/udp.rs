@@ -0,0 +1,340 @@
+use std::{collections::HashMap, error::Error, hash::{BuildHasher, RandomState}, net::SocketAddr, sync::atomic::{AtomicU32, Ordering}, time::{Duration, Instant, SystemTime}};
+use tokio::net::{ToSocketAddrs, UdpSocket};
+use crate::ByteConverter;
+
+// session id (u32), sequence (u32), fragment index (u16) and fragment count (u16)
+const FRAGMENT_HEADER_LENGTH: usize = 12;
+
+// the number of sequences behind the highest completed sequence of a peer that are still accepted, where older datagrams are dropped as duplicates
+const COMPLETED_SEQUENCE_WINDOW_LENGTH: usize = 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UdpSettings {
+    // the largest datagram sent, including the fragment header, which should stay below the path MTU
+    pub max_datagram_size: usize,
+    // partially received messages are dropped once their first fragment is older than this
+    pub reassembly_timeout: Duration,
+    pub max_message_size: usize,
+    // the completed sequences of a peer are forgotten once nothing has been received from it for this long
+    pub peer_timeout: Duration,
+    // starting another partially received message beyond either limit evicts the oldest one of the peer, or of all peers
+    pub max_pending_messages_per_peer: usize,
+    pub max_pending_messages: usize,
+}
+
+impl Default for UdpSettings {
+    fn default() -> Self {
+        Self {
+            max_datagram_size: 1200,
+            reassembly_timeout: Duration::from_secs(5),
+            max_message_size: 16 * 1024 * 1024,
+            peer_timeout: Duration::from_secs(60),
+            max_pending_messages_per_peer: 16,
+            max_pending_messages: 256,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+enum UdpByteConError {
+    #[error("Maximum datagram size {max_datagram_size} leaves no room for a payload after the {FRAGMENT_HEADER_LENGTH} byte fragment header.")]
+    DatagramSizeTooSmall {
+        max_datagram_size: usize,
+    },
+    #[error("Message of {message_length} bytes exceeds the maximum message size of {max_message_size} bytes.")]
+    MessageTooLarge {
+        message_length: usize,
+        max_message_size: usize,
+    },
+    #[error("Message of {message_length} bytes requires more than {} fragments.", u16::MAX)]
+    TooManyFragments {
+        message_length: usize,
+    },
+}
+
+struct FragmentHeader {
+    session_id: u32,
+    sequence: u32,
+    fragment_index: u16,
+    fragment_count: u16,
+}
+
+impl ByteConverter for FragmentHeader {
+    #[inline(always)]
+    fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.session_id.append_to_bytes(bytes)?;
+        self.sequence.append_to_bytes(bytes)?;
+        self.fragment_index.append_to_bytes(bytes)?;
+        self.fragment_count.append_to_bytes(bytes)?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+        Ok(Self {
+            session_id: u32::extract_from_bytes(bytes, index)?,
+            sequence: u32::extract_from_bytes(bytes, index)?,
+            fragment_index: u16::extract_from_bytes(bytes, index)?,
+            fragment_count: u16::extract_from_bytes(bytes, index)?,
+        })
+    }
+}
+
+struct PartialMessage {
+    fragments: Vec<Option<Vec<u8>>>,
+    received_count: usize,
+    received_length: usize,
+    first_received_at: Instant,
+}
+
+// the sequences that completed within the window behind the highest completed sequence of a peer's current session
+struct PeerState {
+    session_id: u32,
+    highest_sequence: u32,
+    completed_window: [u64; COMPLETED_SEQUENCE_WINDOW_LENGTH / 64],
+    last_received_at: Instant,
+}
+
+impl PeerState {
+    fn new(session_id: u32, sequence: u32) -> Self {
+        let mut peer_state = Self {
+            session_id,
+            highest_sequence: sequence,
+            completed_window: [0; COMPLETED_SEQUENCE_WINDOW_LENGTH / 64],
+            last_received_at: Instant::now(),
+        };
+        peer_state.set_completed(sequence);
+        peer_state
+    }
+    fn is_completed_or_stale(&self, sequence: u32) -> bool {
+        // sequences wrap, so anything less than half the sequence space ahead of the highest is newer
+        let distance = self.highest_sequence.wrapping_sub(sequence);
+        if (distance as i32) < 0 {
+            return false;
+        }
+        if distance as usize >= COMPLETED_SEQUENCE_WINDOW_LENGTH {
+            return true;
+        }
+        let slot = sequence as usize % COMPLETED_SEQUENCE_WINDOW_LENGTH;
+        self.completed_window[slot / 64] & (1 << (slot % 64)) != 0
+    }
+    fn complete(&mut self, sequence: u32) {
+        let advance = sequence.wrapping_sub(self.highest_sequence);
+        if advance as i32 > 0 {
+            // the slots that the window moves over belong to sequences that have not completed yet
+            for skipped_sequence in 1..=(advance as usize).min(COMPLETED_SEQUENCE_WINDOW_LENGTH) {
+                let slot = self.highest_sequence.wrapping_add(skipped_sequence as u32) as usize % COMPLETED_SEQUENCE_WINDOW_LENGTH;
+                self.completed_window[slot / 64] &= !(1 << (slot % 64));
+            }
+            self.highest_sequence = sequence;
+        }
+        self.set_completed(sequence);
+    }
+    fn set_completed(&mut self, sequence: u32) {
+        let slot = sequence as usize % COMPLETED_SEQUENCE_WINDOW_LENGTH;
+        self.completed_window[slot / 64] |= 1 << (slot % 64);
+    }
+}
+
+// a new session id per socket lets peers tell a restarted sender, whose sequences start over, apart from duplicated datagrams
+fn get_random_session_id() -> u32 {
+    RandomState::new().hash_one(SystemTime::now()) as u32
+}
+
+// sends ByteConverter values as datagrams, splitting messages that do not fit into a single datagram into fragments
+// fragments are reassembled per peer, session and sequence, where incomplete messages expire, duplicated datagrams are dropped and idle peers are forgotten
+pub struct ByteConUdpSocket {
+    socket: UdpSocket,
+    settings: UdpSettings,
+    session_id: u32,
+    next_sequence: AtomicU32,
+    partial_messages: HashMap<(SocketAddr, u32, u32), PartialMessage>,
+    peer_state_per_address: HashMap<SocketAddr, PeerState>,
+}
+
+impl ByteConUdpSocket {
+    pub async fn bind<TAddress: ToSocketAddrs>(address: TAddress, settings: UdpSettings) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let socket = UdpSocket::bind(address)
+            .await?;
+        Self::from_socket(socket, settings)
+    }
+    pub fn from_socket(socket: UdpSocket, settings: UdpSettings) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        if settings.max_datagram_size <= FRAGMENT_HEADER_LENGTH {
+            return Err(UdpByteConError::DatagramSizeTooSmall {
+                max_datagram_size: settings.max_datagram_size,
+            }.into());
+        }
+        Ok(Self {
+            socket,
+            settings,
+            session_id: get_random_session_id(),
+            next_sequence: AtomicU32::new(0),
+            partial_messages: HashMap::new(),
+            peer_state_per_address: HashMap::new(),
+        })
+    }
+    pub fn local_addr(&self) -> Result<SocketAddr, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(self.socket.local_addr()?)
+    }
+    pub fn get_ref(&self) -> &UdpSocket {
+        &self.socket
+    }
+    pub fn into_inner(self) -> UdpSocket {
+        self.socket
+    }
+    // the number of messages still waiting for some of their fragments
+    pub fn pending_message_count(&self) -> usize {
+        self.partial_messages.len()
+    }
+    // the number of peers whose completed sequences are remembered
+    pub fn peer_count(&self) -> usize {
+        self.peer_state_per_address.len()
+    }
+    pub async fn send_to<T: ByteConverter, TAddress: ToSocketAddrs>(&self, value: &T, address: TAddress) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let message_bytes = value.to_vec_bytes()?;
+        if message_bytes.len() > self.settings.max_message_size {
+            return Err(UdpByteConError::MessageTooLarge {
+                message_length: message_bytes.len(),
+                max_message_size: self.settings.max_message_size,
+            }.into());
+        }
+        let fragment_payload_length = self.settings.max_datagram_size - FRAGMENT_HEADER_LENGTH;
+
+        // an empty message is still sent as a single fragment
+        let fragment_count = message_bytes.len().div_ceil(fragment_payload_length).max(1);
+        let fragment_count = u16::try_from(fragment_count)
+            .map_err(|_| UdpByteConError::TooManyFragments {
+                message_length: message_bytes.len(),
+            })?;
+        let address = tokio::net::lookup_host(address)
+            .await?
+            .next()
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::AddrNotAvailable, "Address did not resolve to any socket address."))?;
+        let sequence = self.next_sequence.fetch_add(1, Ordering::Relaxed);
+        let mut datagram = Vec::with_capacity(self.settings.max_datagram_size);
+        for fragment_index in 0..fragment_count {
+            let start_index = fragment_index as usize * fragment_payload_length;
+            let end_index = (start_index + fragment_payload_length).min(message_bytes.len());
+            datagram.clear();
+            FragmentHeader {
+                session_id: self.session_id,
+                sequence,
+                fragment_index,
+                fragment_count,
+            }.append_to_bytes(&mut datagram)?;
+            datagram.extend_from_slice(&message_bytes[start_index..end_index]);
+            self.socket.send_to(&datagram, address)
+                .await?;
+        }
+        Ok(())
+    }
+    pub async fn receive_from<T: ByteConverter>(&mut self) -> Result<(T, SocketAddr), Box<dyn Error + Send + Sync + 'static>> {
+        let (message_bytes, address) = self.receive_bytes_from()
+            .await?;
+        Ok((T::deserialize_from_bytes(&message_bytes)?, address))
+    }
+    // waits until a whole message has been reassembled, dropping malformed, duplicated and expired datagrams along the way
+    pub async fn receive_bytes_from(&mut self) -> Result<(Vec<u8>, SocketAddr), Box<dyn Error + Send + Sync + 'static>> {
+        // one extra byte detects datagrams that are larger than any this socket would send
+        let mut datagram = vec![0u8; self.settings.max_datagram_size + 1];
+        loop {
+            let (datagram_length, address) = self.socket.recv_from(&mut datagram)
+                .await?;
+            self.remove_expired_state();
+            if datagram_length > self.settings.max_datagram_size {
+                continue;
+            }
+            if let Some(message_bytes) = self.accept_datagram(&datagram[..datagram_length], address) {
+                return Ok((message_bytes, address));
+            }
+        }
+    }
+    fn remove_expired_state(&mut self) {
+        let reassembly_timeout = self.settings.reassembly_timeout;
+        self.partial_messages.retain(|_, partial_message| partial_message.first_received_at.elapsed() < reassembly_timeout);
+        let peer_timeout = self.settings.peer_timeout;
+        self.peer_state_per_address.retain(|_, peer_state| peer_state.last_received_at.elapsed() < peer_timeout);
+    }
+    fn evict_partial_message_if_full(&mut self, address: SocketAddr) {
+        let peer_pending_message_count = self.partial_messages.keys()
+            .filter(|(pending_address, _, _)| *pending_address == address)
+            .count();
+        let is_peer_full = peer_pending_message_count >= self.settings.max_pending_messages_per_peer;
+        if !is_peer_full && self.partial_messages.len() < self.settings.max_pending_messages {
+            return;
+        }
+        let oldest_key = self.partial_messages.iter()
+            .filter(|((pending_address, _, _), _)| !is_peer_full || *pending_address == address)
+            .min_by_key(|(_, partial_message)| partial_message.first_received_at)
+            .map(|(key, _)| *key);
+        if let Some(oldest_key) = oldest_key {
+            self.partial_messages.remove(&oldest_key);
+        }
+    }
+    fn accept_datagram(&mut self, datagram: &[u8], address: SocketAddr) -> Option<Vec<u8>> {
+        let mut index = 0;
+        let header = FragmentHeader::extract_from_bytes(&datagram, &mut index)
+            .ok()?;
+        if header.fragment_count == 0 || header.fragment_index >= header.fragment_count {
+            return None;
+        }
+        let fragment_payload_length = self.settings.max_datagram_size - FRAGMENT_HEADER_LENGTH;
+        if (header.fragment_count as usize - 1) * fragment_payload_length >= self.settings.max_message_size.max(1) {
+            return None;
+        }
+        if let Some(peer_state) = self.peer_state_per_address.get_mut(&address) {
+            if peer_state.session_id == header.session_id {
+                peer_state.last_received_at = Instant::now();
+                if peer_state.is_completed_or_stale(header.sequence) {
+                    return None;
+                }
+            }
+        }
+        let payload = &datagram[index..];
+
+        let key = (address, header.session_id, header.sequence);
+        if header.fragment_count > 1 && !self.partial_messages.contains_key(&key) {
+            self.evict_partial_message_if_full(address);
+        }
+        let partial_message = self.partial_messages.entry(key)
+            .or_insert_with(|| PartialMessage {
+                fragments: vec![None; header.fragment_count as usize],
+                received_count: 0,
+                received_length: 0,
+                first_received_at: Instant::now(),
+            });
+        if partial_message.fragments.len() != header.fragment_count as usize {
+            return None;
+        }
+        let fragment = &mut partial_message.fragments[header.fragment_index as usize];
+        if fragment.is_some() {
+            return None;
+        }
+        *fragment = Some(payload.to_vec());
+        partial_message.received_count += 1;
+        partial_message.received_length += payload.len();
+        if partial_message.received_length > self.settings.max_message_size {
+            self.partial_messages.remove(&key);
+            return None;
+        }
+        if partial_message.received_count < partial_message.fragments.len() {
+            return None;
+        }
+
+        let partial_message = self.partial_messages.remove(&key)?;
+        let mut message_bytes = Vec::with_capacity(partial_message.received_length);
+        for fragment in partial_message.fragments.into_iter().flatten() {
+            message_bytes.extend(fragment);
+        }
+
+        // a message completing in another session means that the peer restarted, so its previous sequences no longer apply
+        match self.peer_state_per_address.get_mut(&address) {
+            Some(peer_state) if peer_state.session_id == header.session_id => {
+                peer_state.complete(header.sequence);
+            },
+            _ => {
+                self.peer_state_per_address.insert(address, PeerState::new(header.session_id, header.sequence));
+            },
+        }
+        Some(message_bytes)
+    }
+}
@@ -0,0 +1,115 @@
+use std::error::Error;
+use futures_util::{stream::{SplitSink, SplitStream}, FutureExt, Sink, SinkExt, Stream, StreamExt};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::{tungstenite::{self, Message}, WebSocketStream};
+use crate::{ByteConverter, ByteStreamReaderAsync, ByteStreamWriterAsync};
+
+#[derive(thiserror::Error, Debug)]
+enum WebSocketByteConError {
+    #[error("WebSocket connection was closed.")]
+    ConnectionClosed,
+    #[error("Unexpected text message received where only binary messages are expected.")]
+    UnexpectedTextMessage,
+}
+
+// each value is carried by exactly one binary message, while pings, pongs and raw frames are skipped
+#[inline(always)]
+async fn read_to_byte_converter<TOutput: ByteConverter, TStream: Stream<Item = Result<Message, tungstenite::Error>> + Unpin>(stream: &mut TStream) -> Result<TOutput, Box<dyn Error + Send + Sync + 'static>> {
+    loop {
+        let message = stream.next()
+            .await
+            .ok_or(WebSocketByteConError::ConnectionClosed)??;
+        match message {
+            Message::Binary(bytes) => return TOutput::deserialize_from_bytes(&bytes),
+            Message::Text(_) => return Err(WebSocketByteConError::UnexpectedTextMessage.into()),
+            Message::Close(_) => return Err(WebSocketByteConError::ConnectionClosed.into()),
+            Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {},
+        }
+    }
+}
+
+// values after the first are only taken while their messages are ready without waiting on the connection
+#[inline(always)]
+async fn read_many<TOutput: ByteConverter, TStream: Stream<Item = Result<Message, tungstenite::Error>> + Unpin>(stream: &mut TStream, max: usize) -> Result<Vec<TOutput>, Box<dyn Error + Send + Sync + 'static>> {
+    if max == 0 {
+        return Ok(Vec::new());
+    }
+    let mut values = vec![read_to_byte_converter(stream).await?];
+    while values.len() < max {
+        // polling next once and dropping it does not lose a message
+        let Some(message) = stream.next().now_or_never() else {
+            break;
+        };
+        match message.ok_or(WebSocketByteConError::ConnectionClosed)?? {
+            Message::Binary(bytes) => values.push(TOutput::deserialize_from_bytes(&bytes)?),
+            Message::Text(_) => return Err(WebSocketByteConError::UnexpectedTextMessage.into()),
+            Message::Close(_) => return Err(WebSocketByteConError::ConnectionClosed.into()),
+            Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {},
+        }
+    }
+    Ok(values)
+}
+
+#[inline(always)]
+async fn write_from_byte_converter<TSink: Sink<Message, Error = tungstenite::Error> + Unpin>(sink: &mut TSink, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    let bytes = byte_converter.to_vec_bytes()?;
+    sink.send(Message::binary(bytes))
+        .await?;
+    Ok(())
+}
+
+// the messages are queued with feed and go out together with a single flush
+#[inline(always)]
+async fn write_many_from_byte_converters<TSink: Sink<Message, Error = tungstenite::Error> + Unpin, T: ByteConverter>(sink: &mut TSink, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    for byte_converter in byte_converters {
+        sink.feed(Message::binary(byte_converter.to_vec_bytes()?))
+            .await?;
+    }
+    sink.flush()
+        .await?;
+    Ok(())
+}
+
+impl<TStream: AsyncRead + AsyncWrite + Unpin> ByteStreamReaderAsync for WebSocketStream<TStream> {
+    #[inline(always)]
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(self).await
+    }
+    #[inline(always)]
+    async fn read_many<T: ByteConverter>(&mut self, max: usize) -> Result<Vec<T>, Box<dyn Error + Send + Sync + 'static>> {
+        read_many(self, max).await
+    }
+}
+
+impl<TStream: AsyncRead + AsyncWrite + Unpin> ByteStreamWriterAsync for WebSocketStream<TStream> {
+    #[inline(always)]
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(self, byte_converter).await
+    }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+}
+
+impl<TStream: AsyncRead + AsyncWrite + Unpin> ByteStreamReaderAsync for SplitStream<WebSocketStream<TStream>> {
+    #[inline(always)]
+    async fn read_to_byte_converter<T: ByteConverter>(&mut self) -> Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        read_to_byte_converter(self).await
+    }
+    #[inline(always)]
+    async fn read_many<T: ByteConverter>(&mut self, max: usize) -> Result<Vec<T>, Box<dyn Error + Send + Sync + 'static>> {
+        read_many(self, max).await
+    }
+}
+
+impl<TStream: AsyncRead + AsyncWrite + Unpin> ByteStreamWriterAsync for SplitSink<WebSocketStream<TStream>, Message> {
+    #[inline(always)]
+    async fn write_from_byte_converter(&mut self, byte_converter: &impl ByteConverter) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_from_byte_converter(self, byte_converter).await
+    }
+    #[inline(always)]
+    async fn write_many<T: ByteConverter>(&mut self, byte_converters: &[T]) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        write_many_from_byte_converters(self, byte_converters).await
+    }
+}
@@ -1,5 +1,17 @@
 #[cfg(test)]
 mod all_features_tests {
+    #[cfg(feature = "aes_gcm")]
+    #[test]
+    fn test_aes_gcm_build() {
+        // ensure that feature builds
+    }
+
+    #[cfg(feature = "avian2d")]
+    #[test]
+    fn test_avian2d_build() {
+        // ensure that feature builds
+    }
+
     #[cfg(feature = "avian3d")]
     #[test]
     fn test_avian3d_build() {
@@ -30,15 +42,63 @@ mod all_features_tests {
         // ensure that feature builds
     }
 
+    #[cfg(feature = "chacha20poly1305")]
+    #[test]
+    fn test_chacha20poly1305_build() {
+        // ensure that feature builds
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn test_checksum_build() {
+        // ensure that feature builds
+    }
+
+    #[cfg(feature = "deflate")]
+    #[test]
+    fn test_deflate_build() {
+        // ensure that feature builds
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn test_lz4_build() {
+        // ensure that feature builds
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_build() {
+        // ensure that feature builds
+    }
+
     #[cfg(feature = "rustls")]
     #[test]
     fn test_rustls_build() {
         // ensure that feature builds
     }
 
+    #[cfg(feature = "rustls23")]
+    #[test]
+    fn test_rustls23_build() {
+        // ensure that feature builds
+    }
+
+    #[cfg(feature = "tls")]
+    #[test]
+    fn test_tls_build() {
+        // ensure that feature builds
+    }
+
     #[cfg(feature = "tokio")]
     #[test]
     fn test_tokio_build() {
         // ensure that feature builds
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "websocket")]
+    #[test]
+    fn test_websocket_build() {
+        // ensure that feature builds
+    }
+}
@@ -0,0 +1,241 @@
+#[cfg(test)]
+mod avian2d_tests {
+    use std::time::Duration;
+
+    use avian2d::prelude::*;
+    use bevy::{app::{App, PluginGroup}, asset::{AssetPlugin, Assets}, scene::ScenePlugin, ecs::entity::Entity, math::{Dir2, Vec2}, prelude::{Mesh, MinimalPlugins, Transform, TransformPlugin}, time::TimeUpdateStrategy};
+    use bytecon::{avian2d::PhysicsSnapshot, ByteConverter};
+
+    fn create_physics_app() -> App {
+        let mut app = App::new();
+        app
+            .add_plugins((
+                MinimalPlugins,
+                TransformPlugin,
+                AssetPlugin::default(),
+                ScenePlugin,
+                PhysicsPlugins::default()
+                    .build()
+                    .disable::<ColliderHierarchyPlugin>(),
+            ))
+            .init_resource::<Assets<Mesh>>()
+            // exactly one fixed timestep per update
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / 64.0)));
+        app.finish();
+        app
+    }
+
+    fn get_positions(app: &mut App, entities: &[Entity]) -> Vec<(Vec2, Rotation)> {
+        entities.iter()
+            .map(|entity| {
+                let entity_ref = app.world().entity(*entity);
+                (entity_ref.get::<Position>().unwrap().0, *entity_ref.get::<Rotation>().unwrap())
+            })
+            .collect()
+    }
+
+    fn assert_collider_round_trip(collider: &Collider) {
+        let cloned_collider = collider.clone_via_bytes().unwrap();
+        assert_eq!(bincode::serialize(collider).unwrap(), bincode::serialize(&cloned_collider).unwrap());
+    }
+
+    #[test]
+    fn test_h8d2_body_components_round_trip() {
+        let position = Position(Vec2::new(1.5, -2.25));
+        assert_eq!(position, position.clone_via_bytes().unwrap());
+
+        let rotation = Rotation::radians(0.7);
+        let cloned_rotation = rotation.clone_via_bytes().unwrap();
+        // the complex number is kept as is, so no precision is lost through an angle
+        assert_eq!(rotation.cos.to_bits(), cloned_rotation.cos.to_bits());
+        assert_eq!(rotation.sin.to_bits(), cloned_rotation.sin.to_bits());
+
+        let linear_velocity = LinearVelocity(Vec2::new(3.0, 4.0));
+        assert_eq!(linear_velocity, linear_velocity.clone_via_bytes().unwrap());
+
+        let angular_velocity = AngularVelocity(-1.25);
+        assert_eq!(angular_velocity, angular_velocity.clone_via_bytes().unwrap());
+
+        for rigid_body in [RigidBody::Dynamic, RigidBody::Kinematic, RigidBody::Static] {
+            assert_eq!(rigid_body, rigid_body.clone_via_bytes().unwrap());
+        }
+
+        let collision_layers = CollisionLayers::new(0b0101, 0b1100);
+        assert_eq!(collision_layers, collision_layers.clone_via_bytes().unwrap());
+
+        let friction = Friction::new(0.4).with_combine_rule(CoefficientCombine::Max);
+        assert_eq!(friction, friction.clone_via_bytes().unwrap());
+
+        let restitution = Restitution::new(0.8).with_combine_rule(CoefficientCombine::Min);
+        assert_eq!(restitution, restitution.clone_via_bytes().unwrap());
+
+        let computed_angular_inertia = ComputedAngularInertia::new(2.5);
+        assert_eq!(computed_angular_inertia, computed_angular_inertia.clone_via_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_q5m1_native_collider_encodings() {
+        let colliders = vec![
+            Collider::circle(0.75),
+            Collider::rectangle(1.0, 2.0),
+            Collider::capsule(0.5, 2.0),
+            Collider::capsule_endpoints(0.25, Vec2::ZERO, Vec2::new(1.0, 2.0)),
+            Collider::segment(Vec2::ZERO, Vec2::new(2.0, 1.0)),
+            Collider::triangle(Vec2::ZERO, Vec2::X, Vec2::Y),
+            Collider::convex_hull(vec![Vec2::ZERO, Vec2::X, Vec2::new(1.0, 1.0), Vec2::Y]).unwrap(),
+            Collider::trimesh(
+                vec![Vec2::ZERO, Vec2::X, Vec2::new(1.0, 1.0), Vec2::Y],
+                vec![[0, 1, 2], [0, 2, 3]],
+            ),
+            Collider::compound(vec![
+                (Vec2::new(0.0, 1.0), Rotation::radians(0.5), Collider::circle(0.5)),
+                (Vec2::new(1.0, 0.0), Rotation::radians(1.0), Collider::rectangle(0.5, 0.5)),
+            ]),
+            // no native encoding, so this goes through the bincode fallback
+            Collider::round_rectangle(1.0, 1.0, 0.1),
+        ];
+        for collider in colliders.iter() {
+            assert_collider_round_trip(collider);
+        }
+
+        // the polygon keeps its points in the same order instead of recomputing its hull
+        let convex_collider = Collider::convex_hull(vec![Vec2::new(1.0, 1.0), Vec2::ZERO, Vec2::Y, Vec2::X, Vec2::new(0.5, 0.5)]).unwrap();
+        let cloned_convex_collider = convex_collider.clone_via_bytes().unwrap();
+        assert_eq!(
+            convex_collider.shape().as_convex_polygon().unwrap().points(),
+            cloned_convex_collider.shape().as_convex_polygon().unwrap().points(),
+        );
+
+        let mut scaled_collider = Collider::rectangle(1.0, 1.0);
+        scaled_collider.set_scale(Vec2::new(2.0, 0.5), 10);
+        assert_collider_round_trip(&scaled_collider);
+
+        let circle = Collider::circle(0.75);
+        assert!(circle.to_vec_bytes().unwrap().len() < bincode::serialize(&circle).unwrap().len());
+
+        let collider_constructors = vec![
+            ColliderConstructor::Circle { radius: 0.5 },
+            ColliderConstructor::Rectangle { x_length: 1.0, y_length: 2.0 },
+            ColliderConstructor::RegularPolygon { circumradius: 1.0, sides: 6 },
+            ColliderConstructor::Polyline { vertices: vec![Vec2::ZERO, Vec2::X, Vec2::Y], indices: None },
+            ColliderConstructor::Heightfield { heights: vec![0.0, 1.0, 0.5], scale: Vec2::new(4.0, 1.0) },
+        ];
+        for collider_constructor in collider_constructors.iter() {
+            assert_eq!(collider_constructor, &collider_constructor.clone_via_bytes().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_q5r2_malformed_collider_encodings_are_rejected() {
+        let get_trimesh_bytes = |indices: Vec<[u32; 3]>| {
+            let mut bytes = vec![6u8];
+            vec![Vec2::ZERO, Vec2::X, Vec2::Y].append_to_bytes(&mut bytes).unwrap();
+            indices.append_to_bytes(&mut bytes).unwrap();
+            0u16.append_to_bytes(&mut bytes).unwrap();
+            Vec2::ONE.append_to_bytes(&mut bytes).unwrap();
+            bytes
+        };
+        assert!(Collider::deserialize_from_bytes(&get_trimesh_bytes(vec![[0, 1, 2]])).is_ok());
+        assert!(Collider::deserialize_from_bytes(&get_trimesh_bytes(vec![[0, 1, 3]])).is_err());
+        assert!(Collider::deserialize_from_bytes(&get_trimesh_bytes(vec![])).is_err());
+
+        let mut empty_compound_bytes = vec![7u8];
+        0usize.append_to_bytes(&mut empty_compound_bytes).unwrap();
+        Vec2::ONE.append_to_bytes(&mut empty_compound_bytes).unwrap();
+        assert!(Collider::deserialize_from_bytes(&empty_compound_bytes).is_err());
+
+        // a huge shape count with no shapes behind it fails without reserving memory for it
+        let mut oversized_compound_bytes = vec![7u8];
+        usize::MAX.append_to_bytes(&mut oversized_compound_bytes).unwrap();
+        assert!(Collider::deserialize_from_bytes(&oversized_compound_bytes).is_err());
+    }
+
+    #[test]
+    fn test_t6w4_joints_and_spatial_queries_round_trip() {
+        let revolute_joint = RevoluteJoint::new(Entity::from_raw(1), Entity::from_raw(2))
+            .with_local_anchor_1(Vec2::new(0.5, 0.0))
+            .with_local_anchor_2(Vec2::new(-0.5, 0.0))
+            .with_angle_limits(-1.0, 1.0)
+            .with_compliance(0.001);
+        let cloned_revolute_joint = revolute_joint.clone_via_bytes().unwrap();
+        assert_eq!(revolute_joint.entity1, cloned_revolute_joint.entity1);
+        assert_eq!(revolute_joint.entity2, cloned_revolute_joint.entity2);
+        assert_eq!(revolute_joint.local_anchor1, cloned_revolute_joint.local_anchor1);
+        assert_eq!(revolute_joint.local_anchor2, cloned_revolute_joint.local_anchor2);
+        assert_eq!(revolute_joint.angle_limit, cloned_revolute_joint.angle_limit);
+        assert_eq!(revolute_joint.compliance, cloned_revolute_joint.compliance);
+
+        let ray_caster = RayCaster::new(Vec2::new(0.0, 5.0), Dir2::NEG_Y)
+            .with_max_hits(3)
+            .with_max_distance(20.0);
+        assert_eq!(ray_caster, ray_caster.clone_via_bytes().unwrap());
+
+        let shape_caster = ShapeCaster::new(Collider::circle(0.5), Vec2::new(0.0, 5.0), 0.3, Dir2::NEG_Y)
+            .with_max_hits(2);
+        let cloned_shape_caster = shape_caster.clone_via_bytes().unwrap();
+        assert_eq!(shape_caster.origin, cloned_shape_caster.origin);
+        assert_eq!(shape_caster.shape_rotation, cloned_shape_caster.shape_rotation);
+        assert_eq!(shape_caster.direction, cloned_shape_caster.direction);
+        assert_eq!(shape_caster.max_hits, cloned_shape_caster.max_hits);
+    }
+
+    #[test]
+    fn test_b3k7_physics_snapshot_rollback_is_bit_identical() {
+        let mut app = create_physics_app();
+        app.world_mut().spawn((
+            RigidBody::Static,
+            Collider::rectangle(20.0, 1.0),
+            Transform::from_xyz(0.0, -0.5, 0.0),
+        ));
+        let entities = (0..6)
+            .map(|index| {
+                let collider = if index % 2 == 0 {
+                    Collider::rectangle(1.0, 1.0)
+                }
+                else {
+                    Collider::circle(0.5)
+                };
+                app.world_mut().spawn((
+                    RigidBody::Dynamic,
+                    collider,
+                    Transform::from_xyz(index as f32 * 0.3, 0.5 + index as f32 * 1.1, 0.0),
+                    AngularVelocity(0.5),
+                )).id()
+            })
+            .collect::<Vec<_>>();
+
+        // let the bodies land and touch each other so that contacts are part of the snapshot
+        for _ in 0..60 {
+            app.update();
+        }
+        let snapshot = PhysicsSnapshot::capture(app.world_mut());
+        assert_eq!(7, snapshot.rigid_bodies.len());
+        assert!(!snapshot.contact_pairs.is_empty());
+
+        let expected_positions = (0..40)
+            .map(|_| {
+                app.update();
+                get_positions(&mut app, &entities)
+            })
+            .collect::<Vec<_>>();
+
+        let snapshot = snapshot.clone_via_bytes().unwrap();
+        snapshot.restore(app.world_mut()).unwrap();
+        assert_eq!(snapshot, PhysicsSnapshot::capture(app.world_mut()));
+
+        let actual_positions = (0..40)
+            .map(|_| {
+                app.update();
+                get_positions(&mut app, &entities)
+            })
+            .collect::<Vec<_>>();
+
+        for (expected_frame, actual_frame) in expected_positions.iter().zip(actual_positions.iter()) {
+            for ((expected_position, expected_rotation), (actual_position, actual_rotation)) in expected_frame.iter().zip(actual_frame.iter()) {
+                assert_eq!(expected_position.to_array().map(f32::to_bits), actual_position.to_array().map(f32::to_bits));
+                assert_eq!(expected_rotation.cos.to_bits(), actual_rotation.cos.to_bits());
+                assert_eq!(expected_rotation.sin.to_bits(), actual_rotation.sin.to_bits());
+            }
+        }
+    }
+}
@@ -0,0 +1,345 @@
+#[cfg(test)]
+mod avian3d_tests {
+    use std::time::Duration;
+
+    use avian3d::prelude::*;
+    use bevy::{app::{App, PluginGroup}, asset::{AssetPlugin, Assets}, scene::ScenePlugin, ecs::entity::Entity, math::{Dir3, Quat, Vec3}, prelude::{Mesh, MinimalPlugins, Transform, TransformPlugin}, time::TimeUpdateStrategy};
+    use bytecon::{avian3d::PhysicsSnapshot, ByteConverter};
+
+    fn create_physics_app() -> App {
+        let mut app = App::new();
+        app
+            .add_plugins((
+                MinimalPlugins,
+                TransformPlugin,
+                AssetPlugin::default(),
+                ScenePlugin,
+                PhysicsPlugins::default()
+                    .build()
+                    .disable::<ColliderHierarchyPlugin>(),
+            ))
+            .init_resource::<Assets<Mesh>>()
+            // exactly one fixed timestep per update
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / 64.0)));
+        app.finish();
+        app
+    }
+
+    fn get_positions(app: &mut App, entities: &[Entity]) -> Vec<(Vec3, Quat)> {
+        entities.iter()
+            .map(|entity| {
+                let entity_ref = app.world().entity(*entity);
+                (entity_ref.get::<Position>().unwrap().0, entity_ref.get::<Rotation>().unwrap().0)
+            })
+            .collect()
+    }
+
+    fn assert_collider_round_trip(collider: &Collider) {
+        let cloned_collider = collider.clone_via_bytes().unwrap();
+        assert_eq!(bincode::serialize(collider).unwrap(), bincode::serialize(&cloned_collider).unwrap());
+    }
+
+    #[test]
+    fn test_c7t3_native_collider_encodings() {
+        let colliders = vec![
+            Collider::sphere(0.75),
+            Collider::cuboid(1.0, 2.0, 3.0),
+            Collider::capsule(0.5, 2.0),
+            Collider::capsule_endpoints(0.25, Vec3::ZERO, Vec3::new(1.0, 2.0, 3.0)),
+            Collider::cylinder(0.5, 1.5),
+            Collider::cone(0.5, 1.5),
+            Collider::trimesh(
+                vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z],
+                vec![[0, 1, 2], [0, 2, 3], [0, 3, 1], [1, 3, 2]],
+            ),
+            Collider::compound(vec![
+                (Vec3::new(0.0, 1.0, 0.0), Quat::from_rotation_y(0.5), Collider::sphere(0.5)),
+                (Vec3::new(1.0, 0.0, 0.0), Quat::from_rotation_x(1.0), Collider::cuboid(0.5, 0.5, 0.5)),
+                (Vec3::new(0.0, 0.0, -1.0), Quat::IDENTITY, Collider::cylinder(0.25, 1.0)),
+            ]),
+            // no native encoding, so this goes through the bincode fallback
+            Collider::round_cuboid(1.0, 1.0, 1.0, 0.1),
+        ];
+        for collider in colliders.iter() {
+            assert_collider_round_trip(collider);
+        }
+
+        let mut scaled_collider = Collider::cuboid(1.0, 1.0, 1.0);
+        scaled_collider.set_scale(Vec3::new(2.0, 1.0, 0.5), 10);
+        assert_collider_round_trip(&scaled_collider);
+
+        let sphere = Collider::sphere(0.75);
+        assert!(sphere.to_vec_bytes().unwrap().len() < bincode::serialize(&sphere).unwrap().len());
+    }
+
+    #[test]
+    fn test_e4y8_native_convex_hull_collider_encoding() {
+        let collider = Collider::convex_hull(vec![
+            Vec3::ZERO,
+            Vec3::X,
+            Vec3::Y,
+            Vec3::Z,
+            Vec3::new(0.1, 0.1, 0.1),
+        ]).unwrap();
+        let cloned_collider = collider.clone_via_bytes().unwrap();
+
+        // the polyhedron is rebuilt from its own points and faces, so nothing is reordered or recomputed
+        let expected_polyhedron = collider.shape().as_convex_polyhedron().unwrap();
+        let actual_polyhedron = cloned_collider.shape().as_convex_polyhedron().unwrap();
+        assert_eq!(expected_polyhedron.points(), actual_polyhedron.points());
+        assert_eq!(expected_polyhedron.to_trimesh(), actual_polyhedron.to_trimesh());
+        assert_eq!(4, actual_polyhedron.points().len());
+    }
+
+    #[test]
+    fn test_c7m4_malformed_collider_encodings_are_rejected() {
+        let get_trimesh_bytes = |indices: Vec<[u32; 3]>| {
+            let mut bytes = vec![6u8];
+            vec![Vec3::ZERO, Vec3::X, Vec3::Y].append_to_bytes(&mut bytes).unwrap();
+            indices.append_to_bytes(&mut bytes).unwrap();
+            0u16.append_to_bytes(&mut bytes).unwrap();
+            Vec3::ONE.append_to_bytes(&mut bytes).unwrap();
+            bytes
+        };
+        assert!(Collider::deserialize_from_bytes(&get_trimesh_bytes(vec![[0, 1, 2]])).is_ok());
+        assert!(Collider::deserialize_from_bytes(&get_trimesh_bytes(vec![[0, 1, 3]])).is_err());
+        assert!(Collider::deserialize_from_bytes(&get_trimesh_bytes(vec![])).is_err());
+
+        let mut empty_compound_bytes = vec![7u8];
+        0usize.append_to_bytes(&mut empty_compound_bytes).unwrap();
+        Vec3::ONE.append_to_bytes(&mut empty_compound_bytes).unwrap();
+        assert!(Collider::deserialize_from_bytes(&empty_compound_bytes).is_err());
+
+        // a huge shape count with no shapes behind it fails without reserving memory for it
+        let mut oversized_compound_bytes = vec![7u8];
+        usize::MAX.append_to_bytes(&mut oversized_compound_bytes).unwrap();
+        assert!(Collider::deserialize_from_bytes(&oversized_compound_bytes).is_err());
+    }
+
+    #[test]
+    fn test_u1g6_native_collider_constructor_encodings() {
+        let collider_constructors = vec![
+            ColliderConstructor::Sphere { radius: 0.5 },
+            ColliderConstructor::Cuboid { x_length: 1.0, y_length: 2.0, z_length: 3.0 },
+            ColliderConstructor::RoundCuboid { x_length: 1.0, y_length: 2.0, z_length: 3.0, border_radius: 0.1 },
+            ColliderConstructor::Cylinder { radius: 0.5, height: 2.0 },
+            ColliderConstructor::Cone { radius: 0.5, height: 2.0 },
+            ColliderConstructor::Capsule { radius: 0.5, height: 2.0 },
+            ColliderConstructor::CapsuleEndpoints { radius: 0.5, a: Vec3::ZERO, b: Vec3::Y },
+            ColliderConstructor::HalfSpace { outward_normal: Vec3::Y },
+            ColliderConstructor::Segment { a: Vec3::ZERO, b: Vec3::X },
+            ColliderConstructor::Triangle { a: Vec3::ZERO, b: Vec3::X, c: Vec3::Y },
+            ColliderConstructor::Polyline { vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y], indices: Some(vec![[0, 1], [1, 2]]) },
+            ColliderConstructor::Polyline { vertices: vec![Vec3::ZERO, Vec3::X], indices: None },
+            ColliderConstructor::Trimesh { vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y], indices: vec![[0, 1, 2]] },
+            ColliderConstructor::TrimeshWithConfig { vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y], indices: vec![[0, 1, 2]], flags: TrimeshFlags::MERGE_DUPLICATE_VERTICES },
+            ColliderConstructor::ConvexDecomposition { vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y], indices: vec![[0, 1, 2]] },
+            ColliderConstructor::ConvexHull { points: vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z] },
+            ColliderConstructor::Heightfield { heights: vec![vec![0.0, 1.0], vec![2.0, 3.0]], scale: Vec3::ONE },
+            ColliderConstructor::TrimeshFromMesh,
+            ColliderConstructor::ConvexDecompositionFromMesh,
+            ColliderConstructor::ConvexHullFromMesh,
+            // no native encoding, so this goes through the bincode fallback
+            ColliderConstructor::ConvexDecompositionFromMeshWithConfig(VhacdParameters::default()),
+        ];
+        for collider_constructor in collider_constructors {
+            assert_eq!(collider_constructor, collider_constructor.clone_via_bytes().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_j6p4_joints_round_trip() {
+        let entity1 = Entity::from_raw(1);
+        let entity2 = Entity::from_raw(2);
+
+        let fixed_joint = FixedJoint::new(entity1, entity2)
+            .with_local_anchor_1(Vec3::X)
+            .with_compliance(0.001);
+        assert_eq!(fixed_joint, fixed_joint.clone_via_bytes().unwrap());
+
+        let revolute_joint = RevoluteJoint::new(entity1, entity2)
+            .with_aligned_axis(Vec3::Z)
+            .with_angle_limits(-1.0, 1.0)
+            .with_linear_velocity_damping(0.5);
+        assert_eq!(revolute_joint, revolute_joint.clone_via_bytes().unwrap());
+
+        let prismatic_joint = PrismaticJoint::new(entity1, entity2)
+            .with_free_axis(Vec3::Y)
+            .with_limits(0.0, 2.0)
+            .with_local_anchor_2(Vec3::new(0.0, -1.0, 0.5));
+        assert_eq!(prismatic_joint, prismatic_joint.clone_via_bytes().unwrap());
+
+        let spherical_joint = SphericalJoint::new(entity1, entity2)
+            .with_swing_limits(-0.5, 0.5)
+            .with_twist_limits(-0.25, 0.25)
+            .with_angular_velocity_damping(2.0);
+        assert_eq!(spherical_joint, spherical_joint.clone_via_bytes().unwrap());
+
+        let distance_joint = DistanceJoint::new(entity1, entity2)
+            .with_rest_length(1.5)
+            .with_limits(1.0, 2.0);
+        assert_eq!(distance_joint, distance_joint.clone_via_bytes().unwrap());
+        let distance_joint = DistanceJoint::new(entity1, entity2);
+        assert_eq!(distance_joint, distance_joint.clone_via_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_s3q8_body_properties_round_trip() {
+        let locked_axes = LockedAxes::new()
+            .lock_rotation_x()
+            .lock_translation_z();
+        assert_eq!(locked_axes.to_bits(), locked_axes.clone_via_bytes().unwrap().to_bits());
+
+        let dominance = Dominance(-5);
+        assert_eq!(dominance, dominance.clone_via_bytes().unwrap());
+
+        let linear_damping = LinearDamping(0.3);
+        assert_eq!(linear_damping, linear_damping.clone_via_bytes().unwrap());
+
+        let angular_damping = AngularDamping(0.7);
+        assert_eq!(angular_damping, angular_damping.clone_via_bytes().unwrap());
+
+        let computed_mass = ComputedMass::new(12.5);
+        assert_eq!(computed_mass, computed_mass.clone_via_bytes().unwrap());
+
+        let computed_angular_inertia = ComputedAngularInertia::new(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(computed_angular_inertia, computed_angular_inertia.clone_via_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_x2b9_spatial_queries_round_trip() {
+        let query_filter = SpatialQueryFilter::from_mask(0b1010)
+            .with_excluded_entities([Entity::from_raw(4), Entity::from_raw(9)]);
+        assert_eq!(query_filter, query_filter.clone_via_bytes().unwrap());
+
+        let ray_caster = RayCaster::new(Vec3::new(0.0, 5.0, 0.0), Dir3::NEG_Y)
+            .with_max_hits(3)
+            .with_max_distance(20.0)
+            .with_solidness(false)
+            .with_query_filter(query_filter.clone());
+        assert_eq!(ray_caster, ray_caster.clone_via_bytes().unwrap());
+
+        let shape_caster = ShapeCaster::new(Collider::sphere(0.5), Vec3::new(0.0, 5.0, 0.0), Quat::from_rotation_y(0.3), Dir3::NEG_Y)
+            .with_max_hits(2)
+            .with_target_distance(0.1)
+            .with_ignore_origin_penetration(true)
+            .with_query_filter(query_filter);
+        let cloned_shape_caster = shape_caster.clone_via_bytes().unwrap();
+        assert_eq!(shape_caster.enabled, cloned_shape_caster.enabled);
+        assert_eq!(bincode::serialize(&shape_caster.shape).unwrap(), bincode::serialize(&cloned_shape_caster.shape).unwrap());
+        assert_eq!(shape_caster.origin, cloned_shape_caster.origin);
+        assert_eq!(shape_caster.shape_rotation, cloned_shape_caster.shape_rotation);
+        assert_eq!(shape_caster.direction, cloned_shape_caster.direction);
+        assert_eq!(shape_caster.max_hits, cloned_shape_caster.max_hits);
+        assert_eq!(shape_caster.max_distance, cloned_shape_caster.max_distance);
+        assert_eq!(shape_caster.target_distance, cloned_shape_caster.target_distance);
+        assert_eq!(shape_caster.compute_contact_on_penetration, cloned_shape_caster.compute_contact_on_penetration);
+        assert_eq!(shape_caster.ignore_origin_penetration, cloned_shape_caster.ignore_origin_penetration);
+        assert_eq!(shape_caster.ignore_self, cloned_shape_caster.ignore_self);
+        assert_eq!(shape_caster.query_filter, cloned_shape_caster.query_filter);
+
+        // the hits can only be produced by the spatial query pipeline
+        let mut app = create_physics_app();
+        app.world_mut().spawn((
+            RigidBody::Static,
+            Collider::cuboid(10.0, 1.0, 10.0),
+            Transform::default(),
+        ));
+        let caster_entity = app.world_mut().spawn((
+            RayCaster::new(Vec3::ZERO, Dir3::NEG_Y),
+            ShapeCaster::new(Collider::sphere(0.25), Vec3::ZERO, Quat::IDENTITY, Dir3::NEG_Y),
+            Transform::from_xyz(0.0, 3.0, 0.0),
+        )).id();
+        for _ in 0..3 {
+            app.update();
+        }
+        let ray_hits = app.world().get::<RayHits>(caster_entity).unwrap().clone();
+        let shape_hits = app.world().get::<ShapeHits>(caster_entity).unwrap().clone();
+        assert_eq!(1, ray_hits.len());
+        assert_eq!(1, shape_hits.len());
+        assert_eq!(ray_hits, ray_hits.clone_via_bytes().unwrap());
+        assert_eq!(shape_hits, shape_hits.clone_via_bytes().unwrap());
+        let ray_hit_data = ray_hits.iter().next().unwrap();
+        assert_eq!(ray_hit_data, &ray_hit_data.clone_via_bytes().unwrap());
+        let shape_hit_data = shape_hits.iter().next().unwrap();
+        assert_eq!(shape_hit_data, &shape_hit_data.clone_via_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_n2r6_physics_snapshot_rollback_is_bit_identical() {
+        let mut app = create_physics_app();
+        app.world_mut().spawn((
+            RigidBody::Static,
+            Collider::cuboid(20.0, 1.0, 20.0),
+            Transform::from_xyz(0.0, -0.5, 0.0),
+        ));
+        let entities = (0..6)
+            .map(|index| {
+                let collider = if index % 2 == 0 {
+                    Collider::cuboid(1.0, 1.0, 1.0)
+                }
+                else {
+                    Collider::sphere(0.5)
+                };
+                app.world_mut().spawn((
+                    RigidBody::Dynamic,
+                    collider,
+                    Transform::from_xyz(index as f32 * 0.3, 0.5 + index as f32 * 1.1, 0.1 * index as f32)
+                        .with_rotation(Quat::from_rotation_z(0.2 * index as f32)),
+                    AngularVelocity(Vec3::new(0.0, 1.0, 0.5)),
+                )).id()
+            })
+            .collect::<Vec<_>>();
+
+        // let the bodies land and touch each other so that contacts are part of the snapshot
+        for _ in 0..60 {
+            app.update();
+        }
+        let snapshot = PhysicsSnapshot::capture(app.world_mut());
+        assert_eq!(7, snapshot.rigid_bodies.len());
+        assert!(!snapshot.contact_pairs.is_empty());
+
+        let expected_positions = (0..40)
+            .map(|_| {
+                app.update();
+                get_positions(&mut app, &entities)
+            })
+            .collect::<Vec<_>>();
+
+        let snapshot = snapshot.clone_via_bytes().unwrap();
+        snapshot.restore(app.world_mut()).unwrap();
+        assert_eq!(snapshot, PhysicsSnapshot::capture(app.world_mut()));
+
+        let actual_positions = (0..40)
+            .map(|_| {
+                app.update();
+                get_positions(&mut app, &entities)
+            })
+            .collect::<Vec<_>>();
+
+        for (expected_frame, actual_frame) in expected_positions.iter().zip(actual_positions.iter()) {
+            for ((expected_position, expected_rotation), (actual_position, actual_rotation)) in expected_frame.iter().zip(actual_frame.iter()) {
+                assert_eq!(expected_position.to_array().map(f32::to_bits), actual_position.to_array().map(f32::to_bits));
+                assert_eq!(expected_rotation.to_array().map(f32::to_bits), actual_rotation.to_array().map(f32::to_bits));
+            }
+        }
+    }
+
+    #[test]
+    fn test_w5k9_physics_snapshot_requires_existing_bodies() {
+        let mut app = create_physics_app();
+        let entity = app.world_mut().spawn((
+            RigidBody::Dynamic,
+            Collider::sphere(0.5),
+            Transform::default(),
+        )).id();
+        // the first update has no elapsed time, so the body is only prepared by the physics step of the second one
+        app.update();
+        app.update();
+
+        let snapshot = PhysicsSnapshot::capture(app.world_mut());
+        assert_eq!(1, snapshot.rigid_bodies.len());
+        app.world_mut().despawn(entity);
+        assert!(snapshot.restore(app.world_mut()).is_err());
+    }
+}
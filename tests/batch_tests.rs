@@ -0,0 +1,98 @@
+#[cfg(test)]
+#[cfg(feature = "base")]
+mod batch_tests {
+    use std::io::{Cursor, Write};
+    use bytecon::{blocking::BlockingByteStream, ByteStreamReader, ByteStreamWriter};
+
+    // counts the calls to write so that coalesced frames can be told apart from a write per frame
+    #[derive(Default)]
+    struct CountingWriter {
+        bytes: Vec<u8>,
+        write_count: usize,
+    }
+
+    impl Write for CountingWriter {
+        fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+            self.write_count += 1;
+            self.bytes.extend_from_slice(buffer);
+            Ok(buffer.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn get_values() -> Vec<String> {
+        (0..100).map(|value| format!("value {value}")).collect()
+    }
+
+    #[test]
+    fn test_h5w1_write_many_coalesces_frames() {
+        let mut single_writer = CountingWriter::default();
+        for value in get_values() {
+            single_writer.write_from_byte_converter(&value).unwrap();
+        }
+        let mut batch_writer = CountingWriter::default();
+        batch_writer.write_many(&get_values()).unwrap();
+        assert_eq!(100, single_writer.write_count);
+        assert_eq!(1, batch_writer.write_count);
+        assert_eq!(single_writer.bytes, batch_writer.bytes);
+
+        let mut stream = BlockingByteStream::new(CountingWriter::default());
+        stream.write_many(&get_values()).unwrap();
+        stream.write_many::<String>(&[]).unwrap();
+        assert_eq!(single_writer.bytes, stream.get_ref().bytes);
+
+        // the blanket reader cannot see what is buffered, so it returns one value at a time
+        let mut cursor = Cursor::new(batch_writer.bytes);
+        assert_eq!(vec![String::from("value 0")], cursor.read_many::<String>(10).unwrap());
+        assert!(cursor.read_many::<String>(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_h5r2_read_many_takes_buffered_frames() {
+        let mut writer = CountingWriter::default();
+        writer.write_many(&get_values()).unwrap();
+        let mut stream = BlockingByteStream::new(Cursor::new(writer.bytes));
+        let mut values = Vec::new();
+        while values.len() < 100 {
+            let batch = stream.read_many::<String>(30).unwrap();
+            assert!(!batch.is_empty() && batch.len() <= 30);
+            values.extend(batch);
+
+            // the frames read ahead are whole
+            assert!(!stream.has_partial_frame());
+        }
+        assert_eq!(get_values(), values);
+        assert_eq!(0, stream.buffered_length());
+        assert!(stream.read_many::<String>(30).is_err());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_h5t3_tokio_batches() {
+        use bytecon::{ByteStreamReaderAsync, ByteStreamWriterAsync};
+        use tokio::{io::BufReader, net::{TcpListener, TcpStream}, sync::mpsc};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mut writer = TcpStream::connect(listener.local_addr().unwrap()).await.unwrap();
+        let mut reader = BufReader::new(listener.accept().await.unwrap().0);
+        writer.write_many(&get_values()).await.unwrap();
+        writer.write_from_byte_converter(&String::from("single")).await.unwrap();
+
+        let mut values = Vec::new();
+        let mut batches_length = 0;
+        while values.len() < 101 {
+            values.extend(reader.read_many::<String>(200).await.unwrap());
+            batches_length += 1;
+        }
+        assert!(batches_length < 101);
+        assert_eq!("single", values.pop().unwrap());
+        assert_eq!(get_values(), values);
+
+        let (mut sender, mut receiver) = mpsc::channel::<Vec<u8>>(200);
+        sender.write_many(&get_values()).await.unwrap();
+        assert_eq!(get_values()[..40].to_vec(), receiver.read_many::<String>(40).await.unwrap());
+        assert_eq!(get_values()[40..].to_vec(), receiver.read_many::<String>(200).await.unwrap());
+    }
+}
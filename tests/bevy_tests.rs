@@ -1,9 +1,11 @@
 #[cfg(test)]
 mod bevy_tests {
-    use std::error::Error;
+    use std::{error::Error, time::Duration};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
 
-    use bevy::{asset::{uuid::Uuid, AssetId, Assets}, color::Color, ecs::{component::Component, entity::Entity, resource::Resource, world::World}, pbr::wireframe::WireframeMaterial, transform::components::Transform};
-    use bytecon::{bevy::{BevyWorldMutSingleton, BevyWorldRefSingleton}, ByteConverter, Context, DeserializationByteConverterFactory, SerializationByteConverterFactory};
+    use bevy::{app::{App, Update}, asset::{uuid::Uuid, AssetId, Assets, Handle}, color::Color, ecs::{component::Component, entity::{Entity, EntityHashMap}, event::Events, hierarchy::ChildOf, resource::Resource, system::{Res, ResMut, RunSystemOnce}, world::World}, input::{keyboard::{Key, KeyboardInput, NativeKey}, ButtonInput, ButtonState, InputPlugin}, math::{Vec2, Vec3}, pbr::wireframe::{WireframeConfig, WireframeMaterial}, picking::{backend::HitData, events::{Click, Pointer}, pointer::{Location, PointerButton, PointerId}}, prelude::KeyCode, render::camera::{ManualTextureViewHandle, NormalizedRenderTarget}, time::{Time, TimePlugin, TimeUpdateStrategy}, transform::components::Transform};
+    use bytecon::{bevy::{BevyWorldMutSingleton, BevyWorldRefSingleton, ByteConverterEventBridgePlugin, ByteConverterResourceAppExt, ByteConverterResourceCommandsExt, ByteConverterResourceRegistry, InputPlayer, InputPlayerPlugin, InputRecorder, InputRecorderPlugin, InputRecording}, rand::ChaChaRngState, ByteConverter, Context, ContextualByteConverter, DeserializationByteConverterFactory, SerializationByteConverterFactory};
 
     #[cfg(feature = "bevy")]
     #[test]
@@ -188,4 +190,339 @@ mod bevy_tests {
         })
             .expect("Failed to set world.");
     }
-}
\ No newline at end of file
+
+    fn insert_wireframe_material(world: &mut World) -> Handle<WireframeMaterial> {
+        world.init_resource::<Assets<WireframeMaterial>>();
+        let mut wireframe_assets = world.get_resource_mut::<Assets<WireframeMaterial>>().expect("Failed to find WireframeMaterial assets.");
+        let asset_id = AssetId::Uuid {
+            uuid: Uuid::parse_str("0f6f3f0e-8e0c-4d38-9b8c-0f2a6f1f7c11").unwrap(),
+        };
+        wireframe_assets.insert(asset_id, WireframeMaterial {
+            color: Color::WHITE,
+        });
+        wireframe_assets.get_strong_handle(asset_id).expect("Failed to find handle for AssetId.")
+    }
+
+    #[test]
+    fn test_c4k2_contextual_asset_handle_with_world() {
+        let mut world = World::default();
+        let expected_handle = insert_wireframe_material(&mut world);
+
+        let bytes = expected_handle.to_vec_bytes_with(&world).expect("Failed to serialize with World.");
+        let actual_handle = Handle::<WireframeMaterial>::deserialize_from_bytes_with(&mut world, &bytes).expect("Failed to deserialize with World.");
+        assert_eq!(expected_handle, actual_handle);
+
+        // the thread-local singletons were never set
+        assert!(expected_handle.to_vec_bytes().is_err());
+    }
+
+    #[test]
+    fn test_h6n3_contextual_asset_handle_within_system() {
+        let mut world = World::default();
+        let expected_handle = insert_wireframe_material(&mut world);
+
+        let handle = expected_handle.clone();
+        let bytes = world.run_system_once(move |wireframe_assets: Res<Assets<WireframeMaterial>>| {
+            handle.to_vec_bytes_with(&*wireframe_assets)
+        })
+            .expect("Failed to run system.")
+            .expect("Failed to serialize with Assets.");
+
+        let actual_handle = world.run_system_once(move |mut wireframe_assets: ResMut<Assets<WireframeMaterial>>| {
+            Handle::<WireframeMaterial>::deserialize_from_bytes_with(&mut *wireframe_assets, &bytes)
+        })
+            .expect("Failed to run system.")
+            .expect("Failed to deserialize with Assets.");
+        assert_eq!(expected_handle, actual_handle);
+    }
+
+    #[test]
+    fn test_r1x8_contextual_entity_with_entity_mapper() {
+        let mut world = World::default();
+        let remote_parent = world.spawn_empty().id();
+        let local_parent = world.spawn_empty().id();
+
+        let mut entity_map = EntityHashMap::<Entity>::default();
+        entity_map.insert(remote_parent, local_parent);
+
+        let child_of = ChildOf(remote_parent);
+        let bytes = child_of.to_vec_bytes_with(&entity_map).unwrap();
+        let mapped_child_of = ChildOf::deserialize_from_bytes_with(&mut entity_map, &bytes).unwrap();
+        assert_eq!(local_parent, mapped_child_of.parent());
+    }
+
+    #[test]
+    fn test_v3m5_event_bridge_between_apps() {
+        let (sender, receiver) = std::sync::mpsc::channel::<Vec<u8>>();
+
+        let mut client_app = App::new();
+        client_app.add_plugins(ByteConverterEventBridgePlugin::default()
+            .with_sender::<Pointer<Click>>(sender));
+
+        let mut server_app = App::new();
+        server_app.add_plugins(ByteConverterEventBridgePlugin::default()
+            .with_receiver::<Pointer<Click>>(receiver));
+
+        let expected_events = (0..3u32)
+            .map(|index| {
+                Pointer::new(
+                    PointerId::Mouse,
+                    Location {
+                        target: NormalizedRenderTarget::TextureView(ManualTextureViewHandle(index)),
+                        position: Vec2::new(index as f32, 2.0),
+                    },
+                    Entity::from_raw(index),
+                    Click {
+                        button: PointerButton::Primary,
+                        hit: HitData::new(Entity::from_raw(100), 1.5, Some(Vec3::X), None),
+                        duration: Duration::from_millis(120),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        for expected_event in expected_events.iter() {
+            client_app.world_mut().send_event(expected_event.clone());
+        }
+        client_app.update();
+        server_app.update();
+
+        let server_events = server_app.world().resource::<Events<Pointer<Click>>>();
+        let actual_events = server_events.get_cursor()
+            .read(server_events)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(expected_events, actual_events);
+    }
+
+    #[test]
+    fn test_m4j7_replay_recorded_input_into_headless_app() {
+        let window = Entity::from_raw(0);
+        let keyboard_input = |key_code: KeyCode, state: ButtonState| {
+            KeyboardInput {
+                key_code,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                text: None,
+                repeat: false,
+                window,
+            }
+        };
+
+        // each frame of the recording is the bytes of the keyboard events that happened during that frame
+        let recorded_frames = vec![
+            vec![keyboard_input(KeyCode::KeyW, ButtonState::Pressed)],
+            vec![keyboard_input(KeyCode::Space, ButtonState::Pressed)],
+            vec![keyboard_input(KeyCode::KeyW, ButtonState::Released), keyboard_input(KeyCode::Space, ButtonState::Released)],
+            vec![],
+        ]
+            .into_iter()
+            .map(|frame| frame.to_vec_bytes().unwrap())
+            .collect::<Vec<Vec<u8>>>();
+
+        let mut app = App::new();
+        app.add_plugins(InputPlugin);
+
+        let mut pressed_per_frame = Vec::new();
+        let mut button_input_bytes_per_frame = Vec::new();
+        for recorded_frame in recorded_frames.iter() {
+            for event in Vec::<KeyboardInput>::deserialize_from_bytes(recorded_frame).unwrap() {
+                app.world_mut().send_event(event);
+            }
+            app.update();
+
+            let button_input = app.world().resource::<ButtonInput<KeyCode>>();
+            pressed_per_frame.push((
+                button_input.pressed(KeyCode::KeyW),
+                button_input.just_pressed(KeyCode::Space),
+                button_input.just_released(KeyCode::KeyW),
+            ));
+            button_input_bytes_per_frame.push(button_input.to_vec_bytes().unwrap());
+        }
+
+        assert_eq!(vec![
+            (true, false, false),
+            (true, true, false),
+            (false, false, true),
+            (false, false, false),
+        ], pressed_per_frame);
+
+        // the snapshot of the third frame still knows what was just released
+        let button_input = ButtonInput::<KeyCode>::deserialize_from_bytes(&button_input_bytes_per_frame[2]).unwrap();
+        assert!(button_input.just_released(KeyCode::Space));
+        assert!(!button_input.pressed(KeyCode::Space));
+    }
+
+    #[test]
+    fn test_d8w1_record_and_replay_input_with_time_and_rng() {
+        #[derive(Resource)]
+        struct GameRng(ChaCha8Rng);
+
+        impl ByteConverter for GameRng {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+                ChaChaRngState(self.0.clone()).append_to_bytes(bytes)
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self(ChaChaRngState::<ChaCha8Rng>::extract_from_bytes(bytes, index)?.0))
+            }
+        }
+
+        #[derive(Resource, Default)]
+        struct PlayerState {
+            positions: Vec<Vec2>,
+        }
+
+        fn move_player(
+            keys: Res<ButtonInput<KeyCode>>,
+            time: Res<Time>,
+            mut rng: ResMut<GameRng>,
+            mut player_state: ResMut<PlayerState>,
+        ) {
+            let mut position = player_state.positions.last().copied().unwrap_or(Vec2::ZERO);
+            if keys.pressed(KeyCode::KeyD) {
+                position.x += 10.0 * time.delta_secs();
+            }
+            if keys.pressed(KeyCode::KeyW) {
+                position.y += 10.0 * time.delta_secs();
+            }
+            position += Vec2::new(rng.0.gen_range(-0.1..0.1), rng.0.gen_range(-0.1..0.1));
+            player_state.positions.push(position);
+        }
+
+        let keyboard_input = |key_code: KeyCode, state: ButtonState| {
+            KeyboardInput {
+                key_code,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                text: None,
+                repeat: false,
+                window: Entity::from_raw(0),
+            }
+        };
+
+        let mut recording_app = App::new();
+        recording_app
+            .add_plugins((TimePlugin, InputPlugin, InputRecorderPlugin::default().with_rng_resource::<GameRng>()))
+            .insert_resource(GameRng(ChaCha8Rng::seed_from_u64(7)))
+            .init_resource::<PlayerState>()
+            .add_systems(Update, move_player);
+
+        let frame_inputs = [
+            vec![],
+            vec![keyboard_input(KeyCode::KeyD, ButtonState::Pressed)],
+            vec![keyboard_input(KeyCode::KeyW, ButtonState::Pressed)],
+            vec![],
+            vec![keyboard_input(KeyCode::KeyD, ButtonState::Released)],
+            vec![keyboard_input(KeyCode::KeyW, ButtonState::Released)],
+        ];
+        for (frame_index, frame_input) in frame_inputs.into_iter().enumerate() {
+            recording_app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(10 + frame_index as u64 * 3)));
+            recording_app.world_mut().send_event_batch(frame_input);
+            recording_app.update();
+        }
+
+        // the path is unique so that concurrent test runs do not share the file
+        let path = std::env::temp_dir().join(format!("bytecon_test_d8w1_input_recording_{}_{}.bin", std::process::id(), std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos()));
+        recording_app.world().resource::<InputRecorder>().save_to_file(&path).unwrap();
+        let expected_positions = recording_app.world().resource::<PlayerState>().positions.clone();
+
+        let recording = InputRecording::load_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(recording_app.world().resource::<InputRecorder>().get_recording(), &recording);
+        assert_eq!(6, recording.frames.len());
+        assert_eq!(Duration::from_millis(13), recording.frames[1].delta);
+        assert_eq!(1, recording.frames[1].keyboard_inputs.len());
+
+        // the rng is seeded differently on purpose since the recorded state replaces it every frame
+        let mut replaying_app = App::new();
+        replaying_app
+            .add_plugins((TimePlugin, InputPlugin, InputPlayerPlugin::new(recording).with_rng_resource::<GameRng>()))
+            .insert_resource(GameRng(ChaCha8Rng::seed_from_u64(1234)))
+            .init_resource::<PlayerState>()
+            .add_systems(Update, move_player);
+        while !replaying_app.world().resource::<InputPlayer>().is_finished() {
+            replaying_app.update();
+        }
+
+        let actual_positions = replaying_app.world().resource::<PlayerState>().positions.clone();
+        assert_eq!(expected_positions, actual_positions);
+        assert!(expected_positions.last().unwrap().x > 0.1);
+    }
+
+    #[test]
+    fn test_q5w2_save_and_load_registered_resources() {
+        #[derive(Resource, Debug, PartialEq)]
+        struct LevelSettings {
+            name: String,
+            difficulty: u8,
+        }
+
+        impl ByteConverter for LevelSettings {
+            fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+                self.name.append_to_bytes(bytes)?;
+                self.difficulty.append_to_bytes(bytes)?;
+                Ok(())
+            }
+            fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+                Ok(Self {
+                    name: String::extract_from_bytes(bytes, index)?,
+                    difficulty: u8::extract_from_bytes(bytes, index)?,
+                })
+            }
+        }
+
+        #[derive(Resource)]
+        struct UnsavedResource;
+
+        // the path is unique so that concurrent test runs do not share the file
+        let path = std::env::temp_dir().join(format!("bytecon_test_q5w2_resources_{}_{}.bin", std::process::id(), std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos()));
+
+        let mut saving_app = App::new();
+        saving_app
+            .register_byte_converter_resource::<LevelSettings>("level_settings")
+            .register_byte_converter_resource::<WireframeConfig>("wireframe_config")
+            .insert_resource(LevelSettings {
+                name: String::from("first level"),
+                difficulty: 3,
+            })
+            .insert_resource(WireframeConfig {
+                global: true,
+                default_color: Color::srgb(0.1, 0.2, 0.3),
+            })
+            .insert_resource(UnsavedResource);
+        saving_app.world_mut().commands().save_resources_to_file(path.clone());
+        saving_app.world_mut().flush();
+
+        let mut loading_app = App::new();
+        loading_app
+            .register_byte_converter_resource::<LevelSettings>("level_settings")
+            .register_byte_converter_resource::<WireframeConfig>("wireframe_config");
+        loading_app.world_mut().commands().load_resources_from_file(path.clone());
+        loading_app.world_mut().flush();
+        std::fs::remove_file(&path).unwrap();
+
+        let loading_world = loading_app.world();
+        assert_eq!(saving_app.world().resource::<LevelSettings>(), loading_world.resource::<LevelSettings>());
+        assert!(loading_world.resource::<WireframeConfig>().global);
+        assert_eq!(Color::srgb(0.1, 0.2, 0.3), loading_world.resource::<WireframeConfig>().default_color);
+        assert!(!loading_world.contains_resource::<UnsavedResource>());
+
+        // resources missing at the time of saving are skipped
+        let mut empty_world = World::default();
+        let registry = loading_world.resource::<ByteConverterResourceRegistry>();
+        let bytes = registry.save(&empty_world).unwrap();
+        registry.load(&mut empty_world, &bytes).unwrap();
+        assert!(!empty_world.contains_resource::<LevelSettings>());
+
+        // a blob holding an unregistered key fails without inserting the resources before it
+        let mut partial_registry = ByteConverterResourceRegistry::default();
+        partial_registry.register::<LevelSettings>("level_settings").unwrap();
+
+        // reusing a key for a different resource type fails instead of replacing the registration
+        assert!(partial_registry.register::<WireframeConfig>("level_settings").is_err());
+        assert_eq!(vec!["level_settings"], partial_registry.get_registered_keys());
+        let bytes = registry.save(saving_app.world()).unwrap();
+        assert!(partial_registry.load(&mut empty_world, &bytes).is_err());
+        assert!(!empty_world.contains_resource::<LevelSettings>());
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod bit_converter_tests {
+    use bytecon::{impl_bit_packed_byte_converter, BitConverter, BitReader, BitWriter, ByteConverter};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct InputFlags {
+        is_jumping: bool,
+        is_crouching: bool,
+        is_sprinting: bool,
+        is_firing: bool,
+        weapon_slots: [bool; 6],
+        selected_item: Option<u8>,
+        aim_offset: i8,
+    }
+
+    impl_bit_packed_byte_converter!(InputFlags {
+        is_jumping: bool,
+        is_crouching: bool,
+        is_sprinting: bool,
+        is_firing: bool,
+        weapon_slots: [bool; 6],
+        selected_item: Option<u8>,
+        aim_offset: i8,
+    });
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct InputPacket {
+        frame: u32,
+        flags: InputFlags,
+        symbol: char,
+    }
+
+    impl_bit_packed_byte_converter!(InputPacket {
+        frame: u32,
+        flags: InputFlags,
+        symbol: char,
+    });
+
+    #[test]
+    fn test_b8w3_bit_writer_and_reader() {
+        let mut bit_writer = BitWriter::new();
+        bit_writer.write_bool(true);
+        bit_writer.write_bits(0b101, 3);
+        bit_writer.write_bits(u64::MAX, 64);
+        bit_writer.write_bits(0b11, 2);
+        assert_eq!(70, bit_writer.bit_length());
+        let bytes = bit_writer.into_bytes();
+        assert_eq!(9, bytes.len());
+
+        let mut bit_reader = BitReader::new(&bytes);
+        assert!(bit_reader.read_bool().unwrap());
+        assert_eq!(0b101, bit_reader.read_bits(3).unwrap());
+        assert_eq!(u64::MAX, bit_reader.read_bits(64).unwrap());
+        assert_eq!(0b11, bit_reader.read_bits(2).unwrap());
+        assert_eq!(70, bit_reader.bit_index());
+        // the padding of the last byte can be read, but nothing past it
+        assert_eq!(0, bit_reader.read_bits(2).unwrap());
+        assert!(bit_reader.read_bits(1).is_err());
+        assert!(BitReader::new(&bytes).read_bits(65).is_err());
+    }
+
+    #[test]
+    fn test_p5k2_primitive_bit_converters() {
+        let mut bit_writer = BitWriter::new();
+        (-3i16).append_to_bits(&mut bit_writer).unwrap();
+        u64::MAX.append_to_bits(&mut bit_writer).unwrap();
+        'é'.append_to_bits(&mut bit_writer).unwrap();
+        (-0.5f32).append_to_bits(&mut bit_writer).unwrap();
+        f64::MIN_POSITIVE.append_to_bits(&mut bit_writer).unwrap();
+        None::<u32>.append_to_bits(&mut bit_writer).unwrap();
+        Some(7u32).append_to_bits(&mut bit_writer).unwrap();
+        assert_eq!(16 + 64 + 21 + 32 + 64 + 33 + 33, bit_writer.bit_length());
+        let bytes = bit_writer.into_bytes();
+
+        let mut bit_reader = BitReader::new(&bytes);
+        assert_eq!(-3i16, i16::extract_from_bits(&mut bit_reader).unwrap());
+        assert_eq!(u64::MAX, u64::extract_from_bits(&mut bit_reader).unwrap());
+        assert_eq!('é', char::extract_from_bits(&mut bit_reader).unwrap());
+        assert_eq!(-0.5f32, f32::extract_from_bits(&mut bit_reader).unwrap());
+        assert_eq!(f64::MIN_POSITIVE, f64::extract_from_bits(&mut bit_reader).unwrap());
+        assert_eq!(None, Option::<u32>::extract_from_bits(&mut bit_reader).unwrap());
+        assert_eq!(Some(7), Option::<u32>::extract_from_bits(&mut bit_reader).unwrap());
+    }
+
+    #[test]
+    fn test_f4n7_bit_packed_structs() {
+        assert_eq!(4 + 6 + 9 + 8, InputFlags::BIT_LENGTH);
+        let input_flags = InputFlags {
+            is_jumping: true,
+            is_crouching: false,
+            is_sprinting: true,
+            is_firing: true,
+            weapon_slots: [false, true, false, false, true, true],
+            selected_item: Some(200),
+            aim_offset: -100,
+        };
+        let bytes = input_flags.to_vec_bytes().unwrap();
+        // 27 bits instead of the 17 bytes of the byte aligned fields
+        assert_eq!(4, bytes.len());
+        assert_eq!(input_flags, input_flags.clone_via_bytes().unwrap());
+
+        let input_packet = InputPacket {
+            frame: 123_456,
+            flags: InputFlags {
+                selected_item: None,
+                ..input_flags
+            },
+            symbol: 'w',
+        };
+        assert_eq!(32 + 27 + 21, InputPacket::BIT_LENGTH);
+        assert_eq!(10, input_packet.to_vec_bytes().unwrap().len());
+        assert_eq!(input_packet, input_packet.clone_via_bytes().unwrap());
+
+        // bit packed structs can be mixed with byte aligned values
+        let mixed = (String::from("packet"), input_packet.clone(), 9u8);
+        assert_eq!(mixed, mixed.clone_via_bytes().unwrap());
+
+        let truncated_bytes = &input_packet.to_vec_bytes().unwrap()[..9];
+        assert!(InputPacket::deserialize_from_bytes(&truncated_bytes).is_err());
+    }
+
+    #[test]
+    fn test_c9j1_invalid_char_bits() {
+        let mut bit_writer = BitWriter::new();
+        // a surrogate code point is not a valid char
+        bit_writer.write_bits(0xD800, 21);
+        let bytes = bit_writer.into_bytes();
+        assert!(char::extract_from_bits(&mut BitReader::new(&bytes)).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+#[cfg(feature = "base")]
+mod blocking_tests {
+    use std::{io::{Cursor, ErrorKind, Read, Write}, net::{TcpListener, TcpStream}, time::Duration};
+    use bytecon::{blocking::BlockingByteStream, ByteConverter, ByteStreamReader, ByteStreamWriter};
+
+    // returns at most one byte per read, interrupting every other read
+    struct OneBytePerRead {
+        bytes: Cursor<Vec<u8>>,
+        is_interrupted: bool,
+    }
+
+    impl Read for OneBytePerRead {
+        fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+            self.is_interrupted = !self.is_interrupted;
+            if self.is_interrupted {
+                return Err(std::io::Error::from(ErrorKind::Interrupted));
+            }
+            let length = buffer.len().min(1);
+            self.bytes.read(&mut buffer[..length])
+        }
+    }
+
+    // accepts at most one byte per write
+    struct OneBytePerWrite {
+        bytes: Vec<u8>,
+        flush_count: usize,
+    }
+
+    impl Write for OneBytePerWrite {
+        fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+            let length = buffer.len().min(1);
+            self.bytes.extend_from_slice(&buffer[..length]);
+            Ok(length)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.flush_count += 1;
+            Ok(())
+        }
+    }
+
+    fn get_values() -> Vec<String> {
+        vec![String::new(), String::from("short"), "long ".repeat(100_000)]
+    }
+
+    fn get_io_error_kind<T: std::fmt::Debug>(result: Result<T, Box<dyn std::error::Error + Send + Sync + 'static>>) -> ErrorKind {
+        result.unwrap_err()
+            .downcast_ref::<std::io::Error>()
+            .expect("Expected an io error.")
+            .kind()
+    }
+
+    #[test]
+    fn test_b9p1_partial_reads_and_writes() {
+        let mut writer = OneBytePerWrite { bytes: Vec::new(), flush_count: 0 };
+        for value in get_values() {
+            writer.write_from_byte_converter(&value).unwrap();
+        }
+        // the blanket writer leaves flushing to the caller
+        assert_eq!(0, writer.flush_count);
+
+        let mut reader = OneBytePerRead { bytes: Cursor::new(writer.bytes.clone()), is_interrupted: false };
+        for value in get_values() {
+            assert_eq!(value, reader.read_to_byte_converter::<String>().unwrap());
+        }
+
+        // the wrapper produces the same frames and can read them back in pieces
+        let mut stream = BlockingByteStream::new(OneBytePerWrite { bytes: Vec::new(), flush_count: 0 });
+        for value in get_values() {
+            stream.write_from_byte_converter(&value).unwrap();
+        }
+        assert_eq!(writer.bytes, stream.get_ref().bytes);
+        assert_eq!(3, stream.get_ref().flush_count);
+        let mut stream = BlockingByteStream::new(OneBytePerRead { bytes: Cursor::new(writer.bytes), is_interrupted: false });
+        for value in get_values() {
+            assert_eq!(value, stream.read_to_byte_converter::<String>().unwrap());
+        }
+        assert!(!stream.has_partial_frame());
+        assert_eq!(0, stream.buffered_length());
+
+        // the buffer grown for the long value is released once it has been read
+        assert!(stream.read_buffer_capacity() <= 64 * 1024);
+    }
+
+    #[test]
+    fn test_b9e2_end_of_stream_is_an_error() {
+        let bytes = String::from("truncated").to_vec_bytes().unwrap();
+        let mut frame = Vec::new();
+        frame.write_from_byte_converter(&String::from("truncated")).unwrap();
+        assert_eq!(8 + bytes.len(), frame.len());
+
+        for length in [0, 4, 8, frame.len() - 1] {
+            let mut reader = Cursor::new(frame[..length].to_vec());
+            assert_eq!(ErrorKind::UnexpectedEof, get_io_error_kind(reader.read_to_byte_converter::<String>()));
+            let mut stream = BlockingByteStream::new(OneBytePerRead { bytes: Cursor::new(frame[..length].to_vec()), is_interrupted: false });
+            assert_eq!(ErrorKind::UnexpectedEof, get_io_error_kind(stream.read_to_byte_converter::<String>()));
+        }
+
+        // a corrupted length does not allocate more than the stream holds before failing
+        let mut corrupted = u64::MAX.to_le_bytes().to_vec();
+        corrupted.extend_from_slice(&[0u8; 16]);
+        assert!(Cursor::new(corrupted.clone()).read_to_byte_converter::<Vec<u8>>().is_err());
+        assert!(BlockingByteStream::new(Cursor::new(corrupted)).read_to_byte_converter::<Vec<u8>>().is_err());
+    }
+
+    #[test]
+    fn test_b9t3_read_timeout_resumes_frame() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut writer = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let mut stream = BlockingByteStream::new(listener.accept().unwrap().0);
+        stream.set_read_timeout(Some(Duration::from_millis(50))).unwrap();
+
+        let mut frame = Vec::new();
+        frame.write_from_byte_converter(&vec![7u32; 1000]).unwrap();
+        writer.write_all(&frame[..100]).unwrap();
+
+        // the frame is incomplete, so the read times out while keeping what it already read
+        let error_kind = get_io_error_kind(stream.read_to_byte_converter::<Vec<u32>>());
+        assert!(matches!(error_kind, ErrorKind::WouldBlock | ErrorKind::TimedOut));
+        assert!(stream.has_partial_frame());
+        assert_eq!(100, stream.buffered_length());
+
+        writer.write_all(&frame[100..]).unwrap();
+        writer.write_from_byte_converter(&String::from("next")).unwrap();
+        assert_eq!(vec![7u32; 1000], stream.read_to_byte_converter::<Vec<u32>>().unwrap());
+        assert_eq!("next", stream.read_to_byte_converter::<String>().unwrap());
+
+        drop(writer);
+        assert_eq!(ErrorKind::UnexpectedEof, get_io_error_kind(stream.read_to_byte_converter::<String>()));
+    }
+}
@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod byte_converter_tests {
-    use std::{collections::HashMap, error::Error, ffi::CString, io::Cursor, path::PathBuf, sync::Mutex};
-    use bevy::{input::{keyboard::NativeKeyCode, mouse::MouseScrollUnit}, prelude::{Entity, KeyCode, MouseButton}};
-    use bytecon::{ByteConverter, ByteStreamReader, ByteStreamWriter, Context, DeserializationByteConverterFactory};
+    use std::{collections::{HashMap, VecDeque}, error::Error, ffi::CString, io::Cursor, path::PathBuf, sync::Mutex};
+    use bevy::{input::{gamepad::{GamepadAxisChangedEvent, GamepadButtonChangedEvent, GamepadButtonStateChangedEvent, GamepadConnection, GamepadConnectionEvent, GamepadEvent}, keyboard::{Key, KeyboardInput, NativeKey, NativeKeyCode}, mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel}, touch::{ForceTouch, TouchPhase}, ButtonState}, prelude::{ButtonInput, Entity, GamepadAxis, GamepadButton, KeyCode, MouseButton, TouchInput, Vec2}};
+    use bytecon::{impl_bit_packed_byte_converter, rand::ChaChaRngState, BitConverter, ByteConverter, ByteStreamReader, ByteStreamWriter, Context, DeserializationByteConverterFactory};
     use rand::{Rng, SeedableRng};
     use rand_chacha::{ChaCha20Rng, ChaCha8Rng};
 
@@ -353,6 +353,15 @@ mod byte_converter_tests {
         assert_eq!(obj, cloned_obj);
     }
 
+    #[test]
+    fn test_p2c8_forged_length_of_large_elements() {
+        // every remaining byte could start an element, but reserving that many 4 KiB elements would take 16 GiB
+        let mut bytes = (usize::MAX / 2).to_vec_bytes().unwrap();
+        bytes.resize(bytes.len() + 4 * 1024 * 1024, 0);
+        assert!(Vec::<[u8; 4096]>::deserialize_from_bytes(&bytes).is_err());
+        assert!(VecDeque::<[u8; 4096]>::deserialize_from_bytes(&bytes).is_err());
+    }
+
     #[test]
     fn test_t4l8_rand_chacha_8_entropy() {
         let obj = ChaCha8Rng::from_entropy();
@@ -381,6 +390,30 @@ mod byte_converter_tests {
         assert_eq!(obj, cloned_obj);
     }
     
+    #[test]
+    fn test_f7h2_rand_chacha_keeps_stream_position() {
+        let mut obj = ChaCha8Rng::seed_from_u64(42);
+        obj.set_stream(3);
+        for _ in 0..17 {
+            let _: u32 = obj.gen();
+        }
+        let ChaChaRngState(mut cloned_obj) = ChaChaRngState(obj.clone()).clone_via_bytes().unwrap();
+        assert_eq!(obj, cloned_obj);
+        for _ in 0..5 {
+            assert_eq!(obj.gen::<u64>(), cloned_obj.gen::<u64>());
+        }
+
+        let mut obj = ChaCha20Rng::seed_from_u64(42);
+        let _: u128 = obj.gen();
+        let ChaChaRngState(mut cloned_obj) = ChaChaRngState(obj.clone()).clone_via_bytes().unwrap();
+        assert_eq!(obj.gen::<u64>(), cloned_obj.gen::<u64>());
+
+        // the plain rng keeps its original seed only format and restarts at the beginning of its stream
+        let bytes = obj.to_vec_bytes().unwrap();
+        assert_eq!(32, bytes.len());
+        assert_eq!(ChaCha20Rng::from_seed(obj.get_seed()), ChaCha20Rng::deserialize_from_bytes(&bytes).unwrap());
+    }
+
     #[test]
     fn test_l2c6_bevy_keys() {
         let key_codes = vec![
@@ -616,6 +649,48 @@ mod byte_converter_tests {
         }
     }
     
+    #[derive(Clone, Debug, PartialEq)]
+    struct BevyInputBits {
+        button_state: ButtonState,
+        mouse_button: MouseButton,
+        visibility: bevy::prelude::Visibility,
+        interaction: bevy::prelude::Interaction,
+        focus_policy: bevy::ui::FocusPolicy,
+    }
+
+    impl_bit_packed_byte_converter!(BevyInputBits {
+        button_state: ButtonState,
+        mouse_button: MouseButton,
+        visibility: bevy::prelude::Visibility,
+        interaction: bevy::prelude::Interaction,
+        focus_policy: bevy::ui::FocusPolicy,
+    });
+
+    #[test]
+    fn test_r6m2_bevy_bit_packed_enums() {
+        assert_eq!(1 + 19 + 2 + 2 + 1, BevyInputBits::BIT_LENGTH);
+        let mouse_buttons = vec![
+            MouseButton::Back,
+            MouseButton::Forward,
+            MouseButton::Left,
+            MouseButton::Middle,
+            MouseButton::Other(u16::MAX),
+            MouseButton::Right,
+        ];
+        for mouse_button in mouse_buttons {
+            let bevy_input_bits = BevyInputBits {
+                button_state: ButtonState::Released,
+                mouse_button,
+                visibility: bevy::prelude::Visibility::Hidden,
+                interaction: bevy::prelude::Interaction::Hovered,
+                focus_policy: bevy::ui::FocusPolicy::Pass,
+            };
+            let bytes = bevy_input_bits.to_vec_bytes().unwrap();
+            assert_eq!(4, bytes.len());
+            assert_eq!(bevy_input_bits, BevyInputBits::deserialize_from_bytes(&bytes).unwrap());
+        }
+    }
+
     #[test]
     fn test_u7v9_bevy_mouse_scroll_unit() {
         let mouse_scroll_units = vec![
@@ -628,6 +703,184 @@ mod byte_converter_tests {
         }
     }
 
+    #[test]
+    fn test_k8d3_bevy_keyboard_input() {
+        let keyboard_inputs = vec![
+            KeyboardInput {
+                key_code: KeyCode::KeyA,
+                logical_key: Key::Character("a".into()),
+                state: ButtonState::Pressed,
+                text: Some("a".into()),
+                repeat: false,
+                window: Entity::from_raw(3),
+            },
+            KeyboardInput {
+                key_code: KeyCode::Escape,
+                logical_key: Key::Escape,
+                state: ButtonState::Released,
+                text: None,
+                repeat: true,
+                window: Entity::from_raw(0),
+            },
+            KeyboardInput {
+                key_code: KeyCode::Unidentified(NativeKeyCode::Xkb(42)),
+                logical_key: Key::Unidentified(NativeKey::Web("Quote".into())),
+                state: ButtonState::Pressed,
+                text: None,
+                repeat: false,
+                window: Entity::from_raw(1),
+            },
+        ];
+
+        for keyboard_input in keyboard_inputs {
+            assert_eq!(keyboard_input, keyboard_input.clone_via_bytes().unwrap());
+        }
+
+        let keys = vec![
+            Key::Dead(None),
+            Key::Dead(Some('^')),
+            Key::Unidentified(NativeKey::Unidentified),
+            Key::Unidentified(NativeKey::Android(7)),
+            Key::Unidentified(NativeKey::MacOS(8)),
+            Key::Unidentified(NativeKey::Windows(9)),
+            Key::AltGraph,
+            Key::MediaPlayPause,
+            Key::F35,
+        ];
+
+        for key in keys {
+            assert_eq!(key, key.clone_via_bytes().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_p3f7_bevy_mouse_input() {
+        let mouse_button_input = MouseButtonInput {
+            button: MouseButton::Other(9),
+            state: ButtonState::Released,
+            window: Entity::from_raw(2),
+        };
+        assert_eq!(mouse_button_input, mouse_button_input.clone_via_bytes().unwrap());
+
+        let mouse_motion = MouseMotion {
+            delta: Vec2::new(-1.5, 3.25),
+        };
+        assert_eq!(mouse_motion, mouse_motion.clone_via_bytes().unwrap());
+
+        let mouse_wheel = MouseWheel {
+            unit: MouseScrollUnit::Pixel,
+            x: 0.5,
+            y: -12.0,
+            window: Entity::from_raw(2),
+        };
+        assert_eq!(mouse_wheel, mouse_wheel.clone_via_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_g9a4_bevy_gamepad_events() {
+        let gamepad = Entity::from_raw(5);
+        let gamepad_events = vec![
+            GamepadEvent::Connection(GamepadConnectionEvent::new(gamepad, GamepadConnection::Connected {
+                name: String::from("Test Pad"),
+                vendor_id: Some(0x045e),
+                product_id: None,
+            })),
+            GamepadEvent::Connection(GamepadConnectionEvent::new(gamepad, GamepadConnection::Disconnected)),
+            GamepadEvent::Button(GamepadButtonChangedEvent::new(gamepad, GamepadButton::RightTrigger2, ButtonState::Pressed, 0.75)),
+            GamepadEvent::Button(GamepadButtonChangedEvent::new(gamepad, GamepadButton::Other(200), ButtonState::Released, 0.0)),
+            GamepadEvent::Axis(GamepadAxisChangedEvent::new(gamepad, GamepadAxis::LeftStickX, -0.25)),
+            GamepadEvent::Axis(GamepadAxisChangedEvent::new(gamepad, GamepadAxis::Other(3), 1.0)),
+        ];
+
+        for gamepad_event in gamepad_events {
+            assert_eq!(gamepad_event, gamepad_event.clone_via_bytes().unwrap());
+        }
+
+        let gamepad_button_state_changed_event = GamepadButtonStateChangedEvent::new(gamepad, GamepadButton::DPadLeft, ButtonState::Pressed);
+        assert_eq!(gamepad_button_state_changed_event, gamepad_button_state_changed_event.clone_via_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_t5c1_bevy_touch_input() {
+        let touch_inputs = vec![
+            TouchInput {
+                phase: TouchPhase::Started,
+                position: Vec2::new(10.0, 20.0),
+                window: Entity::from_raw(1),
+                force: None,
+                id: 0,
+            },
+            TouchInput {
+                phase: TouchPhase::Moved,
+                position: Vec2::new(11.0, 21.0),
+                window: Entity::from_raw(1),
+                force: Some(ForceTouch::Normalized(0.5)),
+                id: 0,
+            },
+            TouchInput {
+                phase: TouchPhase::Canceled,
+                position: Vec2::ZERO,
+                window: Entity::from_raw(1),
+                force: Some(ForceTouch::Calibrated {
+                    force: 1.5,
+                    max_possible_force: 6.0,
+                    altitude_angle: Some(0.3),
+                }),
+                id: u64::MAX,
+            },
+        ];
+
+        for touch_input in touch_inputs {
+            assert_eq!(touch_input, touch_input.clone_via_bytes().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_b6r2_bevy_button_input() {
+        let mut button_input = ButtonInput::<KeyCode>::default();
+        button_input.press(KeyCode::KeyW);
+        button_input.press(KeyCode::ShiftLeft);
+        button_input.press(KeyCode::Space);
+        button_input.clear();
+        button_input.press(KeyCode::KeyD);
+        button_input.release(KeyCode::Space);
+
+        let cloned_button_input = button_input.clone_via_bytes().unwrap();
+        for key_code in [KeyCode::KeyW, KeyCode::ShiftLeft, KeyCode::Space, KeyCode::KeyD, KeyCode::KeyA] {
+            assert_eq!(button_input.pressed(key_code), cloned_button_input.pressed(key_code));
+            assert_eq!(button_input.just_pressed(key_code), cloned_button_input.just_pressed(key_code));
+            assert_eq!(button_input.just_released(key_code), cloned_button_input.just_released(key_code));
+        }
+        assert!(cloned_button_input.just_pressed(KeyCode::KeyD));
+        assert!(cloned_button_input.just_released(KeyCode::Space));
+        assert!(cloned_button_input.pressed(KeyCode::KeyW));
+        assert!(!cloned_button_input.just_pressed(KeyCode::KeyW));
+    }
+
+    #[test]
+    fn test_b6p3_bevy_button_input_same_frame_press_and_release() {
+        let mut button_input = ButtonInput::<KeyCode>::default();
+        button_input.press(KeyCode::KeyW);
+        button_input.clear();
+        button_input.press(KeyCode::KeyE);
+        button_input.release(KeyCode::KeyE);
+        button_input.release(KeyCode::KeyW);
+        button_input.press(KeyCode::KeyW);
+
+        let cloned_button_input = button_input.clone_via_bytes().unwrap();
+        for key_code in [KeyCode::KeyE, KeyCode::KeyW] {
+            assert_eq!(button_input.pressed(key_code), cloned_button_input.pressed(key_code));
+            assert_eq!(button_input.just_pressed(key_code), cloned_button_input.just_pressed(key_code));
+            assert_eq!(button_input.just_released(key_code), cloned_button_input.just_released(key_code));
+        }
+        assert!(!cloned_button_input.pressed(KeyCode::KeyE));
+        assert!(cloned_button_input.just_pressed(KeyCode::KeyE));
+        assert!(cloned_button_input.just_released(KeyCode::KeyE));
+        assert!(cloned_button_input.pressed(KeyCode::KeyW));
+        assert!(cloned_button_input.just_pressed(KeyCode::KeyW));
+        assert!(cloned_button_input.just_released(KeyCode::KeyW));
+    }
+
     #[test]
     fn test_j2s9_bevy_entity_byte_converter() {
         let entities = vec![
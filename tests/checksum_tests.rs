@@ -0,0 +1,106 @@
+#[cfg(test)]
+#[cfg(feature = "checksum")]
+mod checksum_tests {
+    use std::io::Cursor;
+    use bytecon::{checksum::{ChecksumAlgorithmKind, ChecksumMismatch, Checksummed, ChecksummedByteStream, Crc32c, FrameLengthExceeded, Xxh3}, ByteConverter, ByteStreamReader, ByteStreamWriter};
+
+    fn assert_checksum_mismatch<T: std::fmt::Debug>(result: Result<T, Box<dyn std::error::Error + Send + Sync + 'static>>, algorithm: ChecksumAlgorithmKind) {
+        let error = result.unwrap_err();
+        let checksum_mismatch = error.downcast_ref::<ChecksumMismatch>()
+            .unwrap_or_else(|| panic!("Expected a checksum mismatch but found {error}."));
+        assert_eq!(algorithm, checksum_mismatch.algorithm);
+        assert_ne!(checksum_mismatch.expected, checksum_mismatch.actual);
+    }
+
+    #[test]
+    fn test_k3c1_checksummed_round_trip_and_corruption() {
+        let text = String::from("integrity matters");
+
+        let bytes = Checksummed::<String, Crc32c>::new(text.clone()).to_vec_bytes().unwrap();
+        assert_eq!(text.to_vec_bytes().unwrap().to_vec_bytes().unwrap().len() + 4, bytes.len());
+        assert_eq!(text, Checksummed::<String, Crc32c>::deserialize_from_bytes(&bytes).unwrap().value);
+
+        let bytes = Checksummed::<String, Xxh3>::new(text.clone()).to_vec_bytes().unwrap();
+        assert_eq!(text.to_vec_bytes().unwrap().to_vec_bytes().unwrap().len() + 8, bytes.len());
+        assert_eq!(text, Checksummed::<String, Xxh3>::deserialize_from_bytes(&bytes).unwrap().value);
+
+        // flip every bit after the length prefix of the value bytes in turn
+        let length_prefix_length = bytes.len() - 8 - text.to_vec_bytes().unwrap().len();
+        for bit_index in (length_prefix_length * 8)..(bytes.len() * 8) {
+            let mut corrupted_bytes = bytes.clone();
+            corrupted_bytes[bit_index / 8] ^= 1 << (bit_index % 8);
+            assert_checksum_mismatch(Checksummed::<String, Xxh3>::deserialize_from_bytes(&corrupted_bytes), ChecksumAlgorithmKind::Xxh3);
+        }
+    }
+
+    #[test]
+    fn test_k3l2_stream_corrupted_length() {
+        for algorithm in [ChecksumAlgorithmKind::Crc32c, ChecksumAlgorithmKind::Xxh3] {
+            let mut stream = ChecksummedByteStream::new(Cursor::new(Vec::new()), algorithm);
+            stream.write_from_byte_converter(&vec![7u32; 16]).unwrap();
+            let bytes = stream.into_inner().into_inner();
+
+            // a flipped high bit of the length would otherwise ask for an enormous message
+            let mut corrupted_bytes = bytes.clone();
+            corrupted_bytes[5] ^= 0b0100_0000;
+            let mut stream = ChecksummedByteStream::new(Cursor::new(corrupted_bytes), algorithm);
+            assert_checksum_mismatch(stream.read_to_byte_converter::<Vec<u32>>(), algorithm);
+
+            let mut stream = ChecksummedByteStream::new(Cursor::new(bytes), algorithm);
+            assert_eq!(vec![7u32; 16], stream.read_to_byte_converter::<Vec<u32>>().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_k3m4_stream_frame_length_above_maximum() {
+        // a length with a valid checksum is still rejected before anything is allocated for it
+        let algorithm = ChecksumAlgorithmKind::Crc32c;
+        let length_bytes = u64::MAX.to_le_bytes();
+        let mut bytes = length_bytes.to_vec();
+        bytes.extend_from_slice(&(algorithm.checksum(&length_bytes) as u32).to_le_bytes());
+        let mut stream = ChecksummedByteStream::new(Cursor::new(bytes), algorithm);
+        let error = stream.read_to_byte_converter::<Vec<u8>>().unwrap_err();
+        let frame_length_exceeded = error.downcast_ref::<FrameLengthExceeded>().unwrap();
+        assert_eq!(u64::MAX, frame_length_exceeded.frame_length);
+
+        let mut stream = ChecksummedByteStream::new(Cursor::new(Vec::new()), algorithm);
+        stream.write_from_byte_converter(&vec![7u8; 64]).unwrap();
+        let bytes = stream.into_inner().into_inner();
+        let mut stream = ChecksummedByteStream::new(Cursor::new(bytes.clone()), algorithm).with_max_frame_length(32);
+        assert!(stream.read_to_byte_converter::<Vec<u8>>().unwrap_err().is::<FrameLengthExceeded>());
+        let mut stream = ChecksummedByteStream::new(Cursor::new(bytes), algorithm).with_max_frame_length(128);
+        assert_eq!(vec![7u8; 64], stream.read_to_byte_converter::<Vec<u8>>().unwrap());
+    }
+
+    #[test]
+    fn test_k3p3_stream_corrupted_message() {
+        let mut stream = ChecksummedByteStream::new(Cursor::new(Vec::new()), ChecksumAlgorithmKind::Crc32c);
+        stream.write_from_byte_converter(&String::from("first")).unwrap();
+        stream.write_from_byte_converter(&String::from("second")).unwrap();
+        let mut bytes = stream.into_inner().into_inner();
+
+        // corrupt the last byte of the second message, just before its checksum
+        let last_message_byte_index = bytes.len() - 4 - 1;
+        bytes[last_message_byte_index] ^= 1;
+        let mut stream = ChecksummedByteStream::new(Cursor::new(bytes), ChecksumAlgorithmKind::Crc32c);
+        assert_eq!("first", stream.read_to_byte_converter::<String>().unwrap());
+        assert_checksum_mismatch(stream.read_to_byte_converter::<String>(), ChecksumAlgorithmKind::Crc32c);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_k3a5_stream_async() {
+        use bytecon::{ByteStreamReaderAsync, ByteStreamWriterAsync};
+
+        let (client, server) = tokio::io::duplex(1024);
+        let mut client = ChecksummedByteStream::new(client, ChecksumAlgorithmKind::Xxh3);
+        let mut server = ChecksummedByteStream::new(server, ChecksumAlgorithmKind::Xxh3);
+        let values: Vec<u64> = (0..512).collect();
+        let writer = tokio::spawn(async move {
+            ByteStreamWriterAsync::write_from_byte_converter(&mut client, &values).await.unwrap();
+        });
+        let received = ByteStreamReaderAsync::read_to_byte_converter::<Vec<u64>>(&mut server).await.unwrap();
+        writer.await.unwrap();
+        assert_eq!((0..512).collect::<Vec<u64>>(), received);
+    }
+}
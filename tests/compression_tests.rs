@@ -0,0 +1,201 @@
+#[cfg(test)]
+#[cfg(any(feature = "zstd", feature = "lz4", feature = "deflate"))]
+mod compression_tests {
+    use std::{io::Cursor, net::{TcpListener, TcpStream}};
+    use bytecon::{compression::{CompressedByteStream, CompressionAlgorithmKind, CompressionSettings, UncompressedLengthExceeded, DEFAULT_MAX_UNCOMPRESSED_LENGTH}, ByteConverter, ByteStreamReader, ByteStreamWriter};
+
+    fn repetitive_text() -> String {
+        "the quick brown fox jumps over the lazy dog ".repeat(64)
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_c4z1_compressed_zstd_round_trip() {
+        use bytecon::compression::{Compressed, Zstd};
+
+        let text = repetitive_text();
+        let compressed = Compressed::<String, Zstd>::new(text.clone());
+        let bytes = compressed.to_vec_bytes().unwrap();
+        assert!(bytes.len() < text.to_vec_bytes().unwrap().len());
+        assert_eq!(1u8, bytes[0]);
+        let extracted = Compressed::<String, Zstd>::deserialize_from_bytes(&bytes).unwrap();
+        assert_eq!(text, extracted.value);
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn test_c4l2_compressed_lz4_round_trip() {
+        use bytecon::compression::{Compressed, Lz4};
+
+        let values: Vec<u32> = (0..1024).map(|value| value % 16).collect();
+        let compressed = Compressed::<Vec<u32>, Lz4>::new(values.clone());
+        let bytes = compressed.to_vec_bytes().unwrap();
+        assert!(bytes.len() < values.to_vec_bytes().unwrap().len());
+        assert_eq!(2u8, bytes[0]);
+        let extracted = Compressed::<Vec<u32>, Lz4>::deserialize_from_bytes(&bytes).unwrap();
+        assert_eq!(values, extracted.value);
+    }
+
+    #[cfg(feature = "deflate")]
+    #[test]
+    fn test_c4d3_compressed_deflate_round_trip() {
+        use bytecon::compression::{Compressed, Deflate};
+
+        let text = repetitive_text();
+        let compressed = Compressed::<String, Deflate>::new(text.clone());
+        let bytes = compressed.to_vec_bytes().unwrap();
+        assert!(bytes.len() < text.to_vec_bytes().unwrap().len());
+        assert_eq!(3u8, bytes[0]);
+        let extracted = Compressed::<String, Deflate>::deserialize_from_bytes(&bytes).unwrap();
+        assert_eq!(text, extracted.value);
+
+        // a recorded length that does not match the payload is rejected
+        let mut tampered_bytes = bytes.clone();
+        tampered_bytes[1] = tampered_bytes[1].wrapping_add(1);
+        assert!(Compressed::<String, Deflate>::deserialize_from_bytes(&tampered_bytes).is_err());
+    }
+
+    #[test]
+    fn test_c4b8_forged_uncompressed_length_is_rejected() {
+        fn assert_length_exceeded<T: std::fmt::Debug>(result: Result<T, Box<dyn std::error::Error + Send + Sync + 'static>>, uncompressed_length: usize, max_uncompressed_length: usize) {
+            let error = result.unwrap_err();
+            let length_exceeded = error.downcast_ref::<UncompressedLengthExceeded>()
+                .expect("Expected the uncompressed length to be rejected.");
+            assert_eq!(&UncompressedLengthExceeded { uncompressed_length, max_uncompressed_length }, length_exceeded);
+        }
+
+        // the algorithm byte is followed by the usize size byte and the forged length, which no allocator could satisfy
+        let forged_length = usize::MAX / 2;
+        for algorithm in CompressionAlgorithmKind::supported() {
+            let mut frame = Vec::new();
+            algorithm.append_to_bytes(&mut frame).unwrap();
+            forged_length.append_to_bytes(&mut frame).unwrap();
+            frame.extend_from_slice(&algorithm.compress(b"tiny").unwrap());
+            // the frame is written by hand since it takes up the whole length prefixed stream frame
+            let mut stream_bytes = (frame.len() as u64).to_le_bytes().to_vec();
+            stream_bytes.extend_from_slice(&frame);
+
+            let mut stream = CompressedByteStream::new(Cursor::new(stream_bytes), CompressionSettings::default());
+            assert_length_exceeded(stream.read_to_byte_converter::<String>(), forged_length, DEFAULT_MAX_UNCOMPRESSED_LENGTH);
+            assert_length_exceeded(algorithm.decompress(&frame, forged_length, 1024), forged_length, 1024);
+        }
+
+        #[cfg(feature = "zstd")]
+        {
+            use bytecon::compression::{Compressed, Zstd};
+
+            let mut bytes = Compressed::<String, Zstd>::new(repetitive_text()).to_vec_bytes().unwrap();
+            bytes[2..10].copy_from_slice(&(forged_length as u64).to_le_bytes());
+            assert_length_exceeded(Compressed::<String, Zstd>::deserialize_from_bytes(&bytes), forged_length, DEFAULT_MAX_UNCOMPRESSED_LENGTH);
+
+            // the maximum is part of the type, so a smaller one rejects honest values that are too large for it
+            let bytes = Compressed::<String, Zstd>::new(repetitive_text()).to_vec_bytes().unwrap();
+            let uncompressed_length = repetitive_text().to_vec_bytes().unwrap().len();
+            assert_length_exceeded(Compressed::<String, Zstd, 1024>::deserialize_from_bytes(&bytes), uncompressed_length, 1024);
+        }
+    }
+
+    #[cfg(all(feature = "zstd", feature = "lz4"))]
+    #[test]
+    fn test_c4m4_compressed_algorithm_mismatch() {
+        use bytecon::compression::{Compressed, Lz4, Zstd};
+
+        let bytes = Compressed::<String, Zstd>::new(repetitive_text()).to_vec_bytes().unwrap();
+        assert!(Compressed::<String, Lz4>::deserialize_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_c4t5_stream_threshold() {
+        let settings = CompressionSettings {
+            threshold: 256,
+            ..Default::default()
+        };
+        let small_text = String::from("small");
+        let large_text = repetitive_text();
+
+        let mut stream = CompressedByteStream::new(Cursor::new(Vec::new()), settings);
+        assert!(stream.algorithm().is_some());
+        stream.write_from_byte_converter(&small_text).unwrap();
+        let small_length = stream.get_ref().get_ref().len();
+        stream.write_from_byte_converter(&large_text).unwrap();
+        let large_length = stream.get_ref().get_ref().len() - small_length;
+
+        // the small message is only prefixed by the uncompressed flag byte
+        assert_eq!(8 + 1 + small_text.to_vec_bytes().unwrap().len(), small_length);
+        assert!(large_length < large_text.to_vec_bytes().unwrap().len());
+
+        let mut stream = CompressedByteStream::new(Cursor::new(stream.into_inner().into_inner()), settings);
+        assert_eq!(small_text, stream.read_to_byte_converter::<String>().unwrap());
+        assert_eq!(large_text, stream.read_to_byte_converter::<String>().unwrap());
+    }
+
+    #[cfg(all(feature = "zstd", feature = "checksum"))]
+    #[test]
+    fn test_c4k9_compressed_checksummed_stream() {
+        use bytecon::checksum::{ChecksumAlgorithmKind, ChecksummedByteStream};
+
+        let text = "checksummed and compressed ".repeat(100);
+        let checksummed_stream = ChecksummedByteStream::new(Cursor::new(Vec::new()), ChecksumAlgorithmKind::Xxh3);
+        let mut stream = CompressedByteStream::new(checksummed_stream, CompressionSettings::default());
+        stream.write_from_byte_converter(&text).unwrap();
+        let bytes = stream.into_inner().into_inner().into_inner();
+        assert!(bytes.len() < text.len());
+
+        let checksummed_stream = ChecksummedByteStream::new(Cursor::new(bytes), ChecksumAlgorithmKind::Xxh3);
+        let mut stream = CompressedByteStream::new(checksummed_stream, CompressionSettings::default());
+        assert_eq!(text, stream.read_to_byte_converter::<String>().unwrap());
+    }
+
+    #[test]
+    fn test_c4n6_stream_negotiation() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut stream = CompressedByteStream::negotiate(stream, CompressionSettings::default()).unwrap();
+            let message = stream.read_to_byte_converter::<String>().unwrap();
+            stream.write_from_byte_converter(&message).unwrap();
+            stream.algorithm()
+        });
+
+        // the client only prefers the last supported algorithm, which the server also supports
+        let preferred_algorithm = *CompressionAlgorithmKind::supported().last().unwrap();
+        let settings = CompressionSettings {
+            algorithms: match preferred_algorithm {
+                CompressionAlgorithmKind::Zstd => &[CompressionAlgorithmKind::Zstd],
+                CompressionAlgorithmKind::Lz4 => &[CompressionAlgorithmKind::Lz4],
+                CompressionAlgorithmKind::Deflate => &[CompressionAlgorithmKind::Deflate],
+            },
+            threshold: 0,
+            ..Default::default()
+        };
+        let mut stream = CompressedByteStream::negotiate(TcpStream::connect(address).unwrap(), settings).unwrap();
+        assert_eq!(Some(preferred_algorithm), stream.algorithm());
+        let text = repetitive_text();
+        stream.write_from_byte_converter(&text).unwrap();
+        assert_eq!(text, stream.read_to_byte_converter::<String>().unwrap());
+        assert_eq!(CompressionAlgorithmKind::supported().first().copied(), server.join().unwrap());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_c4a7_stream_negotiation_async() {
+        use bytecon::{ByteStreamReaderAsync, ByteStreamWriterAsync};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = CompressedByteStream::negotiate_async(stream, CompressionSettings::default()).await.unwrap();
+            let message = ByteStreamReaderAsync::read_to_byte_converter::<Vec<u16>>(&mut stream).await.unwrap();
+            ByteStreamWriterAsync::write_from_byte_converter(&mut stream, &message).await.unwrap();
+        });
+
+        let stream = tokio::net::TcpStream::connect(address).await.unwrap();
+        let mut stream = CompressedByteStream::negotiate_async(stream, CompressionSettings::default()).await.unwrap();
+        let values: Vec<u16> = (0..2048).map(|value| value % 7).collect();
+        ByteStreamWriterAsync::write_from_byte_converter(&mut stream, &values).await.unwrap();
+        assert_eq!(values, ByteStreamReaderAsync::read_to_byte_converter::<Vec<u16>>(&mut stream).await.unwrap());
+        server.await.unwrap();
+    }
+}
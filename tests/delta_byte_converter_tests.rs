@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod delta_byte_converter_tests {
+    use std::collections::HashMap;
+    use bytecon::{impl_delta_byte_converter, ByteConverter, DeltaByteConverter};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        health: u32,
+        speed: f32,
+        inventory: Vec<u16>,
+        target: Option<u64>,
+    }
+
+    impl ByteConverter for Player {
+        fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+            self.name.append_to_bytes(bytes)?;
+            self.health.append_to_bytes(bytes)?;
+            self.speed.append_to_bytes(bytes)?;
+            self.inventory.append_to_bytes(bytes)?;
+            self.target.append_to_bytes(bytes)?;
+            Ok(())
+        }
+        fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn std::error::Error + Send + Sync + 'static>> where Self: Sized {
+            Ok(Self {
+                name: String::extract_from_bytes(bytes, index)?,
+                health: u32::extract_from_bytes(bytes, index)?,
+                speed: f32::extract_from_bytes(bytes, index)?,
+                inventory: Vec::<u16>::extract_from_bytes(bytes, index)?,
+                target: Option::<u64>::extract_from_bytes(bytes, index)?,
+            })
+        }
+    }
+
+    impl_delta_byte_converter!(Player { name, health, speed, inventory, target });
+
+    fn assert_delta_round_trip<T: DeltaByteConverter + PartialEq + std::fmt::Debug>(value: &T, baseline: &T) -> Vec<u8> {
+        let delta_bytes = value.to_delta_bytes(baseline).unwrap();
+        assert_eq!(value, &T::deserialize_delta_from_bytes(baseline, &delta_bytes).unwrap());
+        delta_bytes
+    }
+
+    #[test]
+    fn test_d4k1_primitive_deltas() {
+        assert_eq!(1, assert_delta_round_trip(&12345u64, &12345u64).len());
+        assert_eq!(9, assert_delta_round_trip(&12346u64, &12345u64).len());
+        assert_delta_round_trip(&true, &false);
+        assert_delta_round_trip(&'x', &'y');
+        assert_delta_round_trip(&-5i128, &-5i128);
+        assert_delta_round_trip(&String::from("after"), &String::from("before"));
+
+        // -0.0 equals 0.0, but the bits are kept
+        let delta_bytes = (-0.0f32).to_delta_bytes(&0.0f32).unwrap();
+        assert!(f32::deserialize_delta_from_bytes(&0.0f32, &delta_bytes).unwrap().is_sign_negative());
+        assert!(!1.5f64.has_changed(&1.5f64));
+    }
+
+    #[test]
+    fn test_v7n2_vec_deltas() {
+        let baseline = (0..32u32).collect::<Vec<_>>();
+
+        let unchanged = baseline.clone();
+        assert!(!unchanged.has_changed(&baseline));
+        assert_delta_round_trip(&unchanged, &baseline);
+
+        let mut changed = baseline.clone();
+        changed[3] = 40;
+        assert!(changed.has_changed(&baseline));
+        let changed_delta_bytes = assert_delta_round_trip(&changed, &baseline);
+        assert!(changed_delta_bytes.len() < changed.to_vec_bytes().unwrap().len());
+        // the length, the changed element count, the element index and the element value without a changed flag
+        assert_eq!(9 + 9 + 9 + 4, changed_delta_bytes.len());
+
+        let mut appended = baseline.clone();
+        appended.extend([100, 101]);
+        assert_delta_round_trip(&appended, &baseline);
+
+        let removed = vec![1u32, 20];
+        assert_delta_round_trip(&removed, &baseline);
+
+        assert_delta_round_trip(&Vec::<u32>::new(), &baseline);
+        assert_delta_round_trip(&baseline, &Vec::<u32>::new());
+
+        let nested = vec![vec![1u8, 2], vec![3]];
+        assert_delta_round_trip(&vec![vec![1u8, 2, 9], vec![]], &nested);
+    }
+
+    #[test]
+    fn test_h2m5_hash_map_deltas() {
+        let baseline = HashMap::from([
+            (String::from("kept"), 1i32),
+            (String::from("changed"), 2),
+            (String::from("removed"), 3),
+        ]);
+        let value = HashMap::from([
+            (String::from("kept"), 1i32),
+            (String::from("changed"), 20),
+            (String::from("added"), 4),
+        ]);
+        assert!(value.has_changed(&baseline));
+        assert!(!baseline.has_changed(&baseline.clone()));
+        assert_delta_round_trip(&value, &baseline);
+        assert_delta_round_trip(&baseline, &value);
+
+        // a delta can only be applied to the baseline that it was made against
+        let delta_bytes = value.to_delta_bytes(&baseline).unwrap();
+        assert!(HashMap::<String, i32>::deserialize_delta_from_bytes(&HashMap::new(), &delta_bytes).is_err());
+    }
+
+    #[test]
+    fn test_o3p8_option_deltas() {
+        assert_delta_round_trip(&None, &Some(5u8));
+        assert_delta_round_trip(&Some(5u8), &None);
+        assert_delta_round_trip(&Some(6u8), &Some(5u8));
+        assert_delta_round_trip(&None::<u8>, &None);
+    }
+
+    #[test]
+    fn test_s6f9_struct_deltas_only_send_changed_fields() {
+        let baseline = Player {
+            name: String::from("Hero"),
+            health: 100,
+            speed: 4.5,
+            inventory: vec![1, 2, 3],
+            target: None,
+        };
+
+        let unchanged_delta_bytes = assert_delta_round_trip(&baseline.clone(), &baseline);
+        // only the bitmask of changed fields is sent
+        assert_eq!(1, unchanged_delta_bytes.len());
+
+        let mut damaged = baseline.clone();
+        damaged.health = 75;
+        let damaged_delta_bytes = assert_delta_round_trip(&damaged, &baseline);
+        // the bitmask and the value, since the bitmask already records that the field changed
+        assert_eq!(1 + 4, damaged_delta_bytes.len());
+        assert!(damaged_delta_bytes.len() < damaged.to_vec_bytes().unwrap().len());
+
+        let mut retargeted = damaged.clone();
+        retargeted.target = Some(42);
+        retargeted.inventory.push(4);
+        retargeted.name = String::from("Villain");
+        assert_delta_round_trip(&retargeted, &baseline);
+        assert_delta_round_trip(&baseline, &retargeted);
+    }
+}
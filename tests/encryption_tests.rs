@@ -0,0 +1,94 @@
+#[cfg(test)]
+#[cfg(any(feature = "chacha20poly1305", feature = "aes_gcm"))]
+mod encryption_tests {
+    use bytecon::{encryption::{Encrypted, EncryptionAlgorithmKind, EncryptionKey, EncryptionKeyring, SecretBytes}, ByteConverter, ContextualByteConverter};
+
+    fn supported_algorithms() -> Vec<EncryptionAlgorithmKind> {
+        [EncryptionAlgorithmKind::ChaCha20Poly1305, EncryptionAlgorithmKind::Aes256Gcm].into_iter()
+            .filter(EncryptionAlgorithmKind::is_supported)
+            .collect()
+    }
+
+    #[test]
+    fn test_e7r1_encrypted_round_trip() {
+        for algorithm in supported_algorithms() {
+            let mut keyring = EncryptionKeyring::new(EncryptionKey::generate(7, algorithm).unwrap());
+            let text = String::from("do not store me in plain text");
+            let bytes = Encrypted::new(text.clone()).to_vec_bytes_with(&keyring).unwrap();
+
+            // algorithm byte, key id, nonce, length prefixed ciphertext and tag
+            let plaintext_bytes = text.to_vec_bytes().unwrap();
+            assert_eq!(1 + 4 + 12 + plaintext_bytes.to_vec_bytes().unwrap().len() + 16, bytes.len());
+            assert!(!bytes.windows(text.len()).any(|window| window == text.as_bytes()));
+            assert_eq!(text, Encrypted::<String>::deserialize_from_bytes_with(&mut keyring, &bytes).unwrap().value);
+
+            // the same value encrypts differently each time since the nonce is random
+            assert_ne!(bytes, Encrypted::new(text.clone()).to_vec_bytes_with(&keyring).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_e7t2_encrypted_tampering() {
+        for algorithm in supported_algorithms() {
+            let mut keyring = EncryptionKeyring::new(EncryptionKey::generate(1, algorithm).unwrap());
+            let bytes = Encrypted::new(vec![1u64, 2, 3]).to_vec_bytes_with(&keyring).unwrap();
+            for byte_index in 0..bytes.len() {
+                let mut tampered_bytes = bytes.clone();
+                tampered_bytes[byte_index] ^= 0b0000_0100;
+                assert!(Encrypted::<Vec<u64>>::deserialize_from_bytes_with(&mut keyring, &tampered_bytes).is_err(), "Tampering with byte {byte_index} was not detected.");
+            }
+        }
+    }
+
+    #[test]
+    fn test_e7k3_key_rotation() {
+        let algorithm = supported_algorithms()[0];
+        let mut keyring = EncryptionKeyring::new(EncryptionKey::generate(1, algorithm).unwrap());
+        let old_bytes = Encrypted::new(10u32).to_vec_bytes_with(&keyring).unwrap();
+
+        keyring.insert_active(EncryptionKey::generate(2, algorithm).unwrap());
+        let new_bytes = Encrypted::new(20u32).to_vec_bytes_with(&keyring).unwrap();
+        assert_eq!(10, Encrypted::<u32>::deserialize_from_bytes_with(&mut keyring, &old_bytes).unwrap().value);
+        assert_eq!(20, Encrypted::<u32>::deserialize_from_bytes_with(&mut keyring, &new_bytes).unwrap().value);
+
+        // payloads for a removed key can no longer be read
+        keyring.remove(1);
+        assert!(Encrypted::<u32>::deserialize_from_bytes_with(&mut keyring, &old_bytes).is_err());
+
+        // the same id with a different secret fails authentication
+        let mut other_keyring = EncryptionKeyring::new(EncryptionKey::generate(2, algorithm).unwrap());
+        assert!(Encrypted::<u32>::deserialize_from_bytes_with(&mut other_keyring, &new_bytes).is_err());
+
+        // there is nothing to encrypt with once the active key is removed
+        keyring.remove(2);
+        assert!(Encrypted::new(30u32).to_vec_bytes_with(&keyring).is_err());
+    }
+
+    #[test]
+    fn test_e7s4_secret_bytes() {
+        let secret = SecretBytes::new(vec![42u8; 32]);
+        assert_eq!("SecretBytes([REDACTED; 32])", format!("{secret:?}"));
+        assert!(EncryptionKey::new(0, supported_algorithms()[0], SecretBytes::new(vec![0u8; 16])).is_err());
+        let key = EncryptionKey::new(0, supported_algorithms()[0], secret).unwrap();
+        assert_eq!(&[42u8; 32], key.secret().expose_secret());
+    }
+
+    #[cfg(feature = "rustls")]
+    #[test]
+    fn test_e7p5_encrypted_private_key() {
+        let mut keyring = EncryptionKeyring::new(EncryptionKey::generate(3, supported_algorithms()[0]).unwrap());
+        let private_key = rustls::PrivateKey(vec![0x30, 0x82, 0x04, 0xbd, 0x02, 0x01, 0x00]);
+        let bytes = private_key.to_vec_bytes_with(&keyring).unwrap();
+        assert!(!bytes.windows(private_key.0.len()).any(|window| window == private_key.0));
+        let decrypted = rustls::PrivateKey::deserialize_from_bytes_with(&mut keyring, &bytes).unwrap();
+        assert_eq!(private_key, decrypted);
+
+        // the key is encoded exactly as an encrypted SecretBytes
+        let secret_bytes = Encrypted::<SecretBytes>::deserialize_from_bytes_with(&mut keyring, &bytes).unwrap().value;
+        assert_eq!(private_key.0, secret_bytes.expose_secret());
+
+        let secret = SecretBytes::from(decrypted);
+        assert_eq!(private_key.0, secret.expose_secret());
+        assert_eq!(private_key, rustls::PrivateKey::from(secret));
+    }
+}
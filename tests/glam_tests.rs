@@ -0,0 +1,323 @@
+#[cfg(test)]
+mod glam_tests {
+    use bytecon::{glam::{HalfPrecision, QuantizationRange, Quantized, SignedUnitRange, SmallestThreeQuat, UnitRange}, ByteConverter, DeltaByteConverter};
+    use glam::{Affine2, Affine3A, BVec2, BVec3, BVec3A, BVec4, BVec4A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, EulerRot, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct ArenaRange;
+
+    impl QuantizationRange for ArenaRange {
+        const MIN: f32 = -512.0;
+        const MAX: f32 = 512.0;
+    }
+
+    fn get_quantized_max_error<TRange: QuantizationRange>(bits: u32) -> f32 {
+        (TRange::MAX - TRange::MIN) / ((1u32 << bits) - 1) as f32 / 2.0
+    }
+
+    // a deterministic spread of component values, including negative and extreme ones, for each seed and component index
+    trait GeneratedComponent {
+        fn generate(seed: u64) -> Self;
+    }
+
+    fn mix_seed(seed: u64) -> u64 {
+        let mut mixed = seed.wrapping_add(0x9e3779b97f4a7c15);
+        mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94d049bb133111eb);
+        mixed ^ (mixed >> 31)
+    }
+
+    macro_rules! impl_generated_integer_component {
+        ($($integer:ty),*) => {
+            $(
+                impl GeneratedComponent for $integer {
+                    fn generate(seed: u64) -> Self {
+                        mix_seed(seed) as $integer
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_generated_integer_component!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+    impl GeneratedComponent for bool {
+        fn generate(seed: u64) -> Self {
+            mix_seed(seed) & 1 == 1
+        }
+    }
+
+    impl GeneratedComponent for f32 {
+        fn generate(seed: u64) -> Self {
+            match seed % 8 {
+                0 => 0.0,
+                1 => f32::MAX,
+                2 => f32::MIN_POSITIVE,
+                _ => (mix_seed(seed) as i64 as f32) / (1u64 << 40) as f32,
+            }
+        }
+    }
+
+    impl GeneratedComponent for f64 {
+        fn generate(seed: u64) -> Self {
+            match seed % 8 {
+                0 => 0.0,
+                1 => f64::MAX,
+                2 => f64::MIN_POSITIVE,
+                _ => (mix_seed(seed) as i64 as f64) / (1u64 << 40) as f64,
+            }
+        }
+    }
+
+    fn generate_components<T: GeneratedComponent, const C: usize>(seed: u64) -> [T; C] {
+        std::array::from_fn(|component_index| T::generate(seed * C as u64 + component_index as u64))
+    }
+
+    // round trips generated component values through the listed glam types, and test_g1z0 fails when src/glam.rs gains a type that is missing here
+    macro_rules! component_round_trip_tests {
+        ($($test_name:ident: $glam_type:ty, [$component:ty; $length:literal], $constructor:expr;)*) => {
+            const COMPONENT_ROUND_TRIP_TYPE_NAMES: &[&str] = &[$(stringify!($glam_type)),*];
+
+            $(
+                #[test]
+                fn $test_name() {
+                    let constructor: fn([$component; $length]) -> $glam_type = $constructor;
+                    for seed in 0..64 {
+                        let value = constructor(generate_components::<$component, $length>(seed));
+                        let bytes = value.to_vec_bytes().unwrap();
+                        assert_eq!(std::mem::size_of::<$component>() * $length, bytes.len());
+                        assert_eq!(value, <$glam_type>::deserialize_from_bytes(&bytes).unwrap());
+                    }
+                }
+            )*
+        };
+    }
+
+    component_round_trip_tests! {
+        test_g1a0_generated_affine2: Affine2, [f32; 6], |components| Affine2::from_cols_array(&components);
+        test_g1a1_generated_affine3a: Affine3A, [f32; 12], |components| Affine3A::from_cols_array(&components);
+        test_g1b0_generated_bvec2: BVec2, [bool; 2], BVec2::from_array;
+        test_g1b1_generated_bvec3: BVec3, [bool; 3], BVec3::from_array;
+        test_g1b2_generated_bvec3a: BVec3A, [bool; 3], BVec3A::from_array;
+        test_g1b3_generated_bvec4: BVec4, [bool; 4], BVec4::from_array;
+        test_g1b4_generated_bvec4a: BVec4A, [bool; 4], BVec4A::from_array;
+        test_g1d0_generated_daffine2: DAffine2, [f64; 6], |components| DAffine2::from_cols_array(&components);
+        test_g1d1_generated_daffine3: DAffine3, [f64; 12], |components| DAffine3::from_cols_array(&components);
+        test_g1d2_generated_dmat2: DMat2, [f64; 4], |components| DMat2::from_cols_array(&components);
+        test_g1d3_generated_dmat3: DMat3, [f64; 9], |components| DMat3::from_cols_array(&components);
+        test_g1d4_generated_dmat4: DMat4, [f64; 16], |components| DMat4::from_cols_array(&components);
+        test_g1d5_generated_dquat: DQuat, [f64; 4], DQuat::from_array;
+        test_g1d6_generated_dvec2: DVec2, [f64; 2], DVec2::from_array;
+        test_g1d7_generated_dvec3: DVec3, [f64; 3], DVec3::from_array;
+        test_g1d8_generated_dvec4: DVec4, [f64; 4], DVec4::from_array;
+        test_g1i0_generated_i8vec2: I8Vec2, [i8; 2], I8Vec2::from_array;
+        test_g1i1_generated_i8vec3: I8Vec3, [i8; 3], I8Vec3::from_array;
+        test_g1i2_generated_i8vec4: I8Vec4, [i8; 4], I8Vec4::from_array;
+        test_g1i3_generated_i16vec2: I16Vec2, [i16; 2], I16Vec2::from_array;
+        test_g1i4_generated_i16vec3: I16Vec3, [i16; 3], I16Vec3::from_array;
+        test_g1i5_generated_i16vec4: I16Vec4, [i16; 4], I16Vec4::from_array;
+        test_g1i6_generated_ivec2: IVec2, [i32; 2], IVec2::from_array;
+        test_g1i7_generated_ivec3: IVec3, [i32; 3], IVec3::from_array;
+        test_g1i8_generated_ivec4: IVec4, [i32; 4], IVec4::from_array;
+        test_g1i9_generated_i64vec2: I64Vec2, [i64; 2], I64Vec2::from_array;
+        test_g1ia_generated_i64vec3: I64Vec3, [i64; 3], I64Vec3::from_array;
+        test_g1ib_generated_i64vec4: I64Vec4, [i64; 4], I64Vec4::from_array;
+        test_g1m0_generated_mat2: Mat2, [f32; 4], |components| Mat2::from_cols_array(&components);
+        test_g1m1_generated_mat3: Mat3, [f32; 9], |components| Mat3::from_cols_array(&components);
+        test_g1m2_generated_mat3a: Mat3A, [f32; 9], |components| Mat3A::from_cols_array(&components);
+        test_g1m3_generated_mat4: Mat4, [f32; 16], |components| Mat4::from_cols_array(&components);
+        test_g1q0_generated_quat: Quat, [f32; 4], Quat::from_array;
+        test_g1u0_generated_u8vec2: U8Vec2, [u8; 2], U8Vec2::from_array;
+        test_g1u1_generated_u8vec3: U8Vec3, [u8; 3], U8Vec3::from_array;
+        test_g1u2_generated_u8vec4: U8Vec4, [u8; 4], U8Vec4::from_array;
+        test_g1u3_generated_u16vec2: U16Vec2, [u16; 2], U16Vec2::from_array;
+        test_g1u4_generated_u16vec3: U16Vec3, [u16; 3], U16Vec3::from_array;
+        test_g1u5_generated_u16vec4: U16Vec4, [u16; 4], U16Vec4::from_array;
+        test_g1u6_generated_uvec2: UVec2, [u32; 2], UVec2::from_array;
+        test_g1u7_generated_uvec3: UVec3, [u32; 3], UVec3::from_array;
+        test_g1u8_generated_uvec4: UVec4, [u32; 4], UVec4::from_array;
+        test_g1u9_generated_u64vec2: U64Vec2, [u64; 2], U64Vec2::from_array;
+        test_g1ua_generated_u64vec3: U64Vec3, [u64; 3], U64Vec3::from_array;
+        test_g1ub_generated_u64vec4: U64Vec4, [u64; 4], U64Vec4::from_array;
+        test_g1v0_generated_vec2: Vec2, [f32; 2], Vec2::from_array;
+        test_g1v1_generated_vec3: Vec3, [f32; 3], Vec3::from_array;
+        test_g1v2_generated_vec3a: Vec3A, [f32; 3], Vec3A::from_array;
+        test_g1v3_generated_vec4: Vec4, [f32; 4], Vec4::from_array;
+    }
+
+    #[test]
+    fn test_g1z0_every_component_type_is_round_tripped() {
+        // EulerRot is an enum rather than a component type and has its own test
+        let untested_type_names = ["EulerRot"];
+        let implemented_type_names = include_str!("../src/glam.rs")
+            .lines()
+            .filter_map(|line| line.strip_prefix("impl ByteConverter for "))
+            .map(|line| line.trim_end_matches(" {"))
+            .filter(|type_name| !untested_type_names.contains(type_name))
+            .collect::<Vec<_>>();
+        assert!(!implemented_type_names.is_empty());
+        for type_name in implemented_type_names {
+            assert!(COMPONENT_ROUND_TRIP_TYPE_NAMES.contains(&type_name), "{type_name} is missing from component_round_trip_tests.");
+        }
+    }
+
+    #[test]
+    fn test_d3m9_dmat3_writes_every_column() {
+        // earlier versions only wrote the x and y axes while reading all three, so a value following a DMat3 was read as its z axis
+        let value = (DMat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]), 42u8);
+        let bytes = value.to_vec_bytes().unwrap();
+        assert_eq!(9 * 8 + 1, bytes.len());
+        assert_eq!(value, <(DMat3, u8)>::deserialize_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_e3r7_euler_rot() {
+        let euler_rots = [
+            EulerRot::ZYX, EulerRot::ZXY, EulerRot::YXZ, EulerRot::YZX, EulerRot::XYZ, EulerRot::XZY,
+            EulerRot::ZYZ, EulerRot::ZXZ, EulerRot::YXY, EulerRot::YZY, EulerRot::XYX, EulerRot::XZX,
+            EulerRot::ZYXEx, EulerRot::ZXYEx, EulerRot::YXZEx, EulerRot::YZXEx, EulerRot::XYZEx, EulerRot::XZYEx,
+            EulerRot::ZYZEx, EulerRot::ZXZEx, EulerRot::YXYEx, EulerRot::YZYEx, EulerRot::XYXEx, EulerRot::XZXEx,
+        ];
+        for euler_rot in euler_rots {
+            assert_eq!(euler_rot, euler_rot.clone_via_bytes().unwrap());
+        }
+        assert!(EulerRot::deserialize_from_bytes(&vec![euler_rots.len() as u8]).is_err());
+    }
+
+    // a small allowance for the f32 arithmetic of quantizing and dequantizing
+    const EPSILON: f32 = 1e-5;
+
+    #[test]
+    fn test_q4v8_quantized_vectors_within_error_bound() {
+        let positions = [
+            Vec3::ZERO,
+            Vec3::new(-512.0, 512.0, 0.0),
+            Vec3::new(123.456, -78.9, 0.001),
+            Vec3::new(-0.5, 300.25, -511.99),
+        ];
+        let max_error = get_quantized_max_error::<ArenaRange>(16);
+        for position in positions {
+            let quantized = Quantized::<Vec3, ArenaRange, 16>::new(position);
+            let bytes = quantized.to_vec_bytes().unwrap();
+            assert_eq!(6, bytes.len());
+            let cloned = Quantized::<Vec3, ArenaRange, 16>::deserialize_from_bytes(&bytes).unwrap();
+            assert!((position - cloned.value).abs().max_element() <= max_error + EPSILON);
+        }
+
+        let normal = Vec3::new(0.3, -0.8, 0.52).normalize();
+        let quantized = Quantized::<Vec3, SignedUnitRange, 10>::new(normal);
+        let bytes = quantized.to_vec_bytes().unwrap();
+        // 30 bits fit into 4 bytes
+        assert_eq!(4, bytes.len());
+        let cloned = Quantized::<Vec3, SignedUnitRange, 10>::deserialize_from_bytes(&bytes).unwrap();
+        assert!((normal - cloned.value).abs().max_element() <= get_quantized_max_error::<SignedUnitRange>(10) + EPSILON);
+
+        let color = Vec4::new(0.1, 0.5, 0.9, 1.0);
+        let cloned = Quantized::<Vec4, UnitRange, 8>::new(color).clone_via_bytes().unwrap();
+        assert!((color - cloned.value).abs().max_element() <= get_quantized_max_error::<UnitRange>(8) + EPSILON);
+
+        let factor = Vec2::new(0.25, 0.75);
+        let cloned = Quantized::<Vec2, UnitRange, 1>::new(factor).clone_via_bytes().unwrap();
+        assert_eq!(Vec2::new(0.0, 1.0), cloned.value);
+    }
+
+    #[test]
+    fn test_c2n6_quantized_values_are_clamped_to_range() {
+        let cloned = Quantized::<Vec2, SignedUnitRange, 12>::new(Vec2::new(-3.0, 7.5)).clone_via_bytes().unwrap();
+        assert_eq!(Vec2::new(-1.0, 1.0), cloned.value);
+    }
+
+    #[test]
+    fn test_h7r3_half_precision_within_error_bound() {
+        let vectors = [
+            Vec3::ZERO,
+            Vec3::new(1.0, -2.0, 4.0),
+            Vec3::new(123.456, -0.001234, 6543.21),
+        ];
+        for vector in vectors {
+            let half_precision = HalfPrecision::new(vector);
+            let bytes = half_precision.to_vec_bytes().unwrap();
+            assert_eq!(6, bytes.len());
+            let cloned = HalfPrecision::<Vec3>::deserialize_from_bytes(&bytes).unwrap();
+            for (expected, actual) in vector.to_array().into_iter().zip(cloned.value.to_array()) {
+                assert!((expected - actual).abs() <= expected.abs() * 2f32.powi(-11) + EPSILON);
+            }
+        }
+
+        let vector = Vec2::new(0.5, -0.25);
+        assert_eq!(vector, HalfPrecision::new(vector).clone_via_bytes().unwrap().value);
+
+        let vector = Vec4::new(1.5, 2.5, -3.5, 4.5);
+        assert_eq!(vector, HalfPrecision::new(vector).clone_via_bytes().unwrap().value);
+
+        let rotation = Quat::from_rotation_y(1.2);
+        let cloned = HalfPrecision::new(rotation).clone_via_bytes().unwrap();
+        assert!(rotation.abs_diff_eq(cloned.value, 2f32.powi(-11)));
+    }
+
+    #[test]
+    fn test_s5t1_smallest_three_quat_within_error_bound() {
+        let rotations = [
+            Quat::IDENTITY,
+            Quat::from_rotation_x(std::f32::consts::PI),
+            Quat::from_rotation_y(-2.5),
+            Quat::from_rotation_z(0.3),
+            Quat::from_euler(EulerRot::XYZ, 0.4, -1.3, 2.2),
+            // the largest component is negative, so the quaternion is stored as its negation
+            -Quat::from_euler(EulerRot::YXZ, 1.1, 0.7, -0.2),
+        ];
+        let max_error = std::f32::consts::SQRT_2 / ((1u32 << 10) - 1) as f32 / 2.0;
+        for rotation in rotations {
+            let smallest_three = SmallestThreeQuat::<10>::new(rotation);
+            let bytes = smallest_three.to_vec_bytes().unwrap();
+            // 2 bits for the index and 30 bits for the components fit into 4 bytes
+            assert_eq!(4, bytes.len());
+            let cloned = SmallestThreeQuat::<10>::deserialize_from_bytes(&bytes).unwrap();
+            assert!(cloned.value.is_normalized());
+            let aligned = if rotation.dot(cloned.value) < 0.0 {
+                -cloned.value
+            }
+            else {
+                cloned.value
+            };
+            // the rebuilt largest component also absorbs the error of the other three
+            assert!((rotation - aligned).to_array().into_iter().all(|difference| difference.abs() <= max_error * 2.0 + EPSILON));
+            assert!((rotation * Vec3::X).distance(aligned * Vec3::X) < 0.01);
+        }
+
+        let rotation = Quat::from_euler(EulerRot::XYZ, 0.1, 0.2, 0.3);
+        let cloned = SmallestThreeQuat::<20>::new(rotation).clone_via_bytes().unwrap();
+        assert_eq!(8, SmallestThreeQuat::<20>::new(rotation).to_vec_bytes().unwrap().len());
+        assert!(rotation.abs_diff_eq(cloned.value, 1e-5));
+    }
+
+    #[test]
+    fn test_d9x4_glam_deltas_only_send_changed_components() {
+        let baseline = Vec3::new(1.0, 2.0, 3.0);
+        let moved = Vec3::new(1.0, 2.5, 3.0);
+        let delta_bytes = moved.to_delta_bytes(&baseline).unwrap();
+        // the bitmask and the one changed component
+        assert_eq!(1 + 4, delta_bytes.len());
+        assert_eq!(moved, Vec3::deserialize_delta_from_bytes(&baseline, &delta_bytes).unwrap());
+        assert!(!baseline.has_changed(&baseline));
+        assert_eq!(1, baseline.to_delta_bytes(&baseline).unwrap().len());
+
+        let baseline = Quat::from_rotation_y(0.5);
+        let rotated = Quat::from_rotation_y(0.6);
+        assert_eq!(rotated, Quat::deserialize_delta_from_bytes(&baseline, &rotated.to_delta_bytes(&baseline).unwrap()).unwrap());
+
+        let baseline = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        let moved = Mat4::from_translation(Vec3::new(1.0, 2.0, 4.0));
+        let delta_bytes = moved.to_delta_bytes(&baseline).unwrap();
+        assert_eq!(2 + 4, delta_bytes.len());
+        assert_eq!(moved, Mat4::deserialize_delta_from_bytes(&baseline, &delta_bytes).unwrap());
+
+        let baseline = DAffine3::from_translation(DVec3::new(1.0, 2.0, 3.0));
+        let rotated = DAffine3::from_rotation_z(1.0) * baseline;
+        assert_eq!(rotated, DAffine3::deserialize_delta_from_bytes(&baseline, &rotated.to_delta_bytes(&baseline).unwrap()).unwrap());
+
+        let baseline = IVec2::new(-4, 9);
+        let moved = IVec2::new(-5, 9);
+        assert_eq!(moved, IVec2::deserialize_delta_from_bytes(&baseline, &moved.to_delta_bytes(&baseline).unwrap()).unwrap());
+    }
+}
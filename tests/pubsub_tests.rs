@@ -0,0 +1,135 @@
+#[cfg(test)]
+#[cfg(feature = "tokio")]
+mod pubsub_tests {
+    use std::{sync::Arc, time::Duration};
+    use bytecon::{pubsub::{LagPolicy, PubSubError, PubSubHub, PubSubMessage, PubSubRemotePublisher, PubSubRemoteSubscriber, SubscriberSettings}, ByteConverter};
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_u4f1_fan_out_by_topic() {
+        let hub = PubSubHub::new();
+        let mut first = hub.subscribe(["prices", "news"], SubscriberSettings::default());
+        let mut second = hub.subscribe(["prices"], SubscriberSettings::default());
+        assert_eq!(2, hub.subscriber_count());
+
+        assert_eq!(2, hub.publish("prices", &42u64).unwrap());
+        assert_eq!(1, hub.publish("news", &String::from("headline")).unwrap());
+        assert_eq!(0, hub.publish("weather", &1u8).unwrap());
+
+        let first_price = first.receive().await.unwrap();
+        let second_price = second.receive().await.unwrap();
+
+        // both subscribers share the same encoded message
+        assert!(Arc::ptr_eq(&first_price, &second_price));
+        assert_eq!("prices", first_price.topic());
+        assert_eq!(42u64, first_price.deserialize_payload::<u64>().unwrap());
+        assert_eq!((String::from("news"), String::from("headline")), first.receive_value::<String>().await.unwrap());
+
+        // a subscriber waits for the next message to be published
+        let waiting_task = tokio::spawn(async move { second.receive_value::<u64>().await.unwrap() });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        hub.publish("prices", &7u64).unwrap();
+        assert_eq!((String::from("prices"), 7u64), waiting_task.await.unwrap());
+
+        // dropped subscriptions are forgotten by the hub
+        assert_eq!(1, hub.subscriber_count());
+        drop(first);
+        assert_eq!(0, hub.subscriber_count());
+        assert_eq!(0, hub.publish("prices", &1u64).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_u4l2_lag_policies() {
+        let hub = PubSubHub::new();
+        let mut dropping = hub.subscribe(["ticks"], SubscriberSettings { capacity: 3, lag_policy: LagPolicy::DropOldest });
+        let mut disconnecting = hub.subscribe(["ticks"], SubscriberSettings { capacity: 3, lag_policy: LagPolicy::Disconnect });
+        for value in 0..3u32 {
+            assert_eq!(2, hub.publish("ticks", &value).unwrap());
+        }
+
+        // the fourth message overflows both queues
+        assert_eq!(1, hub.publish("ticks", &3u32).unwrap());
+        assert_eq!(1, hub.publish("ticks", &4u32).unwrap());
+        assert_eq!(2, dropping.dropped_count());
+        for value in 2..5u32 {
+            assert_eq!(value, dropping.receive_value::<u32>().await.unwrap().1);
+        }
+        assert_eq!(Err(PubSubError::Lagged), disconnecting.receive().await.map(|_| ()));
+        assert_eq!(1, hub.subscriber_count());
+
+        // closing the hub ends the remaining subscriptions
+        drop(hub);
+        assert_eq!(Err(PubSubError::Closed), dropping.receive().await.map(|_| ()));
+    }
+
+    #[tokio::test]
+    async fn test_u4m3_remote_subscribers_and_publishers_over_mpsc() {
+        let hub = PubSubHub::new();
+        let (topic_sender, topic_receiver) = mpsc::channel::<Vec<u8>>(16);
+        let (message_sender, message_receiver) = mpsc::channel::<Vec<u8>>(16);
+        let subscriber_task = tokio::spawn({
+            let hub = hub.clone();
+            async move { hub.serve_subscriber(topic_receiver, message_sender, SubscriberSettings::default()).await }
+        });
+        let mut topic_sender = topic_sender;
+        let mut subscriber = PubSubRemoteSubscriber::subscribe(message_receiver, &mut topic_sender, ["scores"]).await.unwrap();
+
+        let (publish_sender, publish_receiver) = mpsc::channel::<Vec<u8>>(16);
+        tokio::spawn({
+            let hub = hub.clone();
+            async move { hub.serve_publisher(publish_receiver).await }
+        });
+        while hub.subscriber_count() == 0 {
+            tokio::task::yield_now().await;
+        }
+        let mut publisher = PubSubRemotePublisher::new(publish_sender);
+        publisher.publish("ignored", &0i32).await.unwrap();
+        publisher.publish("scores", &vec![1i32, 2, 3]).await.unwrap();
+        assert_eq!((String::from("scores"), vec![1i32, 2, 3]), subscriber.receive_value::<Vec<i32>>().await.unwrap());
+
+        // the message bytes round trip through the frame unchanged
+        let message = PubSubMessage::new("scores", &5i32).unwrap();
+        assert_eq!(message, PubSubMessage::deserialize_from_bytes(&message.to_vec_bytes().unwrap()).unwrap());
+        assert_eq!(5i32.to_vec_bytes().unwrap(), message.payload());
+        assert_eq!((String::from("scores"), 5i32.to_vec_bytes().unwrap()).to_vec_bytes().unwrap(), message.encoded_bytes());
+        assert!(PubSubMessage::deserialize_from_bytes(&message.encoded_bytes()[..message.encoded_bytes().len() - 1].to_vec()).is_err());
+
+        // the served subscription ends once the remote subscriber goes away
+        drop(subscriber);
+        hub.publish("scores", &vec![4i32]).unwrap();
+        assert!(subscriber_task.await.unwrap().is_err());
+        assert_eq!(0, hub.subscriber_count());
+    }
+
+    #[tokio::test]
+    async fn test_u4n4_remote_subscribers_over_tcp() {
+        let hub = PubSubHub::new();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn({
+            let hub = hub.clone();
+            async move {
+                loop {
+                    let (stream, _) = listener.accept().await.unwrap();
+                    let (reader, writer) = stream.into_split();
+                    let hub = hub.clone();
+                    tokio::spawn(async move { hub.serve_subscriber(reader, writer, SubscriberSettings::default()).await });
+                }
+            }
+        });
+
+        let mut subscribers = Vec::new();
+        for _ in 0..3 {
+            let (reader, mut writer) = tokio::net::TcpStream::connect(address).await.unwrap().into_split();
+            subscribers.push((PubSubRemoteSubscriber::subscribe(reader, &mut writer, ["state"]).await.unwrap(), writer));
+        }
+        while hub.subscriber_count() < 3 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let state = "large state update ".repeat(2048);
+        assert_eq!(3, hub.publish("state", &state).unwrap());
+        for (subscriber, _) in subscribers.iter_mut() {
+            assert_eq!((String::from("state"), state.clone()), subscriber.receive_value::<String>().await.unwrap());
+        }
+    }
+}
@@ -0,0 +1,296 @@
+#[cfg(test)]
+#[cfg(feature = "tokio")]
+mod reliable_tests {
+    use std::{collections::BTreeSet, time::{Duration, Instant}};
+    use bytecon::{reliable::{ChannelKind, LossyLink, LossyLinkSettings, ReliableEndpoint, ReliableSettings, ReliableUdpConnection}, udp::{ByteConUdpSocket, UdpSettings}, ByteConverter};
+
+    const UNRELIABLE: u8 = 0;
+    const RELIABLE_UNORDERED: u8 = 1;
+    const RELIABLE_ORDERED: u8 = 2;
+
+    fn get_lossy_link(seed: u64) -> LossyLink {
+        LossyLink::new(LossyLinkSettings {
+            drop_probability: 0.3,
+            duplicate_probability: 0.2,
+            reorder_probability: 0.3,
+            seed,
+        })
+    }
+
+    // exchanges packets over both links, advancing the simulated clock by one resend interval per step
+    fn run_steps(left: &mut ReliableEndpoint, right: &mut ReliableEndpoint, left_to_right: &mut LossyLink, right_to_left: &mut LossyLink, now: &mut Instant, steps_length: usize) {
+        for _ in 0..steps_length {
+            for packet in left_to_right.transmit(left.poll_transmit(*now).unwrap()) {
+                right.receive_packet(&packet).unwrap();
+            }
+            for packet in right_to_left.transmit(right.poll_transmit(*now).unwrap()) {
+                left.receive_packet(&packet).unwrap();
+            }
+            *now += left.settings().resend_interval;
+        }
+    }
+
+    fn drain<T: bytecon::ByteConverter>(endpoint: &mut ReliableEndpoint, channel: u8) -> Vec<T> {
+        let mut values = Vec::new();
+        while let Some(value) = endpoint.receive::<T>(channel).unwrap() {
+            values.push(value);
+        }
+        values
+    }
+
+    #[test]
+    fn test_r7l1_channels_over_lossy_link() {
+        let mut left = ReliableEndpoint::new(ReliableSettings::default());
+        let mut right = ReliableEndpoint::new(ReliableSettings::default());
+        let mut left_to_right = get_lossy_link(1);
+        let mut right_to_left = get_lossy_link(2);
+        let mut now = Instant::now();
+
+        for value in 0..300u32 {
+            left.send(UNRELIABLE, &value).unwrap();
+            left.send(RELIABLE_UNORDERED, &value).unwrap();
+            left.send(RELIABLE_ORDERED, &format!("message {value}")).unwrap();
+            right.send(RELIABLE_ORDERED, &(value as u64)).unwrap();
+            if value % 50 == 0 {
+                run_steps(&mut left, &mut right, &mut left_to_right, &mut right_to_left, &mut now, 1);
+            }
+        }
+        run_steps(&mut left, &mut right, &mut left_to_right, &mut right_to_left, &mut now, 100);
+        assert_eq!(0, left.unacked_message_count());
+        assert_eq!(0, right.unacked_message_count());
+
+        assert_eq!((0..300).map(|value| format!("message {value}")).collect::<Vec<_>>(), drain::<String>(&mut right, RELIABLE_ORDERED));
+        assert_eq!((0..300).collect::<Vec<u64>>(), drain::<u64>(&mut left, RELIABLE_ORDERED));
+
+        // every message is delivered exactly once, but not necessarily in order
+        let unordered = drain::<u32>(&mut right, RELIABLE_UNORDERED);
+        assert_eq!(300, unordered.len());
+        assert_eq!((0..300).collect::<BTreeSet<u32>>(), unordered.iter().cloned().collect::<BTreeSet<_>>());
+        assert_ne!((0..300).collect::<Vec<u32>>(), unordered);
+
+        // unreliable messages are never resent
+        let unreliable = drain::<u32>(&mut right, UNRELIABLE);
+        assert!(unreliable.iter().all(|value| *value < 300));
+        assert!(unreliable.iter().cloned().collect::<BTreeSet<_>>().len() < 300);
+    }
+
+    #[test]
+    fn test_r7d2_lossy_link_is_deterministic() {
+        let packets = (0..100u8).map(|value| vec![value]).collect::<Vec<_>>();
+        let first = get_lossy_link(7).transmit(packets.clone());
+        assert_eq!(first, get_lossy_link(7).transmit(packets.clone()));
+        assert_ne!(first, get_lossy_link(8).transmit(packets.clone()));
+        assert_eq!(packets, LossyLink::new(LossyLinkSettings::default()).transmit(packets.clone()));
+
+        // held back packets arrive after the next transmit call
+        let mut link = LossyLink::new(LossyLinkSettings { reorder_probability: 1.0, ..LossyLinkSettings::default() });
+        assert!(link.transmit(vec![vec![1u8]]).is_empty());
+        assert_eq!(vec![vec![1u8]], link.transmit(Vec::new()));
+    }
+
+    #[test]
+    fn test_r7w3_receive_window_and_unknown_channels() {
+        let settings = ReliableSettings {
+            channels: vec![ChannelKind::ReliableOrdered],
+            receive_window: 4,
+            // each message is sent in its own packet
+            max_packet_size: 1,
+            ..ReliableSettings::default()
+        };
+        let mut left = ReliableEndpoint::new(settings.clone());
+        let mut right = ReliableEndpoint::new(settings);
+        assert!(left.send(1, &0u8).is_err());
+        let mut now = Instant::now();
+        for value in 0..10u8 {
+            left.send(0, &value).unwrap();
+        }
+
+        // arriving in reverse order, only the messages within the receive window are acknowledged
+        let mut packets = left.poll_transmit(now).unwrap();
+        assert_eq!(10, packets.len());
+        packets.reverse();
+        for packet in packets {
+            right.receive_packet(&packet).unwrap();
+        }
+        for packet in right.poll_transmit(now).unwrap() {
+            left.receive_packet(&packet).unwrap();
+        }
+        assert_eq!(6, left.unacked_message_count());
+        now += left.settings().resend_interval;
+        let mut perfect_link = LossyLink::new(LossyLinkSettings::default());
+        let mut return_link = LossyLink::new(LossyLinkSettings::default());
+        run_steps(&mut left, &mut right, &mut perfect_link, &mut return_link, &mut now, 3);
+        assert_eq!(0, left.unacked_message_count());
+        assert_eq!((0..10).collect::<Vec<u8>>(), drain::<u8>(&mut right, 0));
+    }
+
+    // the acks as (channel, sequence) and the messages as (channel, sequence, payload) encode the same as a packet
+    fn get_packet(acks: Vec<(u8, u32)>, messages: Vec<(u8, u32, Vec<u8>)>) -> Vec<u8> {
+        (acks, messages).to_vec_bytes().unwrap()
+    }
+
+    #[test]
+    fn test_r7i5_invalid_packets_are_not_applied() {
+        let mut endpoint = ReliableEndpoint::new(ReliableSettings::default());
+        endpoint.send(RELIABLE_ORDERED, &1u32).unwrap();
+        endpoint.poll_transmit(Instant::now()).unwrap();
+        assert_eq!(1, endpoint.unacked_message_count());
+
+        // the ack is valid, but the packet is rejected as a whole for the unknown channel after it
+        assert!(endpoint.receive_packet(&get_packet(vec![(RELIABLE_ORDERED, 0)], vec![(9, 0, vec![1])])).is_err());
+        assert!(endpoint.receive_packet(&get_packet(vec![(RELIABLE_ORDERED, 0), (9, 0)], Vec::new())).is_err());
+        assert_eq!(1, endpoint.unacked_message_count());
+
+        endpoint.receive_packet(&get_packet(vec![(RELIABLE_ORDERED, 0)], vec![(UNRELIABLE, 0, 7u8.to_vec_bytes().unwrap())])).unwrap();
+        assert_eq!(0, endpoint.unacked_message_count());
+        assert_eq!(Some(7u8), endpoint.receive::<u8>(UNRELIABLE).unwrap());
+    }
+
+    #[test]
+    fn test_r7f6_in_flight_and_resend_limits() {
+        let settings = ReliableSettings {
+            channels: vec![ChannelKind::ReliableUnordered],
+            // each message is sent in its own packet
+            max_packet_size: 1,
+            max_in_flight_messages: 2,
+            max_queued_messages: 5,
+            max_resends_per_transmit: 1,
+            ..ReliableSettings::default()
+        };
+        let mut endpoint = ReliableEndpoint::new(settings);
+        for value in 0..5u8 {
+            endpoint.send(0, &value).unwrap();
+        }
+        assert!(endpoint.send(0, &5u8).is_err());
+
+        // only the in-flight messages are sent, and the rest wait for acks
+        let mut now = Instant::now();
+        assert_eq!(2, endpoint.poll_transmit(now).unwrap().len());
+        assert!(endpoint.poll_transmit(now).unwrap().is_empty());
+
+        // both are due again, but only one resend goes out per transmit
+        now += endpoint.settings().resend_interval;
+        assert_eq!(1, endpoint.poll_transmit(now).unwrap().len());
+        assert_eq!(1, endpoint.poll_transmit(now).unwrap().len());
+        assert!(endpoint.poll_transmit(now).unwrap().is_empty());
+
+        // acking the first message lets the next one be sent for the first time, which is not limited as a resend
+        endpoint.receive_packet(&get_packet(vec![(0, 0)], Vec::new())).unwrap();
+        assert_eq!(1, endpoint.poll_transmit(now).unwrap().len());
+        endpoint.send(0, &5u8).unwrap();
+        assert_eq!(5, endpoint.unacked_message_count());
+    }
+
+    #[test]
+    fn test_r7a8_acks_are_split_across_packets() {
+        let settings = ReliableSettings {
+            channels: vec![ChannelKind::ReliableUnordered],
+            max_packet_size: 32,
+            ..ReliableSettings::default()
+        };
+        let mut endpoint = ReliableEndpoint::new(settings);
+        let messages = (0..20u32)
+            .map(|sequence| (0u8, sequence, vec![1u8]))
+            .collect::<Vec<_>>();
+        endpoint.receive_packet(&get_packet(Vec::new(), messages)).unwrap();
+
+        let packets = endpoint.poll_transmit(Instant::now()).unwrap();
+        assert!(packets.len() > 1);
+        let mut sequences = Vec::new();
+        for packet in packets {
+            assert!(packet.len() <= 32);
+            let (acks, messages) = <(Vec<(u8, u32)>, Vec<(u8, u32, Vec<u8>)>)>::deserialize_from_bytes(&packet).unwrap();
+            assert!(messages.is_empty());
+            sequences.extend(acks.into_iter().map(|(_, sequence)| sequence));
+        }
+        assert_eq!((0..20u32).collect::<Vec<_>>(), sequences);
+    }
+
+    #[test]
+    fn test_r7q9_delivered_and_unsent_unreliable_limits() {
+        let settings = ReliableSettings {
+            channels: vec![ChannelKind::Unreliable, ChannelKind::ReliableOrdered],
+            max_unsent_unreliable_messages: 2,
+            max_delivered_messages: 2,
+            ..ReliableSettings::default()
+        };
+        let mut endpoint = ReliableEndpoint::new(settings);
+
+        // the oldest unsent unreliable message is dropped
+        for value in 0..3u8 {
+            endpoint.send(0, &value).unwrap();
+        }
+        let packets = endpoint.poll_transmit(Instant::now()).unwrap();
+        assert_eq!(1, packets.len());
+        let (_, messages) = <(Vec<(u8, u32)>, Vec<(u8, u32, Vec<u8>)>)>::deserialize_from_bytes(&packets[0]).unwrap();
+        assert_eq!(vec![vec![1u8], vec![2u8]], messages.into_iter().map(|(_, _, payload)| payload).collect::<Vec<_>>());
+
+        // unreliable messages beyond the limit are dropped
+        endpoint.receive_packet(&get_packet(Vec::new(), vec![(0, 0, vec![0]), (0, 0, vec![1]), (0, 0, vec![2])])).unwrap();
+        assert_eq!(Some(0u8), endpoint.receive(0).unwrap());
+        assert_eq!(Some(1u8), endpoint.receive(0).unwrap());
+        assert_eq!(None, endpoint.receive::<u8>(0).unwrap());
+
+        // reliable messages beyond the limit are not acked so that the sender resends them
+        endpoint.receive_packet(&get_packet(Vec::new(), vec![(1, 0, vec![0]), (1, 1, vec![1]), (1, 2, vec![2])])).unwrap();
+        let packets = endpoint.poll_transmit(Instant::now()).unwrap();
+        let (acks, _) = <(Vec<(u8, u32)>, Vec<(u8, u32, Vec<u8>)>)>::deserialize_from_bytes(&packets[0]).unwrap();
+        assert_eq!(vec![(1, 0), (1, 1)], acks);
+        assert_eq!(Some(0u8), endpoint.receive(1).unwrap());
+        endpoint.receive_packet(&get_packet(Vec::new(), vec![(1, 2, vec![2])])).unwrap();
+        assert_eq!(Some(1u8), endpoint.receive(1).unwrap());
+        assert_eq!(Some(2u8), endpoint.receive(1).unwrap());
+        assert_eq!(None, endpoint.receive::<u8>(1).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_r7u4_reliable_ordered_over_localhost() {
+        let settings = ReliableSettings {
+            resend_interval: Duration::from_millis(20),
+            ..ReliableSettings::default()
+        };
+        let left_socket = ByteConUdpSocket::bind("127.0.0.1:0", UdpSettings::default()).await.unwrap();
+        let right_socket = ByteConUdpSocket::bind("127.0.0.1:0", UdpSettings::default()).await.unwrap();
+        let left_address = left_socket.local_addr().unwrap();
+        let right_address = right_socket.local_addr().unwrap();
+        let mut left = ReliableUdpConnection::new(left_socket, right_address, settings.clone());
+        let mut right = ReliableUdpConnection::new(right_socket, left_address, settings);
+
+        let right_task = tokio::spawn(async move {
+            let mut values = Vec::new();
+            for _ in 0..20 {
+                values.push(right.receive::<Vec<u16>>(RELIABLE_ORDERED).await.unwrap());
+            }
+            right.send(RELIABLE_ORDERED, &values.len()).await.unwrap();
+            while right.endpoint().unacked_message_count() > 0 {
+                right.receive_once().await.unwrap();
+            }
+            values
+        });
+        for value in 0..20u16 {
+            left.send(RELIABLE_ORDERED, &vec![value; 1000]).await.unwrap();
+        }
+        assert_eq!(20, left.receive::<usize>(RELIABLE_ORDERED).await.unwrap());
+        assert_eq!(0, left.endpoint().unacked_message_count());
+        let values = right_task.await.unwrap();
+        assert_eq!((0..20u16).map(|value| vec![value; 1000]).collect::<Vec<_>>(), values);
+        assert!(left.get_ref().local_addr().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_r7b7_connection_drops_invalid_packets() {
+        let peer_socket = ByteConUdpSocket::bind("127.0.0.1:0", UdpSettings::default()).await.unwrap();
+        let connection_socket = ByteConUdpSocket::bind("127.0.0.1:0", UdpSettings::default()).await.unwrap();
+        let connection_address = connection_socket.local_addr().unwrap();
+        let mut connection = ReliableUdpConnection::new(connection_socket, peer_socket.local_addr().unwrap(), ReliableSettings::default());
+        connection.send(RELIABLE_ORDERED, &1u32).await.unwrap();
+
+        peer_socket.send_to(&(vec![(RELIABLE_ORDERED, 0u32)], vec![(9u8, 0u32, vec![1u8])]), connection_address).await.unwrap();
+        connection.receive_once().await.unwrap();
+        assert_eq!(1, connection.endpoint().unacked_message_count());
+
+        peer_socket.send_to(&(vec![(RELIABLE_ORDERED, 0u32)], Vec::<(u8, u32, Vec<u8>)>::new()), connection_address).await.unwrap();
+        connection.receive_once().await.unwrap();
+        assert_eq!(0, connection.endpoint().unacked_message_count());
+    }
+}
@@ -0,0 +1,256 @@
+#[cfg(test)]
+#[cfg(feature = "tokio")]
+mod rpc_tests {
+    use std::{error::Error, sync::Arc, time::Duration};
+    use bytecon::{rpc::{RpcClient, RpcDispatcher, RpcError, RpcRequest}, ByteConverter};
+    use tokio::sync::mpsc;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Add {
+        left: i64,
+        right: i64,
+    }
+
+    impl ByteConverter for Add {
+        fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+            self.left.append_to_bytes(bytes)?;
+            self.right.append_to_bytes(bytes)?;
+            Ok(())
+        }
+        fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+            Ok(Self {
+                left: i64::extract_from_bytes(bytes, index)?,
+                right: i64::extract_from_bytes(bytes, index)?,
+            })
+        }
+    }
+
+    impl RpcRequest for Add {
+        const NAME: &'static str = "add";
+        type Response = i64;
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Delayed {
+        milliseconds: u64,
+        text: String,
+    }
+
+    impl ByteConverter for Delayed {
+        fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+            self.milliseconds.append_to_bytes(bytes)?;
+            self.text.append_to_bytes(bytes)?;
+            Ok(())
+        }
+        fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+            Ok(Self {
+                milliseconds: u64::extract_from_bytes(bytes, index)?,
+                text: String::extract_from_bytes(bytes, index)?,
+            })
+        }
+    }
+
+    impl RpcRequest for Delayed {
+        const NAME: &'static str = "delayed";
+        type Response = String;
+    }
+
+    // a request type that the dispatcher has no handler for
+    struct Unregistered;
+
+    impl ByteConverter for Unregistered {
+        fn append_to_bytes(&self, _: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+            Ok(())
+        }
+        fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_: &'a TBytes, _: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+            Ok(Self)
+        }
+    }
+
+    impl RpcRequest for Unregistered {
+        const NAME: &'static str = "unregistered";
+        type Response = ();
+    }
+
+    fn get_dispatcher() -> RpcDispatcher {
+        let mut dispatcher = RpcDispatcher::default();
+        dispatcher
+            .register(|request: Add| async move {
+                request.left.checked_add(request.right)
+                    .ok_or_else(|| "Addition overflowed.".into())
+            })
+            .unwrap()
+            .register(|request: Delayed| async move {
+                tokio::time::sleep(Duration::from_millis(request.milliseconds)).await;
+                Ok(request.text)
+            })
+            .unwrap();
+        dispatcher
+    }
+
+    // an in-process connection where the server task ends once the client side is dropped
+    fn spawn_mpsc_connection() -> RpcClient {
+        let (request_sender, request_receiver) = mpsc::channel::<Vec<u8>>(16);
+        let (response_sender, response_receiver) = mpsc::channel::<Vec<u8>>(16);
+        tokio::spawn(async move {
+            let _ = get_dispatcher().serve(request_receiver, response_sender).await;
+        });
+        let (client, driver) = RpcClient::new(response_receiver, request_sender);
+        tokio::spawn(driver);
+        client
+    }
+
+    fn downcast_rpc_error<T: std::fmt::Debug>(result: Result<T, Box<dyn Error + Send + Sync + 'static>>) -> RpcError {
+        result.unwrap_err()
+            .downcast_ref::<RpcError>()
+            .expect("Expected an RpcError.")
+            .clone()
+    }
+
+    #[tokio::test]
+    async fn test_p2m1_concurrent_calls_over_mpsc() {
+        let client = spawn_mpsc_connection();
+        assert_eq!(5, client.call(&Add { left: 2, right: 3 }).await.unwrap());
+
+        // the slow request is sent first but its response arrives last
+        let (completion_sender, mut completion_receiver) = mpsc::unbounded_channel();
+        let mut tasks = Vec::new();
+        for (milliseconds, text) in [(150, "slow"), (0, "fast"), (50, "medium")] {
+            let client = client.clone();
+            let completion_sender = completion_sender.clone();
+            tasks.push(tokio::spawn(async move {
+                let response = client.call(&Delayed { milliseconds, text: String::from(text) }).await.unwrap();
+                assert_eq!(text, response);
+                completion_sender.send(response).unwrap();
+            }));
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        for task in tasks {
+            task.await.unwrap();
+        }
+        let mut completions = Vec::new();
+        while let Ok(completion) = completion_receiver.try_recv() {
+            completions.push(completion);
+        }
+        assert_eq!(vec!["fast", "medium", "slow"], completions);
+
+        let tasks = (0..32).map(|value| {
+            let client = client.clone();
+            tokio::spawn(async move { client.call(&Add { left: value, right: value }).await.unwrap() })
+        }).collect::<Vec<_>>();
+        for (value, task) in tasks.into_iter().enumerate() {
+            assert_eq!(value as i64 * 2, task.await.unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_p2t2_timeout_and_errors() {
+        let client = spawn_mpsc_connection().with_timeout(Duration::from_millis(500));
+        assert_eq!(RpcError::Timeout, downcast_rpc_error(client.call_with_timeout(&Delayed { milliseconds: 200, text: String::from("late") }, Duration::from_millis(20)).await));
+
+        // the late response is dropped without disturbing the next call
+        assert_eq!("on time", client.call(&Delayed { milliseconds: 250, text: String::from("on time") }).await.unwrap());
+
+        match downcast_rpc_error(client.call(&Add { left: i64::MAX, right: 1 }).await) {
+            RpcError::Remote { message } => assert_eq!("Addition overflowed.", message),
+            rpc_error => panic!("Unexpected error {rpc_error:?}."),
+        }
+        match downcast_rpc_error(client.call(&Unregistered).await) {
+            RpcError::Remote { message } => assert!(message.contains("unregistered")),
+            rpc_error => panic!("Unexpected error {rpc_error:?}."),
+        }
+        let mut registered_names = get_dispatcher().get_registered_names();
+        registered_names.sort();
+        assert_eq!(vec!["add", "delayed"], registered_names);
+    }
+
+    // a different request type that claims the name of Add
+    struct Subtract;
+
+    impl ByteConverter for Subtract {
+        fn append_to_bytes(&self, _: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+            Ok(())
+        }
+        fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(_: &'a TBytes, _: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+            Ok(Self)
+        }
+    }
+
+    impl RpcRequest for Subtract {
+        const NAME: &'static str = "add";
+        type Response = i64;
+    }
+
+    #[test]
+    fn test_p2r5_conflicting_request_names() {
+        let mut dispatcher = get_dispatcher();
+        let error = dispatcher.register(|_: Subtract| async move { Ok(0i64) }).err().unwrap();
+        assert!(error.to_string().contains("already registered"));
+
+        // the handler registered first is kept
+        let mut registered_names = dispatcher.get_registered_names();
+        registered_names.sort();
+        assert_eq!(vec!["add", "delayed"], registered_names);
+    }
+
+    #[tokio::test]
+    async fn test_p2w6_serve_stops_when_the_writer_fails() {
+        let (request_sender, request_receiver) = mpsc::channel::<Vec<u8>>(16);
+        let (response_sender, response_receiver) = mpsc::channel::<Vec<u8>>(16);
+        drop(response_receiver);
+        let serve_task = tokio::spawn(async move {
+            get_dispatcher().serve(request_receiver, response_sender).await
+        });
+
+        // the request channel stays open, so only the failed response write can end serve
+        let (_unused_sender, unused_receiver) = mpsc::channel::<Vec<u8>>(16);
+        let (client, driver) = RpcClient::new(unused_receiver, request_sender);
+        tokio::spawn(driver);
+        assert_eq!(RpcError::Timeout, downcast_rpc_error(client.call_with_timeout(&Add { left: 1, right: 1 }, Duration::from_millis(100)).await));
+        let serve_result = tokio::time::timeout(Duration::from_secs(5), serve_task).await
+            .expect("Expected serve to stop after its writer failed.")
+            .unwrap();
+        assert!(serve_result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_p2c3_connection_closed() {
+        let (request_sender, mut request_receiver) = mpsc::channel::<Vec<u8>>(16);
+        let (response_sender, response_receiver) = mpsc::channel::<Vec<u8>>(16);
+        let (client, driver) = RpcClient::new(response_receiver, request_sender);
+        let driver_task = tokio::spawn(driver);
+
+        // the server receives the request and goes away without responding
+        let server_task = tokio::spawn(async move {
+            request_receiver.recv().await.unwrap();
+            drop(response_sender);
+        });
+        assert_eq!(RpcError::ConnectionClosed, downcast_rpc_error(client.call(&Add { left: 1, right: 1 }).await));
+        server_task.await.unwrap();
+        assert!(driver_task.await.unwrap().is_err());
+        assert_eq!(RpcError::ConnectionClosed, downcast_rpc_error(client.call(&Add { left: 1, right: 1 }).await));
+    }
+
+    #[tokio::test]
+    async fn test_p2n4_calls_over_tcp() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let dispatcher = Arc::new(get_dispatcher());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, writer) = stream.into_split();
+            let _ = dispatcher.serve(reader, writer).await;
+        });
+
+        let (reader, writer) = tokio::net::TcpStream::connect(address).await.unwrap().into_split();
+        let (client, driver) = RpcClient::new(reader, writer);
+        tokio::spawn(driver);
+        let delayed = Delayed { milliseconds: 10, text: "over tcp ".repeat(2048) };
+        let (sum, text) = tokio::join!(
+            client.call(&Add { left: -4, right: 10 }),
+            client.call(&delayed),
+        );
+        assert_eq!(6, sum.unwrap());
+        assert_eq!("over tcp ".repeat(2048), text.unwrap());
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+#[cfg(feature = "rustls23")]
+#[cfg(any(feature = "chacha20poly1305", feature = "aes_gcm"))]
+mod rustls23_tests {
+    use std::{net::{IpAddr, Ipv4Addr, Ipv6Addr}, sync::Arc};
+    use bytecon::{encryption::{EncryptionAlgorithmKind, EncryptionKey, EncryptionKeyring}, rustls23::{ClientConfigDescription, ServerConfigDescription}, ByteConverter, ContextualByteConverter};
+    use rcgen::{BasicConstraints, CertificateParams, IsCa, KeyPair};
+    use rustls_pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer, ServerName};
+    use rustls23::{ClientConfig, ClientConnection, ServerConfig, ServerConnection};
+
+    struct GeneratedCertificate {
+        cert_chain: Vec<CertificateDer<'static>>,
+        private_key: PrivateKeyDer<'static>,
+        root_cert: CertificateDer<'static>,
+    }
+
+    // a leaf certificate for the subject names signed by a freshly generated certificate authority
+    fn generate_certificate(subject_alt_names: Vec<String>) -> GeneratedCertificate {
+        let ca_key_pair = KeyPair::generate().unwrap();
+        let mut ca_params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        let ca_cert = ca_params.self_signed(&ca_key_pair).unwrap();
+
+        let leaf_key_pair = KeyPair::generate().unwrap();
+        let leaf_cert = CertificateParams::new(subject_alt_names).unwrap()
+            .signed_by(&leaf_key_pair, &ca_cert, &ca_key_pair)
+            .unwrap();
+
+        GeneratedCertificate {
+            cert_chain: vec![leaf_cert.der().clone(), ca_cert.der().clone()],
+            private_key: PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(leaf_key_pair.serialize_der())),
+            root_cert: ca_cert.der().clone(),
+        }
+    }
+
+    fn get_keyring() -> EncryptionKeyring {
+        let algorithm = [EncryptionAlgorithmKind::ChaCha20Poly1305, EncryptionAlgorithmKind::Aes256Gcm].into_iter()
+            .find(EncryptionAlgorithmKind::is_supported)
+            .unwrap();
+        EncryptionKeyring::new(EncryptionKey::generate(1, algorithm).unwrap())
+    }
+
+    fn handshake(server_config: Arc<ServerConfig>, client_config: Arc<ClientConfig>, server_name: ServerName<'static>) -> Result<(), rustls23::Error> {
+        let mut client = ClientConnection::new(client_config, server_name)?;
+        let mut server = ServerConnection::new(server_config)?;
+        for _ in 0..16 {
+            if !client.is_handshaking() && !server.is_handshaking() {
+                return Ok(());
+            }
+            let mut bytes = Vec::new();
+            client.write_tls(&mut bytes).unwrap();
+            server.read_tls(&mut bytes.as_slice()).unwrap();
+            server.process_new_packets()?;
+
+            let mut bytes = Vec::new();
+            server.write_tls(&mut bytes).unwrap();
+            client.read_tls(&mut bytes.as_slice()).unwrap();
+            client.process_new_packets()?;
+        }
+        panic!("Handshake did not complete.");
+    }
+
+    #[test]
+    fn test_t5p1_pki_types_round_trip() {
+        let generated_certificate = generate_certificate(vec![String::from("localhost")]);
+        let cert_chain = Vec::<CertificateDer<'static>>::deserialize_from_bytes(&generated_certificate.cert_chain.to_vec_bytes().unwrap()).unwrap();
+        assert_eq!(generated_certificate.cert_chain, cert_chain);
+
+        let private_keys = [
+            PrivateKeyDer::Pkcs1(PrivatePkcs1KeyDer::from(vec![1u8, 2, 3])),
+            PrivateKeyDer::Sec1(PrivateSec1KeyDer::from(vec![4u8, 5])),
+            generated_certificate.private_key.clone_key(),
+        ];
+        let mut keyring = get_keyring();
+        for private_key in private_keys {
+            let bytes = private_key.to_vec_bytes_with(&keyring).unwrap();
+            assert!(!bytes.windows(private_key.secret_der().len()).any(|window| window == private_key.secret_der()));
+            let extracted = PrivateKeyDer::<'static>::deserialize_from_bytes_with(&mut keyring, &bytes).unwrap();
+            assert_eq!(private_key, extracted);
+        }
+
+        // a different keyring cannot decrypt the key
+        let bytes = generated_certificate.private_key.to_vec_bytes_with(&keyring).unwrap();
+        assert!(PrivateKeyDer::<'static>::deserialize_from_bytes_with(&mut get_keyring(), &bytes).is_err());
+
+        let server_names = [
+            ServerName::try_from("example.com").unwrap().to_owned(),
+            ServerName::from(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+            ServerName::from(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+        ];
+        for server_name in server_names {
+            let extracted = ServerName::<'static>::deserialize_from_bytes(&server_name.to_vec_bytes().unwrap()).unwrap();
+            assert_eq!(server_name, extracted);
+        }
+
+        let mut invalid_server_name_bytes = vec![0u8];
+        String::from("not a valid name!").append_to_bytes(&mut invalid_server_name_bytes).unwrap();
+        assert!(ServerName::<'static>::deserialize_from_bytes(&invalid_server_name_bytes).is_err());
+    }
+
+    #[test]
+    fn test_t5c2_config_descriptions_rebuild_configs() {
+        let generated_certificate = generate_certificate(vec![String::from("localhost")]);
+        let mut server_config_description = ServerConfigDescription::new(generated_certificate.cert_chain.clone(), generated_certificate.private_key.clone_key());
+        server_config_description.alpn_protocols = vec![b"bytecon".to_vec()];
+        let mut client_config_description = ClientConfigDescription::new(vec![generated_certificate.root_cert.clone()]);
+        client_config_description.alpn_protocols = vec![b"bytecon".to_vec()];
+
+        let mut keyring = get_keyring();
+        let server_config_description = server_config_description.clone_via_bytes_with(&mut keyring).unwrap();
+        let client_config_description = client_config_description.clone_via_bytes_with(&mut keyring).unwrap();
+        let server_config = server_config_description.build().unwrap();
+        let client_config = client_config_description.build().unwrap();
+        assert_eq!(vec![b"bytecon".to_vec()], server_config.alpn_protocols);
+        handshake(server_config.clone(), client_config.clone(), ServerName::try_from("localhost").unwrap()).unwrap();
+
+        // the certificate is not valid for other names
+        assert!(handshake(server_config, client_config, ServerName::try_from("example.com").unwrap()).is_err());
+
+        // a client that does not trust the certificate authority rejects the server
+        let untrusted_root_cert = generate_certificate(vec![String::from("localhost")]).root_cert;
+        let untrusted_client_config = ClientConfigDescription::new(vec![untrusted_root_cert]).build().unwrap();
+        assert!(handshake(server_config_description.build().unwrap(), untrusted_client_config, ServerName::try_from("localhost").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_t5m3_client_auth() {
+        let server_certificate = generate_certificate(vec![String::from("localhost")]);
+        let client_certificate = generate_certificate(vec![String::from("client")]);
+        let mut server_config_description = ServerConfigDescription::new(server_certificate.cert_chain.clone(), server_certificate.private_key.clone_key());
+        server_config_description.client_auth_roots = Some(vec![client_certificate.root_cert.clone()]);
+        let mut keyring = get_keyring();
+        let server_config_description = server_config_description.clone_via_bytes_with(&mut keyring).unwrap();
+
+        let mut client_config_description = ClientConfigDescription::new(vec![server_certificate.root_cert.clone()]);
+        client_config_description.client_auth = Some((client_certificate.cert_chain.clone(), client_certificate.private_key.clone_key()));
+        let client_config_description_bytes = client_config_description.to_vec_bytes_with(&keyring).unwrap();
+        let client_config_description = ClientConfigDescription::deserialize_from_bytes_with(&mut keyring, &client_config_description_bytes).unwrap();
+        handshake(server_config_description.build().unwrap(), client_config_description.build().unwrap(), ServerName::try_from("localhost").unwrap()).unwrap();
+
+        // the server requires a client certificate
+        let anonymous_client_config = ClientConfigDescription::new(vec![server_certificate.root_cert.clone()]).build().unwrap();
+        assert!(handshake(server_config_description.build().unwrap(), anonymous_client_config, ServerName::try_from("localhost").unwrap()).is_err());
+
+        // the client auth is encoded like any other Option
+        assert_eq!(0u8, client_config_description_bytes[client_config_description.root_certs.to_vec_bytes().unwrap().len()]);
+        let mut anonymous_bytes = Vec::<CertificateDer<'static>>::new().to_vec_bytes().unwrap();
+        anonymous_bytes.push(1u8);
+        Vec::<Vec<u8>>::new().append_to_bytes(&mut anonymous_bytes).unwrap();
+        assert_eq!(anonymous_bytes, ClientConfigDescription::new(Vec::new()).to_vec_bytes_with(&keyring).unwrap());
+    }
+}
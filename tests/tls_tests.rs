@@ -0,0 +1,121 @@
+#[cfg(test)]
+#[cfg(feature = "tls")]
+mod tls_tests {
+    use std::{error::Error, time::Duration};
+    use bytecon::{tls::{ByteConTlsClient, ByteConTlsServer}, ByteConverter};
+    use rcgen::CertifiedKey;
+    use rustls_pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer, ServerName};
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Request {
+        Add(i32, i32),
+        Echo(String),
+    }
+
+    impl ByteConverter for Request {
+        fn append_to_bytes(&self, bytes: &mut Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+            match self {
+                Self::Add(left, right) => {
+                    0u8.append_to_bytes(bytes)?;
+                    left.append_to_bytes(bytes)?;
+                    right.append_to_bytes(bytes)?;
+                },
+                Self::Echo(text) => {
+                    1u8.append_to_bytes(bytes)?;
+                    text.append_to_bytes(bytes)?;
+                },
+            }
+            Ok(())
+        }
+        fn extract_from_bytes<'a, TBytes: AsRef<[u8]>>(bytes: &'a TBytes, index: &mut usize) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> where Self: Sized {
+            match u8::extract_from_bytes(bytes, index)? {
+                0u8 => Ok(Self::Add(i32::extract_from_bytes(bytes, index)?, i32::extract_from_bytes(bytes, index)?)),
+                1u8 => Ok(Self::Echo(String::extract_from_bytes(bytes, index)?)),
+                _ => Err("Unexpected enum variant byte.".into()),
+            }
+        }
+    }
+
+    fn generate_self_signed_certificate() -> (CertificateDer<'static>, PrivateKeyDer<'static>) {
+        let CertifiedKey { cert, key_pair } = rcgen::generate_simple_self_signed(vec![String::from("localhost")]).unwrap();
+        (cert.der().clone(), PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key_pair.serialize_der())))
+    }
+
+    #[tokio::test]
+    async fn test_s9r1_request_response_over_localhost() {
+        let (cert, private_key) = generate_self_signed_certificate();
+        let server = ByteConTlsServer::<Request, String>::bind("127.0.0.1:0", vec![cert.clone()], private_key).await.unwrap();
+        let address = server.local_addr().unwrap();
+        let server_task = tokio::spawn(async move {
+            let mut connection = server.accept().await.unwrap().complete().await.unwrap();
+            let mut requests = Vec::new();
+            while let Ok(request) = connection.receive().await {
+                let response = match &request {
+                    Request::Add(left, right) => (left + right).to_string(),
+                    Request::Echo(text) => text.clone(),
+                };
+                connection.send(&response).await.unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+
+        let mut client = ByteConTlsClient::<Request, String>::connect(address, ServerName::try_from("localhost").unwrap(), vec![cert]).await.unwrap();
+        assert_eq!("5", client.request(&Request::Add(2, 3)).await.unwrap());
+        let long_text = "a message spanning multiple TLS records ".repeat(1024);
+        assert_eq!(long_text, client.request(&Request::Echo(long_text.clone())).await.unwrap());
+
+        // several requests can be sent before reading their responses
+        for value in 0..8 {
+            client.send(&Request::Add(value, value)).await.unwrap();
+        }
+        for value in 0..8 {
+            assert_eq!((value * 2).to_string(), client.receive().await.unwrap());
+        }
+
+        // closing the client ends the server loop
+        drop(client);
+        let requests = server_task.await.unwrap();
+        assert_eq!(10, requests.len());
+        assert_eq!(Request::Echo(long_text), requests[1]);
+    }
+
+    #[tokio::test]
+    async fn test_s9u2_untrusted_server_rejected() {
+        let (cert, private_key) = generate_self_signed_certificate();
+        let (untrusted_cert, _) = generate_self_signed_certificate();
+        let server = ByteConTlsServer::<Request, String>::bind("127.0.0.1:0", vec![cert.clone()], private_key).await.unwrap();
+        let address = server.local_addr().unwrap();
+        let server_task = tokio::spawn(async move {
+            (server.accept().await.unwrap().complete().await.is_err(), server.accept().await.unwrap().complete().await.is_err())
+        });
+
+        assert!(ByteConTlsClient::<Request, String>::connect(address, ServerName::try_from("localhost").unwrap(), vec![untrusted_cert]).await.is_err());
+        assert!(ByteConTlsClient::<Request, String>::connect(address, ServerName::try_from("example.com").unwrap(), vec![cert]).await.is_err());
+        assert_eq!((true, true), server_task.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_s9h3_stalled_handshake_does_not_block_accepts() {
+        let (cert, private_key) = generate_self_signed_certificate();
+        let server = ByteConTlsServer::<Request, String>::bind("127.0.0.1:0", vec![cert.clone()], private_key).await.unwrap()
+            .with_handshake_timeout(Duration::from_millis(200));
+        let address = server.local_addr().unwrap();
+        let server_task = tokio::spawn(async move {
+            // the stalled client is accepted first, but its handshake is completed on its own task
+            let stalled_handshake = server.accept().await.unwrap();
+            let stalled_task = tokio::spawn(stalled_handshake.complete());
+            let mut connection = server.accept().await.unwrap().complete().await.unwrap();
+            let request = connection.receive().await.unwrap();
+            connection.send(&String::from("accepted")).await.unwrap();
+            (request, stalled_task.await.unwrap().is_err())
+        });
+
+        // connects over TCP without ever starting the TLS handshake
+        let _stalled_stream = tokio::net::TcpStream::connect(address).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let mut client = ByteConTlsClient::<Request, String>::connect(address, ServerName::try_from("localhost").unwrap(), vec![cert]).await.unwrap();
+        assert_eq!("accepted", client.request(&Request::Echo(String::from("hello"))).await.unwrap());
+        assert_eq!((Request::Echo(String::from("hello")), true), server_task.await.unwrap());
+    }
+}
@@ -0,0 +1,201 @@
+#[cfg(test)]
+#[cfg(feature = "tokio")]
+mod udp_tests {
+    use std::time::Duration;
+    use bytecon::{udp::{ByteConUdpSocket, UdpSettings}, ByteConverter};
+    use tokio::net::UdpSocket;
+
+    fn get_settings() -> UdpSettings {
+        UdpSettings {
+            max_datagram_size: 64,
+            reassembly_timeout: Duration::from_millis(100),
+            peer_timeout: Duration::from_millis(100),
+            ..UdpSettings::default()
+        }
+    }
+
+    fn get_datagram(session_id: u32, sequence: u32, fragment_index: u16, fragment_count: u16, payload: &[u8]) -> Vec<u8> {
+        let mut datagram = Vec::new();
+        session_id.append_to_bytes(&mut datagram).unwrap();
+        sequence.append_to_bytes(&mut datagram).unwrap();
+        fragment_index.append_to_bytes(&mut datagram).unwrap();
+        fragment_count.append_to_bytes(&mut datagram).unwrap();
+        datagram.extend_from_slice(payload);
+        datagram
+    }
+
+    #[tokio::test]
+    async fn test_d6f1_fragmented_messages_over_localhost() {
+        let sender = ByteConUdpSocket::bind("127.0.0.1:0", get_settings()).await.unwrap();
+        let mut receiver = ByteConUdpSocket::bind("127.0.0.1:0", get_settings()).await.unwrap();
+        let receiver_address = receiver.local_addr().unwrap();
+
+        sender.send_to(&7u32, receiver_address).await.unwrap();
+        let (value, address) = receiver.receive_from::<u32>().await.unwrap();
+        assert_eq!(7, value);
+        assert_eq!(sender.local_addr().unwrap(), address);
+
+        // spans many fragments of 56 payload bytes each
+        let large_value = (0..1000u32).collect::<Vec<_>>();
+        sender.send_to(&large_value, receiver_address).await.unwrap();
+        sender.send_to(&String::from("after"), receiver_address).await.unwrap();
+        assert_eq!(large_value, receiver.receive_from::<Vec<u32>>().await.unwrap().0);
+        assert_eq!("after", receiver.receive_from::<String>().await.unwrap().0);
+        assert_eq!(0, receiver.pending_message_count());
+
+        let mut empty_sender = ByteConUdpSocket::bind("127.0.0.1:0", get_settings()).await.unwrap();
+        empty_sender.send_to(&(), receiver_address).await.unwrap();
+        receiver.receive_from::<()>().await.unwrap();
+        assert!(empty_sender.get_ref().local_addr().is_ok());
+        assert!(tokio::time::timeout(Duration::from_millis(20), empty_sender.receive_bytes_from()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_d6r2_reordered_and_duplicated_fragments() {
+        let mut receiver = ByteConUdpSocket::bind("127.0.0.1:0", get_settings()).await.unwrap();
+        let receiver_address = receiver.local_addr().unwrap();
+        let raw_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        for datagram in [
+            get_datagram(7, 1, 2, 3, b"ghi"),
+            get_datagram(7, 1, 0, 3, b"abc"),
+            get_datagram(7, 1, 0, 3, b"xxx"),
+            get_datagram(7, 1, 1, 3, b"def"),
+            // the whole message repeated after it completed
+            get_datagram(7, 1, 0, 3, b"abc"),
+            get_datagram(7, 1, 1, 3, b"def"),
+            get_datagram(7, 1, 2, 3, b"ghi"),
+            // malformed headers
+            get_datagram(7, 2, 3, 3, b"bad"),
+            get_datagram(7, 2, 0, 0, b"bad"),
+            vec![1, 2, 3],
+            get_datagram(7, 3, 0, 1, b"jkl"),
+        ] {
+            raw_socket.send_to(&datagram, receiver_address).await.unwrap();
+        }
+        assert_eq!(b"abcdefghi".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        assert_eq!(b"jkl".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        assert_eq!(0, receiver.pending_message_count());
+    }
+
+    #[tokio::test]
+    async fn test_d6t3_incomplete_messages_expire() {
+        let mut receiver = ByteConUdpSocket::bind("127.0.0.1:0", get_settings()).await.unwrap();
+        let receiver_address = receiver.local_addr().unwrap();
+        let raw_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        raw_socket.send_to(&get_datagram(7, 1, 0, 2, b"lost"), receiver_address).await.unwrap();
+        raw_socket.send_to(&get_datagram(7, 2, 0, 1, b"first"), receiver_address).await.unwrap();
+        assert_eq!(b"first".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        assert_eq!(1, receiver.pending_message_count());
+
+        // the missing fragment arrives after the reassembly timeout
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        raw_socket.send_to(&get_datagram(7, 1, 1, 2, b"late"), receiver_address).await.unwrap();
+        raw_socket.send_to(&get_datagram(7, 3, 0, 1, b"second"), receiver_address).await.unwrap();
+        assert_eq!(b"second".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        assert_eq!(1, receiver.pending_message_count());
+    }
+
+    #[tokio::test]
+    async fn test_d6s5_restarted_peers_and_sequence_window() {
+        let mut receiver = ByteConUdpSocket::bind("127.0.0.1:0", UdpSettings { peer_timeout: Duration::from_secs(60), ..get_settings() }).await.unwrap();
+        let receiver_address = receiver.local_addr().unwrap();
+        let raw_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        for datagram in [
+            get_datagram(7, 0, 0, 1, b"a"),
+            get_datagram(7, 0, 0, 1, b"a"),
+            // the peer restarted and its sequences start over
+            get_datagram(8, 0, 0, 1, b"b"),
+            get_datagram(8, 2000, 0, 1, b"c"),
+            // too far behind the highest sequence to tell apart from a duplicate
+            get_datagram(8, 5, 0, 1, b"stale"),
+            get_datagram(8, 1990, 0, 1, b"d"),
+            get_datagram(8, 1990, 0, 1, b"d"),
+            get_datagram(8, 2000, 0, 1, b"c"),
+            get_datagram(8, 2001, 0, 1, b"e"),
+        ] {
+            raw_socket.send_to(&datagram, receiver_address).await.unwrap();
+        }
+        for expected in [b"a", b"b", b"c", b"d", b"e"] {
+            assert_eq!(expected.to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        }
+        assert_eq!(1, receiver.peer_count());
+
+        // a sender whose sequences wrap keeps delivering
+        let wrapping_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        wrapping_socket.send_to(&get_datagram(9, u32::MAX, 0, 1, b"last"), receiver_address).await.unwrap();
+        wrapping_socket.send_to(&get_datagram(9, 0, 0, 1, b"first"), receiver_address).await.unwrap();
+        wrapping_socket.send_to(&get_datagram(9, u32::MAX, 0, 1, b"last"), receiver_address).await.unwrap();
+        wrapping_socket.send_to(&get_datagram(9, 1, 0, 1, b"second"), receiver_address).await.unwrap();
+        for expected in [b"last".to_vec(), b"first".to_vec(), b"second".to_vec()] {
+            assert_eq!(expected, receiver.receive_bytes_from().await.unwrap().0);
+        }
+        assert_eq!(2, receiver.peer_count());
+    }
+
+    #[tokio::test]
+    async fn test_d6p6_idle_peers_are_forgotten() {
+        let mut receiver = ByteConUdpSocket::bind("127.0.0.1:0", get_settings()).await.unwrap();
+        let receiver_address = receiver.local_addr().unwrap();
+        let idle_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let active_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        idle_socket.send_to(&get_datagram(7, 0, 0, 1, b"idle"), receiver_address).await.unwrap();
+        assert_eq!(b"idle".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        assert_eq!(1, receiver.peer_count());
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        active_socket.send_to(&get_datagram(7, 0, 0, 1, b"active"), receiver_address).await.unwrap();
+        assert_eq!(b"active".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        assert_eq!(1, receiver.peer_count());
+    }
+
+    #[tokio::test]
+    async fn test_d6b7_pending_message_limits() {
+        let settings = UdpSettings {
+            reassembly_timeout: Duration::from_secs(5),
+            max_pending_messages_per_peer: 2,
+            max_pending_messages: 3,
+            ..get_settings()
+        };
+        let mut receiver = ByteConUdpSocket::bind("127.0.0.1:0", settings).await.unwrap();
+        let receiver_address = receiver.local_addr().unwrap();
+        let first_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let second_socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+        // the third partial message of the first peer evicts its oldest one, while single datagram messages never evict
+        for sequence in 1..=3 {
+            first_socket.send_to(&get_datagram(7, sequence, 0, 2, b"a"), receiver_address).await.unwrap();
+        }
+        first_socket.send_to(&get_datagram(7, 10, 0, 1, b"first done"), receiver_address).await.unwrap();
+        assert_eq!(b"first done".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        assert_eq!(2, receiver.pending_message_count());
+
+        // the total limit evicts the oldest partial message of any peer
+        for sequence in 1..=2 {
+            second_socket.send_to(&get_datagram(8, sequence, 0, 2, b"b"), receiver_address).await.unwrap();
+        }
+        second_socket.send_to(&get_datagram(8, 10, 0, 1, b"second done"), receiver_address).await.unwrap();
+        assert_eq!(b"second done".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        assert_eq!(3, receiver.pending_message_count());
+
+        // sequence 3 of the first peer survived, but sequence 2 was evicted and starts over instead of completing
+        first_socket.send_to(&get_datagram(7, 3, 1, 2, b"3"), receiver_address).await.unwrap();
+        assert_eq!(b"a3".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        first_socket.send_to(&get_datagram(7, 2, 1, 2, b"2"), receiver_address).await.unwrap();
+        first_socket.send_to(&get_datagram(7, 11, 0, 1, b"first done again"), receiver_address).await.unwrap();
+        assert_eq!(b"first done again".to_vec(), receiver.receive_bytes_from().await.unwrap().0);
+        assert_eq!(3, receiver.pending_message_count());
+    }
+
+    #[tokio::test]
+    async fn test_d6l4_message_limits() {
+        assert!(ByteConUdpSocket::bind("127.0.0.1:0", UdpSettings { max_datagram_size: 8, ..UdpSettings::default() }).await.is_err());
+        let sender = ByteConUdpSocket::bind("127.0.0.1:0", UdpSettings { max_message_size: 100, ..get_settings() }).await.unwrap();
+        let receiver_address = sender.local_addr().unwrap();
+        assert!(sender.send_to(&vec![0u8; 200], receiver_address).await.is_err());
+        assert!(sender.send_to(&vec![0u8; 50], receiver_address).await.is_ok());
+    }
+}
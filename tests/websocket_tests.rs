@@ -0,0 +1,84 @@
+#[cfg(test)]
+#[cfg(feature = "websocket")]
+mod websocket_tests {
+    use bytecon::{ByteStreamReaderAsync, ByteStreamWriterAsync};
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+    async fn connect() -> (WebSocketStream<MaybeTlsStream<TcpStream>>, WebSocketStream<TcpStream>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server_task = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(stream).await.unwrap()
+        });
+        let (client, _) = tokio_tungstenite::connect_async(format!("ws://{address}")).await.unwrap();
+        (client, server_task.await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_w8b1_one_binary_message_per_value() {
+        let (mut client, mut server) = connect().await;
+        client.write_from_byte_converter(&String::from("hello")).await.unwrap();
+        client.write_from_byte_converter(&vec![1u32, 2, 3]).await.unwrap();
+
+        // each value arrives as its own binary message holding exactly its bytes
+        assert_eq!(Message::binary(bytecon::ByteConverter::to_vec_bytes(&String::from("hello")).unwrap()), server.next().await.unwrap().unwrap());
+        assert_eq!(vec![1u32, 2, 3], server.read_to_byte_converter::<Vec<u32>>().await.unwrap());
+
+        // pings are skipped while reading
+        server.send(Message::Ping(vec![1u8].into())).await.unwrap();
+        server.write_from_byte_converter(&(-5i64)).await.unwrap();
+        assert_eq!(-5i64, client.read_to_byte_converter::<i64>().await.unwrap());
+
+        let large_text = "websocket ".repeat(100_000);
+        server.write_from_byte_converter(&large_text).await.unwrap();
+        assert_eq!(large_text, client.read_to_byte_converter::<String>().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_w8s2_split_halves() {
+        let (client, server) = connect().await;
+        let (mut server_writer, mut server_reader) = server.split();
+        let echo_task = tokio::spawn(async move {
+            while let Ok(value) = server_reader.read_to_byte_converter::<u64>().await {
+                server_writer.write_from_byte_converter(&(value * 2)).await.unwrap();
+            }
+        });
+
+        let (mut client_writer, mut client_reader) = client.split();
+        for value in 0..16u64 {
+            client_writer.write_from_byte_converter(&value).await.unwrap();
+        }
+        for value in 0..16u64 {
+            assert_eq!(value * 2, client_reader.read_to_byte_converter::<u64>().await.unwrap());
+        }
+        client_writer.close().await.unwrap();
+        echo_task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_w8e3_text_and_close_are_errors() {
+        let (mut client, mut server) = connect().await;
+        client.send(Message::text("not binary")).await.unwrap();
+        assert!(server.read_to_byte_converter::<String>().await.is_err());
+
+        client.close(None).await.unwrap();
+        assert!(server.read_to_byte_converter::<String>().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_w8m4_batches() {
+        let (mut client, mut server) = connect().await;
+        let values = (0..50u32).collect::<Vec<_>>();
+        client.write_many(&values).await.unwrap();
+        let mut received = Vec::new();
+        while received.len() < values.len() {
+            let batch = server.read_many::<u32>(20).await.unwrap();
+            assert!(!batch.is_empty() && batch.len() <= 20);
+            received.extend(batch);
+        }
+        assert_eq!(values, received);
+    }
+}